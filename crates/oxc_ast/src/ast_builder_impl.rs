@@ -1,8 +1,8 @@
 #![warn(missing_docs)]
 
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::RefCell};
 
-use oxc_allocator::{Allocator, AllocatorAccessor, Box, FromIn, IntoIn, Vec};
+use oxc_allocator::{Allocator, AllocatorAccessor, Box, FromIn, HashSet, IntoIn, Vec};
 use oxc_span::{Atom, SPAN, Span};
 use oxc_syntax::{
     comment_node::CommentNodeId, number::NumberBase, operator::UnaryOperator, scope::ScopeId,
@@ -33,17 +33,62 @@ impl<'a> AllocatorAccessor<'a> for AstBuilder<'a> {
 pub struct AstBuilder<'a> {
     /// The memory allocator used to allocate AST nodes in the arena.
     pub allocator: &'a Allocator,
+    /// Strings interned by this builder and its copies, see [`AstBuilder::with_atom_cache`].
+    atom_cache: Option<&'a AtomCache<'a>>,
+}
+
+/// Strings interned by an [`AstBuilder`] created with [`AstBuilder::with_atom_cache`].
+///
+/// The cache is stored in the arena, so it is freed along with the AST when the allocator is
+/// reset or dropped.
+pub struct AtomCache<'a> {
+    strings: RefCell<HashSet<'a, &'a str>>,
+}
+
+impl<'a> AtomCache<'a> {
+    fn get(&self, value: &str) -> Option<&'a str> {
+        self.strings.borrow().get(value).copied()
+    }
+
+    fn intern(&self, value: &'a str) -> &'a str {
+        self.strings.borrow_mut().get_or_insert(value)
+    }
 }
 
 impl<'a> AstBuilder<'a> {
     /// Create a new AST builder that will allocate nodes in the given allocator.
     #[inline]
     pub fn new(allocator: &'a Allocator) -> Self {
-        Self { allocator }
+        Self { allocator, atom_cache: None }
+    }
+
+    /// Create a new AST builder that will allocate nodes in the given allocator, and intern the
+    /// strings of the atoms it creates.
+    ///
+    /// The builder returns the same string for every atom with the same value, so equal atoms
+    /// are pointer-equal and each string is only stored in the arena once. The cache is shared by
+    /// all copies of the builder, e.g. by parsers given the same builder with
+    /// `Parser::with_ast_builder`.
+    pub fn with_atom_cache(allocator: &'a Allocator) -> Self {
+        let atom_cache =
+            allocator.alloc(AtomCache { strings: RefCell::new(HashSet::new_in(allocator)) });
+        Self { allocator, atom_cache: Some(atom_cache) }
+    }
+
+    /// Intern a string which lives as long as the arena, e.g. a slice of the source text.
+    ///
+    /// Returns the interned copy of `value` if the builder has an atom cache which already
+    /// contains it, otherwise returns `value` without copying it.
+    #[inline]
+    pub fn intern(self, value: &'a str) -> &'a str {
+        match self.atom_cache {
+            Some(atom_cache) => atom_cache.intern(value),
+            None => value,
+        }
     }
 
     /// Create [`CommentNodeId`] for an AST node.
-    #[expect(dead_code, clippy::unused_self, clippy::trivially_copy_pass_by_ref)]
+    #[expect(dead_code, clippy::unused_self)]
     pub(crate) fn get_comment_node_id(&self) -> CommentNodeId {
         // TODO: Generate a real ID
         CommentNodeId::DUMMY
@@ -93,21 +138,34 @@ impl<'a> AstBuilder<'a> {
 
     /// Move a string slice into the memory arena, returning a reference to the slice
     /// in the heap.
+    ///
+    /// If the builder has an atom cache, returns the interned string instead of allocating a new
+    /// one where possible.
     #[inline]
     pub fn str(self, value: &str) -> &'a str {
-        self.allocator.alloc_str(value)
+        let Some(atom_cache) = self.atom_cache else {
+            return self.allocator.alloc_str(value);
+        };
+        match atom_cache.get(value) {
+            Some(interned) => interned,
+            None => atom_cache.intern(self.allocator.alloc_str(value)),
+        }
     }
 
     /// Allocate an [`Atom`] from a string slice.
     #[inline]
     pub fn atom(self, value: &str) -> Atom<'a> {
+        if self.atom_cache.is_some() {
+            return Atom::from(self.str(value));
+        }
         Atom::from_in(value, self.allocator)
     }
 
     /// Allocate an [`Atom`] from an array of string slices.
     #[inline]
     pub fn atom_from_strs_array<const N: usize>(self, strings: [&str; N]) -> Atom<'a> {
-        Atom::from_strs_array_in(strings, self.allocator)
+        let atom = Atom::from_strs_array_in(strings, self.allocator);
+        Atom::from(self.intern(atom.as_str()))
     }
 
     /// Convert a [`Cow<'a, str>`] to an [`Atom<'a>`].
@@ -116,9 +174,14 @@ impl<'a> AstBuilder<'a> {
     /// without allocating a new one.
     ///
     /// If the `Cow` is owned, allocates the string into arena to generate a new `Atom`.
+    ///
+    /// If the builder has an atom cache, the string is interned either way.
     #[inline]
     pub fn atom_from_cow(self, value: &Cow<'a, str>) -> Atom<'a> {
-        Atom::from_cow_in(value, self.allocator)
+        match value {
+            Cow::Borrowed(s) => Atom::from(self.intern(s)),
+            Cow::Owned(s) => self.atom(s),
+        }
     }
 
     /// `0`
@@ -273,3 +336,33 @@ impl<'a> AstBuilder<'a> {
         ))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{borrow::Cow, ptr};
+
+    use oxc_allocator::Allocator;
+
+    use super::AstBuilder;
+
+    #[test]
+    fn atom_cache() {
+        let allocator = Allocator::default();
+        let builder = AstBuilder::with_atom_cache(&allocator);
+        let copy = builder;
+        let source = allocator.alloc_str("foo");
+
+        let atom = builder.atom("foo");
+        assert!(ptr::eq(copy.atom("foo").as_str(), atom.as_str()));
+        assert!(ptr::eq(copy.str("foo"), atom.as_str()));
+        assert!(ptr::eq(copy.intern(source), atom.as_str()));
+        assert!(ptr::eq(copy.atom_from_cow(&Cow::Owned("foo".into())).as_str(), atom.as_str()));
+        assert!(ptr::eq(copy.atom_from_strs_array(["f", "oo"]).as_str(), atom.as_str()));
+        assert!(!ptr::eq(copy.atom("bar").as_str(), atom.as_str()));
+
+        // Without a cache, every atom is a new string.
+        let builder = AstBuilder::new(&allocator);
+        assert!(!ptr::eq(builder.atom("foo").as_str(), builder.atom("foo").as_str()));
+        assert!(ptr::eq(builder.intern(source), source));
+    }
+}
@@ -11,6 +11,7 @@ pub fn get_parse_options() -> ParseOptions {
         allow_v8_intrinsics: true,
         // `oxc_formatter` expects this to be `false`, otherwise panics
         preserve_parens: false,
+        ..ParseOptions::default()
     }
 }
 
@@ -0,0 +1,89 @@
+//! Syntactic detection of CommonJS usage, see [`crate::Parser::parse_cjs_detect`].
+
+use oxc_ast::ast::{Expression, IdentifierName};
+use oxc_span::Span;
+
+use crate::ParserImpl;
+
+/// CommonJS usage found by [`Parser::parse_cjs_detect`], e.g. for ESM migration tools to decide
+/// whether a file is CommonJS.
+///
+/// Each field is the span of the first occurrence. Detection is purely syntactic, so a local
+/// variable named `require` or `module` is reported too.
+///
+/// [`Parser::parse_cjs_detect`]: crate::Parser::parse_cjs_detect
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CommonJsUsage {
+    /// A call to `require`, e.g. `require('fs')`.
+    pub require: Option<Span>,
+
+    /// `module.exports`.
+    pub module_exports: Option<Span>,
+
+    /// A property of `exports`, e.g. `exports.foo`.
+    pub exports: Option<Span>,
+
+    /// `__dirname`.
+    pub dirname: Option<Span>,
+
+    /// `__filename`.
+    pub filename: Option<Span>,
+}
+
+impl CommonJsUsage {
+    /// Whether any CommonJS usage was found.
+    pub fn is_commonjs(&self) -> bool {
+        self.require.is_some()
+            || self.module_exports.is_some()
+            || self.exports.is_some()
+            || self.dirname.is_some()
+            || self.filename.is_some()
+    }
+}
+
+/// Keep the first `span` in source order, as speculative parsing may visit code out of order.
+fn record(found: &mut Option<Span>, span: Span) {
+    if found.is_none_or(|found| span.start < found.start) {
+        *found = Some(span);
+    }
+}
+
+impl<'a> ParserImpl<'a> {
+    /// `__dirname`, `__filename`
+    #[cold]
+    pub(crate) fn detect_commonjs_identifier(&mut self, name: &str, span: Span) {
+        let Some(usage) = &mut self.commonjs_usage else { return };
+        match name {
+            "__dirname" => record(&mut usage.dirname, span),
+            "__filename" => record(&mut usage.filename, span),
+            _ => {}
+        }
+    }
+
+    /// `require(...)`
+    #[cold]
+    pub(crate) fn detect_commonjs_call(&mut self, callee: &Expression<'a>, span: Span) {
+        let Some(usage) = &mut self.commonjs_usage else { return };
+        if matches!(callee, Expression::Identifier(ident) if ident.name == "require") {
+            record(&mut usage.require, span);
+        }
+    }
+
+    /// `module.exports`, `exports.foo`
+    #[cold]
+    pub(crate) fn detect_commonjs_member(
+        &mut self,
+        object: &Expression<'a>,
+        property: &IdentifierName<'a>,
+        span: Span,
+    ) {
+        let Some(usage) = &mut self.commonjs_usage else { return };
+        let Expression::Identifier(ident) = object else { return };
+        if ident.name == "module" && property.name == "exports" {
+            record(&mut usage.module_exports, span);
+        } else if ident.name == "exports" {
+            record(&mut usage.exports, span);
+        }
+    }
+}
@@ -47,6 +47,9 @@ bitflags! {
         ///   * ambient variable declaration => `declare var $: any`
         ///   * ambient class declaration => `declare class C { foo(); } , etc..`
         const Ambient = 1 << 6;
+
+        /// Strict mode code: modules, class bodies, and code after a `"use strict"` directive.
+        const StrictMode = 1 << 7;
     }
 }
 
@@ -92,6 +95,11 @@ impl Context {
         self.contains(Self::Ambient)
     }
 
+    #[inline]
+    pub(crate) fn has_strict_mode(self) -> bool {
+        self.contains(Self::StrictMode)
+    }
+
     #[inline]
     pub(crate) fn union_await_if(self, include: bool) -> Self {
         self.union_if(Self::Await, include)
@@ -142,6 +150,11 @@ impl Context {
         self.and(Self::Ambient, include)
     }
 
+    #[inline]
+    pub(crate) fn and_strict_mode(self, include: bool) -> Self {
+        self.and(Self::StrictMode, include)
+    }
+
     #[inline]
     fn and(self, flag: Self, set: bool) -> Self {
         if set { self | flag } else { self - flag }
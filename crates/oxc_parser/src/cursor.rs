@@ -1,12 +1,12 @@
 //! Code related to navigating `Token`s from the lexer
 
-use oxc_allocator::Vec;
+use oxc_allocator::{Dummy, Vec};
 use oxc_ast::ast::{BindingRestElement, RegExpFlags};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
 
 use crate::{
-    Context, ParserImpl, diagnostics,
+    Context, ContextualKeyword, ParserImpl, ShapeCounts, diagnostics,
     error_handler::FatalError,
     lexer::{Kind, LexerCheckpoint, LexerContext, Token},
 };
@@ -17,7 +17,15 @@ pub struct ParserCheckpoint<'a> {
     cur_token: Token,
     prev_span_end: u32,
     errors_pos: usize,
+    deferred_strict_mode_errors_len: usize,
     fatal_error: Option<FatalError>,
+    contextual_keywords_len: usize,
+    token_gaps_len: usize,
+    recovered: bool,
+    recovered_spans_len: usize,
+    fixes_len: usize,
+    unclosed_delimiters_len: usize,
+    shape_counts: ShapeCounts,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -71,12 +79,12 @@ impl<'a> ParserImpl<'a> {
 
     /// Get current string
     pub(crate) fn cur_string(&self) -> &'a str {
-        self.lexer.get_string(self.token)
+        self.ast.intern(self.lexer.get_string(self.token))
     }
 
     /// Get current template string
     pub(crate) fn cur_template_string(&self) -> Option<&'a str> {
-        self.lexer.get_template_string(self.token.start())
+        self.lexer.get_template_string(self.token.start()).map(|s| self.ast.intern(s))
     }
 
     /// Checks if the current index has token `Kind`
@@ -101,17 +109,50 @@ impl<'a> ParserImpl<'a> {
         if self.token.escaped() && kind.is_any_keyword() {
             self.report_escaped_keyword(self.token.span());
         }
+        if self.options.collect_contextual_keywords {
+            self.record_contextual_keyword(kind);
+        }
+        if self.options.capture_token_trivia {
+            self.record_token_gap();
+        }
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_token();
     }
 
+    /// Record the current token if it is consumed as a contextual keyword.
+    ///
+    /// Contextual keywords used as identifiers are consumed with [`Kind::Ident`] via `bump_remap`,
+    /// so `kind` is only a keyword kind when the token is used as a keyword.
+    #[cold]
+    fn record_contextual_keyword(&mut self, kind: Kind) {
+        // `kind` differs from the current token after a failed `expect`.
+        let token = self.token;
+        if token.kind() == kind
+            && token.is_keyword_context_sensitive()
+            && let Some(keyword) = ContextualKeyword::from_kind(kind)
+        {
+            self.contextual_keywords.push((token.span(), keyword));
+        }
+    }
+
     /// Move to the next `JSXChild`
     /// Checks if the current token is escaped if it is a keyword
     pub(crate) fn advance_for_jsx_child(&mut self) {
+        if self.options.capture_token_trivia {
+            self.record_token_gap();
+        }
         self.prev_token_end = self.token.end();
         self.token = self.lexer.next_jsx_child();
     }
 
+    /// Record the source between the previous token and the current one, if there is any.
+    fn record_token_gap(&mut self) {
+        let start = self.token.start();
+        if self.prev_token_end < start {
+            self.token_gaps.push(Span::new(self.prev_token_end, start));
+        }
+    }
+
     /// Advance and return true if we are at `Kind`, return false otherwise
     #[inline]
     #[must_use = "Use `bump` instead of `eat` if you are ignoring the return value"]
@@ -166,11 +207,27 @@ impl<'a> ParserImpl<'a> {
     #[inline(never)]
     fn handle_expect_failure(&mut self, expected_kind: Kind) {
         let range = self.cur_token().span();
-        let error =
-            diagnostics::expect_token(expected_kind.to_str(), self.cur_kind().to_str(), range);
+        let error = diagnostics::expect_token(
+            expected_kind.to_str(),
+            self.cur_kind().to_str(),
+            range,
+            self.previous_span_at_eof(),
+        );
         self.set_fatal_error(error);
     }
 
+    /// The last character of the previous token, if the current token is the end of the file,
+    /// so that errors for a missing token can point at where it was expected.
+    pub(crate) fn previous_span_at_eof(&self) -> Option<Span> {
+        if !matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) {
+            return None;
+        }
+        let end = self.prev_token_end;
+        let last_char = self.source_text[..end as usize].chars().next_back()?;
+        let len = u32::try_from(last_char.len_utf8()).unwrap_or(1);
+        Some(Span::new(end - len, end))
+    }
+
     /// # Errors
     #[inline]
     pub(crate) fn expect_without_advance(&mut self, kind: Kind) {
@@ -192,13 +249,33 @@ impl<'a> ParserImpl<'a> {
     #[inline]
     pub(crate) fn expect_closing(&mut self, kind: Kind, opening_span: Span) {
         if !self.at(kind) {
+            if self.options.recover_from_errors
+                && self.recover_mismatched_closing_delimiter(kind, opening_span)
+            {
+                return;
+            }
+            if self.options.recover_from_errors && self.close_unclosed_list() {
+                return;
+            }
+            if self.at_end_of_file() {
+                self.record_unclosed_delimiter(kind, opening_span);
+            }
             let range = self.cur_token().span();
-            let error = diagnostics::expect_closing(
-                kind.to_str(),
-                self.cur_kind().to_str(),
-                range,
-                opening_span,
-            );
+            let error = if matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) {
+                diagnostics::expect_closing_at_eof(
+                    kind.to_str(),
+                    self.cur_kind().to_str(),
+                    range,
+                    opening_span,
+                )
+            } else {
+                diagnostics::expect_closing(
+                    kind.to_str(),
+                    self.cur_kind().to_str(),
+                    range,
+                    opening_span,
+                )
+            };
             self.set_fatal_error(error);
         }
         self.advance(kind);
@@ -306,7 +383,15 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            deferred_strict_mode_errors_len: self.deferred_strict_mode_errors.len(),
             fatal_error: self.fatal_error.take(),
+            contextual_keywords_len: self.contextual_keywords.len(),
+            token_gaps_len: self.token_gaps.len(),
+            recovered: self.recovered,
+            recovered_spans_len: self.recovered_spans.len(),
+            fixes_len: self.fixes.len(),
+            unclosed_delimiters_len: self.unclosed_delimiters.len(),
+            shape_counts: self.shape_counts,
         }
     }
 
@@ -316,19 +401,48 @@ impl<'a> ParserImpl<'a> {
             cur_token: self.token,
             prev_span_end: self.prev_token_end,
             errors_pos: self.errors.len(),
+            deferred_strict_mode_errors_len: self.deferred_strict_mode_errors.len(),
             fatal_error: self.fatal_error.take(),
+            contextual_keywords_len: self.contextual_keywords.len(),
+            token_gaps_len: self.token_gaps.len(),
+            recovered: self.recovered,
+            recovered_spans_len: self.recovered_spans.len(),
+            fixes_len: self.fixes.len(),
+            unclosed_delimiters_len: self.unclosed_delimiters.len(),
+            shape_counts: self.shape_counts,
         }
     }
 
     pub(crate) fn rewind(&mut self, checkpoint: ParserCheckpoint<'a>) {
-        let ParserCheckpoint { lexer, cur_token, prev_span_end, errors_pos, fatal_error } =
-            checkpoint;
+        let ParserCheckpoint {
+            lexer,
+            cur_token,
+            prev_span_end,
+            errors_pos,
+            deferred_strict_mode_errors_len,
+            fatal_error,
+            contextual_keywords_len,
+            token_gaps_len,
+            recovered,
+            recovered_spans_len,
+            fixes_len,
+            unclosed_delimiters_len,
+            shape_counts,
+        } = checkpoint;
 
         self.lexer.rewind(lexer);
         self.token = cur_token;
         self.prev_token_end = prev_span_end;
         self.errors.truncate(errors_pos);
+        self.deferred_strict_mode_errors.truncate(deferred_strict_mode_errors_len);
         self.fatal_error = fatal_error;
+        self.contextual_keywords.truncate(contextual_keywords_len);
+        self.token_gaps.truncate(token_gaps_len);
+        self.recovered = recovered;
+        self.recovered_spans.truncate(recovered_spans_len);
+        self.fixes.truncate(fixes_len);
+        self.unclosed_delimiters.truncate(unclosed_delimiters_len);
+        self.shape_counts = shape_counts;
     }
 
     pub(crate) fn try_parse<T>(
@@ -393,23 +507,75 @@ impl<'a> ParserImpl<'a> {
         result
     }
 
-    pub(crate) fn parse_normal_list<F, T>(&mut self, open: Kind, close: Kind, f: F) -> Vec<'a, T>
+    /// Run `cb` one level deeper in the parse tree.
+    ///
+    /// Wraps the recursive productions (expressions, types, binding patterns, statements and JSX)
+    /// so that adversarially nested input reports a fatal error once
+    /// [`ParseOptions::max_nesting_depth`](crate::ParseOptions::max_nesting_depth) is exceeded,
+    /// if set, instead of overflowing the stack.
+    #[inline]
+    pub(crate) fn with_nesting_guard<F, T>(&mut self, cb: F) -> T
     where
-        F: Fn(&mut Self) -> T,
+        F: FnOnce(&mut Self) -> T,
+        T: Dummy<'a>,
+    {
+        if let Some(max_nesting_depth) = self.options.max_nesting_depth
+            && self.nesting_depth >= max_nesting_depth
+        {
+            let error = diagnostics::nesting_too_deep(max_nesting_depth, self.cur_token().span());
+            return self.fatal_error(error);
+        }
+        self.nesting_depth += 1;
+        if self.options.collect_stats {
+            self.max_nesting_depth_reached = self.max_nesting_depth_reached.max(self.nesting_depth);
+        }
+        let result = cb(self);
+        self.nesting_depth -= 1;
+        result
+    }
+
+    /// Parse the contents of a delimited construct which is closed by `close`.
+    ///
+    /// The delimiter is tracked so that [`ParserImpl::expect_closing`] can tell whether a
+    /// mismatched closing delimiter belongs to an enclosing construct.
+    pub(crate) fn with_open_delimiter<F, T>(&mut self, close: Kind, cb: F) -> T
+    where
+        F: FnOnce(&mut Self) -> T,
+    {
+        if !self.options.recover_from_errors {
+            return cb(self);
+        }
+        self.state.open_delimiters.push(close);
+        let result = cb(self);
+        self.state.open_delimiters.pop();
+        result
+    }
+
+    pub(crate) fn parse_normal_list<F, T>(
+        &mut self,
+        open: Kind,
+        close: Kind,
+        mut f: F,
+    ) -> Vec<'a, T>
+    where
+        F: FnMut(&mut Self) -> T,
     {
         let opening_span = self.cur_token().span();
         self.expect(open);
-        let mut list = self.ast.vec();
-        loop {
-            let kind = self.cur_kind();
-            if kind == close
-                || matches!(kind, Kind::Eof | Kind::Undetermined)
-                || self.fatal_error.is_some()
-            {
-                break;
+        let list = self.with_open_delimiter(close, |p| {
+            let mut list = p.ast.vec();
+            loop {
+                let kind = p.cur_kind();
+                if kind == close
+                    || matches!(kind, Kind::Eof | Kind::Undetermined)
+                    || p.fatal_error.is_some()
+                {
+                    break;
+                }
+                list.push(f(p));
             }
-            list.push(f(self));
-        }
+            list
+        });
         self.expect_closing(close, opening_span);
         list
     }
@@ -425,17 +591,20 @@ impl<'a> ParserImpl<'a> {
     {
         let opening_span = self.cur_token().span();
         self.expect(open);
-        let mut list = self.ast.vec();
-        loop {
-            if self.at(close) || self.has_fatal_error() {
-                break;
-            }
-            if let Some(e) = f(self) {
-                list.push(e);
-            } else {
-                break;
+        let list = self.with_open_delimiter(close, |p| {
+            let mut list = p.ast.vec();
+            loop {
+                if p.at(close) || p.has_fatal_error() {
+                    break;
+                }
+                if let Some(e) = f(p) {
+                    list.push(e);
+                } else {
+                    break;
+                }
             }
-        }
+            list
+        });
         self.expect_closing(close, opening_span);
         list
     }
@@ -447,6 +616,33 @@ impl<'a> ParserImpl<'a> {
         opening_span: Span,
         f: F,
     ) -> (Vec<'a, T>, Option<u32>)
+    where
+        F: Fn(&mut Self) -> T,
+    {
+        self.parse_delimited_list_impl(close, separator, opening_span, false, f)
+    }
+
+    /// [`Self::parse_delimited_list`] for the properties of an object literal, where a `;` is
+    /// taken as a mistyped `,` when recovering from errors, e.g. `({ a: 1; b: 2 })`.
+    pub(crate) fn parse_object_literal_list<F, T>(
+        &mut self,
+        opening_span: Span,
+        f: F,
+    ) -> (Vec<'a, T>, Option<u32>)
+    where
+        F: Fn(&mut Self) -> T,
+    {
+        self.parse_delimited_list_impl(Kind::RCurly, Kind::Comma, opening_span, true, f)
+    }
+
+    fn parse_delimited_list_impl<F, T>(
+        &mut self,
+        close: Kind,
+        separator: Kind,
+        opening_span: Span,
+        is_object_literal: bool,
+        f: F,
+    ) -> (Vec<'a, T>, Option<u32>)
     where
         F: Fn(&mut Self) -> T,
     {
@@ -468,7 +664,24 @@ impl<'a> ParserImpl<'a> {
             {
                 return (list, None);
             }
-            if !self.at(separator) {
+            if is_object_literal && kind == Kind::Semicolon && self.options.recover_from_errors {
+                self.record_recovery();
+                self.error(diagnostics::semicolon_in_object_literal(self.cur_token().span()));
+                self.suggest_fix(self.cur_token().span(), Kind::Comma.to_str());
+                self.bump_any(); // bump the mistyped `,`
+            } else if !self.at(separator) {
+                // `(a]`: leave the mismatched closing delimiter to the caller's `expect_closing`.
+                if self.options.recover_from_errors && kind.is_closing_delimiter() {
+                    return (list, None);
+                }
+                // `f(g(a, b;`
+                if self.options.recover_from_errors
+                    && close == Kind::RParen
+                    && kind == Kind::Semicolon
+                {
+                    self.recover_unclosed_list(opening_span);
+                    return (list, None);
+                }
                 self.set_fatal_error(diagnostics::expect_closing_or_separator(
                     close.to_str(),
                     separator.to_str(),
@@ -477,8 +690,9 @@ impl<'a> ParserImpl<'a> {
                     opening_span,
                 ));
                 return (list, None);
+            } else {
+                self.advance(separator);
             }
-            self.advance(separator);
             if self.cur_kind() == close {
                 let trailing_separator = self.prev_token_end - 1;
                 return (list, Some(trailing_separator));
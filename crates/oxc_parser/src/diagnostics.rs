@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_ast::ast::REGEXP_FLAGS_LIST;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_span::Span;
 
 use crate::modifiers::{Modifier, ModifierFlags, ModifierKind};
@@ -48,6 +48,35 @@ pub fn overlong_source() -> OxcDiagnostic {
     OxcDiagnostic::error("Source length exceeds 4 GiB limit")
 }
 
+#[cold]
+pub fn nesting_too_deep(max_nesting_depth: u32, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Maximum nesting depth of {max_nesting_depth} exceeded"))
+        .with_help("Reduce nesting, or increase `ParseOptions::max_nesting_depth`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn too_many_parameters(max_parameters: u32, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Maximum number of {max_parameters} parameters exceeded"))
+        .with_help("Reduce the number of parameters, or increase `ParseOptions::max_parameters`")
+        .with_label(span)
+}
+
+#[cold]
+#[cfg(not(feature = "regular_expression"))]
+pub fn regular_expression_feature_disabled() -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "`parse_regular_expression` has no effect because the `regular_expression` feature is disabled",
+    )
+}
+
+#[cold]
+pub fn placeholder_nodes_without_recovery() -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "`emit_placeholder_nodes` has no effect unless `recover_from_errors` is enabled",
+    )
+}
+
 #[cold]
 pub fn flow(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Flow is not supported").with_label(span)
@@ -108,9 +137,15 @@ pub fn jsx_in_non_jsx(span: Span) -> OxcDiagnostic {
 }
 
 #[cold]
-pub fn expect_token(x0: &str, x1: &str, span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Expected `{x0}` but found `{x1}`"))
-        .with_label(span.label(format!("`{x0}` expected")))
+pub fn expect_token(x0: &str, x1: &str, span: Span, previous_span: Option<Span>) -> OxcDiagnostic {
+    let error = OxcDiagnostic::error(format!("Expected `{x0}` but found `{x1}`"));
+    match previous_span {
+        Some(previous_span) => error.with_labels([
+            span.label(format!("`{x0}` expected")),
+            previous_span.primary_label("after this"),
+        ]),
+        None => error.with_label(span.label(format!("`{x0}` expected"))),
+    }
 }
 
 #[cold]
@@ -128,6 +163,51 @@ pub fn expect_closing(
     )
 }
 
+#[cold]
+pub fn expect_closing_at_eof(
+    expected_closing: &str,
+    actual: &str,
+    span: Span,
+    opening_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected `{expected_closing}` but found `{actual}`")).with_labels(
+        [
+            span.label(format!("`{expected_closing}` expected")),
+            opening_span.primary_label("Opened here, but never closed"),
+        ],
+    )
+}
+
+#[cold]
+pub fn unmatched_closing_delimiter(delimiter: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Unmatched `{delimiter}`"))
+        .with_label(span.label(format!("`{delimiter}` does not close anything")))
+}
+
+#[cold]
+pub fn mismatched_closing_delimiter(
+    expected_closing: &str,
+    opening: &str,
+    actual: &str,
+    span: Span,
+    opening_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Expected `{expected_closing}` to match this `{opening}` but found `{actual}`"
+    ))
+    .with_labels([
+        span.primary_label(format!("`{expected_closing}` expected")),
+        opening_span.label("Opened here"),
+    ])
+}
+
+#[cold]
+pub fn semicolon_in_object_literal(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Unexpected `;` in object literal")
+        .with_help("Did you mean `,`?")
+        .with_label(span.label("`,` expected"))
+}
+
 #[cold]
 pub fn expect_closing_or_separator(
     expected_closing: &str,
@@ -153,6 +233,14 @@ pub fn expect_conditional_alternative(x: &str, span: Span, question_span: Span)
     ])
 }
 
+#[cold]
+pub fn conditional_missing_branch(after: &str, span: Span, question_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected an expression after `{after}`")).with_labels([
+        span.primary_label("Expression expected"),
+        question_span.label("Conditional starts here"),
+    ])
+}
+
 #[cold]
 pub fn unexpected_trailing_comma(name: &'static str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("{name} may not have a trailing comma."))
@@ -175,6 +263,20 @@ pub fn invalid_character(x0: char, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Invalid Character `{x0}`")).with_label(span1)
 }
 
+#[cold]
+pub fn non_ascii_identifier_character(c: char, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Non-ASCII character `{c}` in identifier"))
+        .with_help("Identifiers are restricted to ASCII by `ParseOptions::identifier_validation`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn confusable_character(c: char, ascii: char, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid Character `{c}` (U+{:04X})", u32::from(c)))
+        .with_help(format!("Did you mean `{ascii}`?"))
+        .with_label(span)
+}
+
 #[cold]
 pub fn invalid_number_end(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Invalid characters after number").with_label(span)
@@ -197,6 +299,11 @@ pub fn reg_exp_flag(x0: char, span1: Span) -> OxcDiagnostic {
         .with_help(format!("The allowed flags are `{REGEXP_FLAGS_LIST}`"))
 }
 
+#[cold]
+pub fn reg_exp_flag_warning(x0: char, span: Span) -> OxcDiagnostic {
+    reg_exp_flag(x0, span).with_severity(Severity::Warning)
+}
+
 #[cold]
 pub fn reg_exp_flag_twice(x0: char, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Flag {x0} is mentioned twice in regular expression literal"))
@@ -267,6 +374,65 @@ pub fn lexical_declaration_single_statement(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn labeled_lexical_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("A lexical declaration cannot be labeled")
+        .with_help("Remove the label, or wrap the declaration in a block statement")
+        .with_label(span)
+}
+
+#[cold]
+pub fn body_expected(keyword: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected {keyword} body")).with_label(span.label("`{` expected"))
+}
+
+#[cold]
+pub fn with_statement(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("'with' statements are not allowed").with_label(span)
+}
+
+#[cold]
+pub fn legacy_octal_literal(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Legacy octal literals are not allowed")
+        .with_help("Use the `0o` prefix instead")
+        .with_label(span)
+}
+
+#[cold]
+pub fn legacy_octal_escape_sequence(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Octal escape sequences are not allowed")
+        .with_help("Use a `\\x` or `\\u` escape sequence instead")
+        .with_label(span)
+}
+
+#[cold]
+pub fn irregular_whitespace_in_token(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Irregular whitespace inside a token")
+        .with_help("Remove this character, which splits the token in two")
+        .with_label(span)
+}
+
+#[cold]
+pub fn irregular_whitespace_after_dot(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Irregular whitespace between `.` and a property name")
+        .with_help("Remove this character")
+        .with_label(span)
+}
+
+#[cold]
+pub fn html_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("HTML-like comments are not allowed")
+        .with_help("Use a `//` comment instead")
+        .with_label(span)
+}
+
+#[cold]
+pub fn object_destructuring_statement(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Object destructuring assignment must be wrapped in parentheses")
+        .with_help("A `{` at the start of a statement begins a block, use `({ a } = obj);` instead")
+        .with_label(span)
+}
+
 #[cold]
 pub fn async_function_declaration(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Async functions can only be declared at the top level or inside a block")
@@ -358,6 +524,22 @@ pub fn spread_last_element(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Spread must be last element").with_label(span)
 }
 
+#[cold]
+pub fn namespace_body_with_token(token: &str, span: Span) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::error("Namespace bodies are declared with `{ ... }` directly")
+        .with_label(span.label(format!("Remove this `{token}`")));
+    if token == "=" {
+        diagnostic.with_help("To import a module under a name, use `import x = require(...)`")
+    } else {
+        diagnostic
+    }
+}
+
+#[cold]
+pub fn spread_not_allowed_here(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("'...' is not allowed here").with_label(span)
+}
+
 #[cold]
 pub fn rest_element_trailing_comma(span: Span) -> OxcDiagnostic {
     unexpected_trailing_comma("A rest parameter or binding pattern", span)
@@ -419,6 +601,11 @@ pub fn for_loop_let_reserved_word(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn for_loop_head_keyword(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected `of`, `in`, or `;` in for-statement head").with_label(span)
+}
+
 #[cold]
 pub fn for_await(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("await can only be used in conjunction with `for...of` statements")
@@ -494,6 +681,59 @@ pub fn identifier_generator(x0: &str, span1: Span, looks_like_expression: bool)
     }
 }
 
+#[cold]
+pub fn await_binding_identifier(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "'await' cannot be used as a binding name inside an async function or module",
+    )
+    .with_label(span)
+}
+
+#[cold]
+pub fn yield_binding_identifier(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("'yield' cannot be used as a binding name inside a generator")
+        .with_label(span)
+}
+
+#[cold]
+pub fn strict_mode_reserved_word(reserved: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("'{reserved}' is a reserved word in strict mode"))
+        .with_help("Modules and classes are always strict mode code")
+        .with_label(span)
+}
+
+#[cold]
+pub fn expression_expected(span: Span) -> OxcDiagnostic {
+    ts_error("1109", "Expression expected.").with_label(span)
+}
+
+#[cold]
+pub fn type_expected(span: Span) -> OxcDiagnostic {
+    ts_error("1110", "Type expected.").with_label(span)
+}
+
+#[cold]
+pub fn type_predicate_type_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected type after `is`").with_label(span)
+}
+
+#[cold]
+pub fn type_operand_expected(operator: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected type after `{operator}`")).with_label(span)
+}
+
+#[cold]
+pub fn asserts_parameter_name_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected a parameter name or `this` after `asserts`").with_label(span)
+}
+
+#[cold]
+pub fn mapped_type_modifier_conflicting_signs(modifier: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("`+` and `-` cannot both be applied to the `{modifier}` modifier"))
+        .with_label(span)
+        .with_help(format!("Use `+{modifier}` to add the modifier, or `-{modifier}` to remove it"))
+}
+
 #[cold]
 pub fn identifier_expected(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Identifier expected.").with_label(span)
@@ -541,6 +781,12 @@ pub fn template_literal(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Bad escape sequence in untagged template literal").with_label(span)
 }
 
+#[cold]
+pub fn unclosed_template_substitution(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected `}` to close the template substitution")
+        .with_label(span.label("`}` expected"))
+}
+
 #[cold]
 pub fn empty_parenthesized_expression(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Empty parenthesized expression").with_label(span)
@@ -554,6 +800,94 @@ pub fn illegal_newline(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
     ])
 }
 
+#[cold]
+pub fn explicit_resource_management(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Explicit resource management is not supported by older syntax levels")
+        .with_help("Lower `using` and `await using` declarations with a transform")
+        .with_label(span)
+}
+
+#[cold]
+pub fn suspicious_comment(lines: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "This block comment spans {lines} lines and appears to contain code"
+    ))
+    .with_help("Did you forget to close an earlier comment with `*/`?")
+    .with_label(span)
+}
+
+#[cold]
+pub fn esm_import_in_commonjs(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This file is CommonJS (`.cts`), but uses an ES module import")
+        .with_help(
+            "Use an import assignment `import x = require('y')`, or rename the file to `.mts`",
+        )
+        .with_label(span)
+}
+
+#[cold]
+pub fn export_assignment_in_module(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`export =` cannot be used in an ES module")
+        .with_help("Use `export default`, or rename the file to `.cts`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn require_in_module(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("`require` is not defined in ES modules")
+        .with_help("Use `import`, or rename the file to `.cjs` or `.cts`")
+        .with_label(span)
+}
+
+#[cold]
+pub fn var_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Unexpected `var`")
+        .with_help("Use `let` or `const` instead")
+        .with_label(span)
+}
+
+#[cold]
+pub fn skipped_code_fence(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Skipped a markdown code fence around the source").with_label(span)
+}
+
+#[cold]
+pub fn skipped_script_tag(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Skipped a `<script>` tag around the source").with_label(span)
+}
+
+#[cold]
+pub fn sparse_array_element(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Empty element in sparse array")
+        .with_help("Use `undefined` if the element is meant to be empty")
+        .with_label(span)
+}
+
+#[cold]
+pub fn heritage_clause_trailing_comma(span: Span) -> OxcDiagnostic {
+    ts_error("1009", "Trailing comma not allowed.").with_label(span)
+}
+
+#[cold]
+pub fn throw_expression_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected expression after 'throw'").with_label(span)
+}
+
+#[cold]
+pub fn parameter_default_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected expression after '=' in parameter default").with_label(span)
+}
+
+#[cold]
+pub fn accessor_name_expected(keyword: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Expected property name after '{keyword}'")).with_label(span)
+}
+
+#[cold]
+pub fn do_while_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected 'while' after 'do' body").with_label(span)
+}
+
 #[cold]
 pub fn optional_chain_tagged_template(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Tagged template expressions are not permitted in an optional chain")
@@ -601,6 +935,13 @@ pub fn unexpected_super(span: Span) -> OxcDiagnostic {
         .with_label(span)
 }
 
+#[cold]
+pub fn class_declaration_name(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Class declaration requires a name")
+        .with_help("Class name is required in class declaration or named export")
+        .with_label(span)
+}
+
 #[cold]
 pub fn expect_function_name(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Expected function name")
@@ -700,6 +1041,16 @@ pub fn duplicate_export(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
     ])
 }
 
+#[cold]
+pub fn let_in_lexical_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("'let' cannot be used as a name in lexical declarations").with_label(span)
+}
+
+#[cold]
+pub fn import_meta_in_script(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("import.meta is only allowed in modules").with_label(span)
+}
+
 #[cold]
 pub fn import_meta(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("The only valid meta property for import is import.meta").with_label(span)
@@ -715,6 +1066,11 @@ pub fn private_in_private(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected right-hand side of private-in expression").with_label(span)
 }
 
+#[cold]
+pub fn private_name_outside_class(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Private names are only allowed in class bodies").with_label(span)
+}
+
 #[cold]
 pub fn import_arguments(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Dynamic imports can only accept a module specifier and an optional set of attributes as arguments").with_label(span)
@@ -725,6 +1081,13 @@ pub fn rest_element_property_name(span: Span) -> OxcDiagnostic {
     ts_error("2566", "A rest element cannot have a property name.").with_label(span)
 }
 
+#[cold]
+pub fn property_signature_initializer(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("interface members cannot have initializers")
+        .with_help("Remove the initializer, or use a class to provide default values")
+        .with_label(span)
+}
+
 #[cold]
 pub fn a_rest_element_cannot_have_an_initializer(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("A rest element cannot have an initializer.").with_label(span)
@@ -900,6 +1263,13 @@ pub fn computed_property_names_not_allowed_in_enums(span: Span) -> OxcDiagnostic
     ts_error("1164", "Computed property names are not allowed in enums.").with_label(span)
 }
 
+#[cold]
+pub fn enum_member_initializer_expected(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Expected an expression after `=` in enum member")
+        .with_label(span.label("Initializer expected after this `=`"))
+        .with_help("Add an initializer, or remove the `=`")
+}
+
 #[cold]
 pub fn enum_member_cannot_have_numeric_name(span: Span) -> OxcDiagnostic {
     ts_error("2452", "An enum member cannot have a numeric name.").with_label(span)
@@ -931,6 +1301,13 @@ pub fn import_equals_can_only_be_used_in_typescript_files(span: Span) -> OxcDiag
         .with_help("TypeScript transforms 'import ... =' to 'const ... ='")
 }
 
+#[cold]
+pub fn export_assignment_can_only_be_used_in_typescript_files(span: Span) -> OxcDiagnostic {
+    ts_error("8003", "'export =' can only be used in TypeScript files.")
+        .with_label(span)
+        .with_help("Use `export default` or `module.exports =` instead")
+}
+
 #[cold]
 pub fn index_signature_question_mark(span: Span) -> OxcDiagnostic {
     ts_error("1019", "An index signature parameter cannot have a question mark.").with_label(span)
@@ -973,6 +1350,13 @@ pub fn unexpected_export(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Unexpected export.").with_label(span)
 }
 
+#[cold]
+pub fn export_default_variable_declaration(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("A variable declaration cannot be the default export")
+        .with_label(span)
+        .with_help("Export it separately or export an expression")
+}
+
 #[cold]
 pub fn decorators_in_export_and_class(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Decorators may not appear after 'export' or 'export default' if they also appear before 'export'.").with_label(span)
@@ -1032,6 +1416,20 @@ pub fn implementation_in_ambient(span: Span) -> OxcDiagnostic {
     ts_error("1183", "An implementation cannot be declared in ambient contexts.").with_label(span)
 }
 
+#[cold]
+pub fn initializer_in_ambient(span: Span) -> OxcDiagnostic {
+    ts_error("1039", "Initializers are not allowed in ambient contexts.").with_label(span)
+}
+
+#[cold]
+pub fn invalid_ambient_const_initializer(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1254",
+        "A 'const' initializer in an ambient context must be a string or numeric literal or literal enum reference.",
+    )
+    .with_label(span)
+}
+
 #[cold]
 pub fn interface_implements(span: Span) -> OxcDiagnostic {
     ts_error("1176", "Interface declaration cannot have 'implements' clause.").with_label(span)
@@ -1120,6 +1518,13 @@ pub fn modifiers_cannot_appear_here(
     ts_error("1184", "Modifiers cannot appear here.").with_label(modifier.span)
 }
 
+#[cold]
+pub fn function_heritage_clause(keyword: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Unexpected `{keyword}` after function parameters"))
+        .with_label(span)
+        .with_help(format!("Only classes can have an `{keyword}` clause"))
+}
+
 #[cold]
 pub fn expect_function_body(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Expected function body")
@@ -1127,6 +1532,21 @@ pub fn expect_function_body(span: Span) -> OxcDiagnostic {
         .with_help("Add a function body (`{}`).")
 }
 
+#[cold]
+pub fn multiple_default_clauses(first: Span, duplicates: &[Span]) -> OxcDiagnostic {
+    OxcDiagnostic::error("More than one 'default' clause in a switch statement").with_labels(
+        std::iter::once(first.label("First 'default' clause"))
+            .chain(duplicates.iter().map(|span| span.label("Another 'default' clause"))),
+    )
+}
+
+#[cold]
+pub fn case_clause_outside_switch(keyword: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("`{keyword}` clause outside of a switch statement"))
+        .with_label(span)
+        .with_help("Move this clause into a `switch` statement, or remove it")
+}
+
 #[cold]
 pub fn expect_switch_clause(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Expected switch clause")
@@ -1155,6 +1575,22 @@ pub fn identifier_expected_jsx_no_hyphen(span: Span) -> OxcDiagnostic {
         .with_help("Remove the hyphen from the identifier")
 }
 
+#[cold]
+pub fn jsx_namespaced_name(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("JSX namespaced names are not supported")
+        .with_label(span)
+        .with_help("Use a name without a namespace, e.g. `xlinkHref` instead of `xlink:href`")
+}
+
+#[cold]
+pub fn tsx_generic_arrow_function(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "generic arrow functions in .tsx require a trailing comma in the type parameter list (`<T,>`)",
+    )
+    .with_label(span)
+    .with_help("Add a trailing comma, e.g. `<T,>(x: T) => x`, so that `<T>` is not parsed as JSX")
+}
+
 #[cold]
 pub fn jsx_attribute_value_empty_expression(span: Span) -> OxcDiagnostic {
     ts_error("17000", "JSX attributes must only be assigned a non-empty 'expression'.")
@@ -1185,3 +1621,60 @@ pub fn only_default_import_allowed_in_source_phase(span: Span) -> OxcDiagnostic
     OxcDiagnostic::error("Only a single default import is allowed in a source phase import.")
         .with_label(span)
 }
+
+#[cold]
+pub fn json_identifier(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Identifiers are not allowed in {format}"))
+        .with_label(span)
+        .with_help("Wrap this with double quotes to make it a string")
+}
+
+#[cold]
+pub fn json_unsupported_expression(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!(
+        "Only strings, numbers, booleans, `null`, arrays and objects are allowed in {format}"
+    ))
+    .with_label(span)
+}
+
+#[cold]
+pub fn json_invalid_number(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid number in {format}")).with_label(span)
+}
+
+#[cold]
+pub fn json_invalid_sign(format: &str, sign: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("`{sign}` is only allowed before a number in {format}"))
+        .with_label(span)
+}
+
+#[cold]
+pub fn json_plus_sign(format: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Numbers cannot have a leading `+` in {format}"))
+        .with_label(span)
+        .with_help("Remove the `+`")
+}
+
+#[cold]
+pub fn json_single_quoted_string(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Strings must use double quotes in JSON")
+        .with_label(span)
+        .with_help("Replace the single quotes with double quotes")
+}
+
+#[cold]
+pub fn json_invalid_property_key(format: &str, expected: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Property keys in {format} must be {expected}")).with_label(span)
+}
+
+#[cold]
+pub fn json_trailing_comma(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Trailing commas are not allowed in JSON")
+        .with_label(span)
+        .with_help("Remove the trailing comma here")
+}
+
+#[cold]
+pub fn json_comment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error("Comments are not allowed in JSON").with_label(span)
+}
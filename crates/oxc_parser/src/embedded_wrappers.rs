@@ -0,0 +1,63 @@
+//! Wrapper lines around source pasted from documentation, see
+//! [`crate::ParseOptions::skip_embedded_wrappers`].
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+use crate::diagnostics;
+
+/// Find a wrapper line at the very start and at the very end of `source_text`.
+///
+/// Returns the offset where the program starts (after the opening line, including its line
+/// break), the offset where it ends (at the start of the closing line), and a warning for each
+/// skipped line.
+pub fn find_embedded_wrappers(source_text: &str) -> (usize, usize, Vec<OxcDiagnostic>) {
+    let mut warnings = vec![];
+
+    let first_line_end = source_text.find('\n').unwrap_or(source_text.len());
+    let first_line = &source_text[..first_line_end];
+    let start = if is_opening_wrapper(first_line.trim()) {
+        warnings.push(skipped(first_line, 0));
+        (first_line_end + 1).min(source_text.len())
+    } else {
+        0
+    };
+
+    let trimmed = source_text[start..].trim_end();
+    let last_line_start = trimmed.rfind('\n').map_or(0, |i| i + 1);
+    let last_line = &trimmed[last_line_start..];
+    let end = if is_closing_wrapper(last_line.trim()) {
+        let last_line_start = start + last_line_start;
+        warnings.push(skipped(last_line, last_line_start));
+        last_line_start
+    } else {
+        source_text.len()
+    };
+
+    (start, end, warnings)
+}
+
+/// ```` ``` ```` with an optional language tag, or a `<script ...>` tag.
+fn is_opening_wrapper(line: &str) -> bool {
+    if let Some(tag) = line.strip_prefix("```") {
+        return tag.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'+' | b'_'));
+    }
+    line.strip_prefix("<script").is_some_and(|rest| {
+        rest.ends_with('>') && (rest == ">" || rest.starts_with(|c: char| c.is_ascii_whitespace()))
+    })
+}
+
+/// ```` ``` ```` or `</script>`.
+fn is_closing_wrapper(line: &str) -> bool {
+    line == "```" || line == "</script>"
+}
+
+fn skipped(line: &str, start: usize) -> OxcDiagnostic {
+    #[expect(clippy::cast_possible_truncation)]
+    let span = Span::sized(start as u32, line.trim_end().len() as u32);
+    if line.trim_start().starts_with('<') {
+        diagnostics::skipped_script_tag(span)
+    } else {
+        diagnostics::skipped_code_fence(span)
+    }
+}
@@ -1,10 +1,17 @@
 //! Code related to error handling.
 
 use oxc_allocator::Dummy;
-use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::Span;
+use oxc_ast::ast::{
+    BindingIdentifier, Expression, IdentifierName, IdentifierReference, PropertyKey,
+    SimpleAssignmentTarget, TSType,
+};
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_span::{Atom, CompactStr, GetSpan, Span};
 
-use crate::{ParserImpl, diagnostics, lexer::Kind};
+use crate::{
+    ParserImpl, SuggestedFix, UnterminatedConstruct, UnterminatedConstructKind, diagnostics,
+    lexer::Kind,
+};
 
 /// Fatal parsing error.
 #[derive(Debug, Clone)]
@@ -13,6 +20,8 @@ pub struct FatalError {
     pub error: OxcDiagnostic,
     /// Length of `errors` at time fatal error is recorded
     pub errors_len: usize,
+    /// Whether the current token ended at the end of the file when the fatal error was recorded
+    pub at_end_of_file: bool,
 }
 
 impl<'a> ParserImpl<'a> {
@@ -57,6 +66,19 @@ impl<'a> ParserImpl<'a> {
         self.errors.push(error);
     }
 
+    /// Push a Syntax Error which only applies to strict mode code.
+    ///
+    /// An unambiguous source is strict once module syntax is found, which may come later in the
+    /// file, so the error is held back until then.
+    #[cold]
+    pub(crate) fn strict_mode_error(&mut self, error: OxcDiagnostic) {
+        if self.ctx.has_strict_mode() {
+            self.error(error);
+        } else if self.source_type.is_unambiguous() {
+            self.deferred_strict_mode_errors.push(error);
+        }
+    }
+
     /// Count of all parser and lexer errors.
     pub(crate) fn errors_count(&self) -> usize {
         self.errors.len() + self.lexer.errors.len()
@@ -66,8 +88,10 @@ impl<'a> ParserImpl<'a> {
     #[cold]
     pub(crate) fn set_fatal_error(&mut self, error: OxcDiagnostic) {
         if self.fatal_error.is_none() {
+            let at_end_of_file = self.at_end_of_file();
             self.lexer.advance_to_end();
-            self.fatal_error = Some(FatalError { error, errors_len: self.errors.len() });
+            self.fatal_error =
+                Some(FatalError { error, errors_len: self.errors.len(), at_end_of_file });
         }
     }
 
@@ -80,6 +104,486 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn has_fatal_error(&self) -> bool {
         matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined) || self.fatal_error.is_some()
     }
+
+    /// Whether parsing has reached the end of the file, either at the current token, or at the
+    /// token where the fatal error was recorded.
+    pub(crate) fn at_end_of_file(&self) -> bool {
+        match &self.fatal_error {
+            Some(fatal_error) => fatal_error.at_end_of_file,
+            None => {
+                matches!(self.cur_kind(), Kind::Eof | Kind::Undetermined)
+                    && self.lexer.remaining().is_empty()
+            }
+        }
+    }
+
+    /// Record a brace, parenthesis or bracket which is unclosed at the end of the file,
+    /// see [`crate::ParserReturn::unterminated_constructs`].
+    #[cold]
+    pub(crate) fn record_unclosed_delimiter(&mut self, close: Kind, opening_span: Span) {
+        let kind = match close {
+            Kind::RCurly => UnterminatedConstructKind::Brace,
+            Kind::RParen => UnterminatedConstructKind::Paren,
+            Kind::RBrack => UnterminatedConstructKind::Bracket,
+            _ => return,
+        };
+        self.unclosed_delimiters.push(UnterminatedConstruct { kind, span: opening_span });
+    }
+
+    /// Report a `...` where a spread is not allowed, e.g. `return ...values;`, and skip it,
+    /// so the following expression or binding is parsed as if it were not there.
+    #[cold]
+    pub(crate) fn recover_stray_spread(&mut self) {
+        self.record_recovery();
+        self.error(diagnostics::spread_not_allowed_here(self.cur_token().span()));
+        self.bump_any();
+    }
+
+    /// Report a `=>` or `=` between a namespace name and its body, e.g. `namespace A => { }`,
+    /// and skip it, so the block is parsed as the namespace body.
+    #[cold]
+    pub(crate) fn recover_namespace_body_token(&mut self) {
+        self.record_recovery();
+        let token = self.cur_token();
+        self.error(diagnostics::namespace_body_with_token(token.kind().to_str(), token.span()));
+        self.bump_any();
+    }
+}
+
+// ==================== Placeholder Nodes ====================
+//
+// Some recoveries need a node where the source has none (e.g. the missing operand in `a + ;`),
+// or where the source has a node of the wrong kind (e.g. the assignment target in `1 = x`).
+// These are only attempted when both `ParseOptions::recover_from_errors` and
+// `ParseOptions::emit_placeholder_nodes` are enabled, otherwise the error stays fatal.
+//
+// A placeholder is always an identifier with an empty name, or the `unknown` keyword where a
+// type is needed (`any` for a tuple element, as in TypeScript). It spans the source it stands in
+// for, or is empty if there is none. Source text can't produce an empty identifier or keyword,
+// so placeholders can't be mistaken for real nodes.
+// Each recovery documents what it replaces by a placeholder.
+impl<'a> ParserImpl<'a> {
+    /// Whether error recovery may insert placeholder nodes.
+    #[inline]
+    pub(crate) fn can_insert_placeholder(&self) -> bool {
+        self.options.recover_from_errors && self.options.emit_placeholder_nodes
+    }
+
+    /// Create a placeholder [`IdentifierReference`] standing in for `span`.
+    pub(crate) fn placeholder_identifier_reference(&self, span: Span) -> IdentifierReference<'a> {
+        self.ast.identifier_reference(span, Atom::empty())
+    }
+
+    /// Create a placeholder [`Expression`] standing in for `span`.
+    pub(crate) fn placeholder_expression(&self, span: Span) -> Expression<'a> {
+        Expression::Identifier(self.alloc(self.placeholder_identifier_reference(span)))
+    }
+
+    /// Create a placeholder `unknown` [`TSType`] standing in for `span`.
+    pub(crate) fn placeholder_type(&self, span: Span) -> TSType<'a> {
+        self.ast.ts_type_unknown_keyword(span)
+    }
+
+    /// Report a missing expression before the current token, and return a placeholder for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_expression_operand(&mut self) -> IdentifierReference<'a> {
+        self.record_recovery();
+        self.error(diagnostics::expression_expected(self.cur_token().span()));
+        self.placeholder_identifier_reference(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing type before the current token, and return a placeholder for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_type(&mut self) -> TSType<'a> {
+        self.record_recovery();
+        self.error(diagnostics::type_expected(self.cur_token().span()));
+        self.placeholder_type(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing element type in a named tuple member, e.g. `[a: ]`, and return `any` for
+    /// it, as TypeScript does.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_tuple_element_type(&mut self) -> TSType<'a> {
+        self.record_recovery();
+        self.error(diagnostics::type_expected(self.cur_token().span()));
+        self.ast.ts_type_any_keyword(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing type after `is` in a type predicate, e.g. `x is ;`, and return a
+    /// placeholder for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_type_predicate_type(&mut self) -> TSType<'a> {
+        self.record_recovery();
+        self.error(diagnostics::type_predicate_type_expected(self.cur_token().span()));
+        self.placeholder_type(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing operand after a type operator, e.g. `keyof ;`, and return a placeholder
+    /// for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_type_operand(&mut self, operator: &'static str) -> TSType<'a> {
+        self.record_recovery();
+        self.error(diagnostics::type_operand_expected(operator, self.cur_token().span()));
+        self.placeholder_type(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing name after `typeof` in a type, e.g. `typeof ;`, and return a placeholder
+    /// for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_type_query_name(&mut self) -> IdentifierReference<'a> {
+        self.record_recovery();
+        self.error(diagnostics::type_operand_expected("typeof", self.cur_token().span()));
+        self.placeholder_identifier_reference(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a token after `asserts` which is not a parameter name, e.g. `asserts 1`, and return
+    /// a placeholder standing in for it.
+    ///
+    /// Consumes the offending token.
+    #[cold]
+    pub(crate) fn recover_invalid_asserts_parameter_name(&mut self) -> IdentifierName<'a> {
+        self.record_recovery();
+        let span = self.cur_token().span();
+        self.error(diagnostics::asserts_parameter_name_expected(span));
+        self.bump_any();
+        self.ast.identifier_name(span, Atom::empty())
+    }
+
+    /// Report a missing `:` and alternate in a conditional expression, e.g. `cond ? a;`,
+    /// and return a placeholder for the alternate.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_conditional_alternative(
+        &mut self,
+        question_span: Span,
+    ) -> Expression<'a> {
+        self.record_recovery();
+        self.error(diagnostics::expect_conditional_alternative(
+            self.cur_kind().to_str(),
+            self.cur_token().span(),
+            question_span,
+        ));
+        self.placeholder_expression(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing consequent or alternate in a conditional expression, e.g. `cond ? : b`,
+    /// where `after` is the `?` or `:` before it, and return a placeholder for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_conditional_branch(
+        &mut self,
+        after: Kind,
+        question_span: Span,
+    ) -> Expression<'a> {
+        self.record_recovery();
+        self.error(diagnostics::conditional_missing_branch(
+            after.to_str(),
+            self.cur_token().span(),
+            question_span,
+        ));
+        self.placeholder_expression(Span::empty(self.prev_token_end))
+    }
+
+    /// Report a missing accessor name after `get` or `set`, and return a placeholder key for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_accessor_name(&mut self, keyword: &str) -> PropertyKey<'a> {
+        self.record_recovery();
+        self.error(diagnostics::accessor_name_expected(keyword, self.cur_token().span()));
+        let ident = self.ast.identifier_name(Span::empty(self.prev_token_end), Atom::empty());
+        PropertyKey::StaticIdentifier(self.alloc(ident))
+    }
+
+    /// Report a missing class name in a class declaration, e.g. `class extends B {}`, and return
+    /// a placeholder for it.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_class_name(&mut self) -> BindingIdentifier<'a> {
+        self.record_recovery();
+        self.error(diagnostics::class_declaration_name(self.cur_token().span()));
+        self.ast.binding_identifier(Span::empty(self.prev_token_end), Atom::empty())
+    }
+
+    /// Report a missing property name after `.`, e.g. `a.;`, and return a placeholder for it.
+    ///
+    /// In a template substitution, a `` ` `` after the `.` is taken to close the template, as in
+    /// `` `${a.` ``, and is re-lexed as its tail.
+    ///
+    /// Does not consume the current token, so the caller can continue with it.
+    #[cold]
+    pub(crate) fn recover_missing_member_name(&mut self) -> IdentifierName<'a> {
+        self.record_recovery();
+        let span = self.cur_token().span();
+        let name = self.ast.identifier_name(Span::empty(self.prev_token_end), Atom::empty());
+        if self.state.template_substitution != Some(self.state.open_delimiters.len())
+            || self.source_text.as_bytes().get(span.start as usize) != Some(&b'`')
+        {
+            self.error(diagnostics::identifier_expected(span));
+        } else {
+            self.error(diagnostics::unclosed_template_substitution(Span::empty(span.start)));
+            if self.cur_kind() == Kind::Undetermined {
+                // Discard the unterminated template error, the `` ` `` is not an opening one.
+                self.lexer.errors.pop();
+                self.lexer.unterminated_constructs.pop();
+            }
+            self.token = self.lexer.re_lex_template_tail_at(span.start);
+        }
+        name
+    }
+
+    /// Report an invalid assignment target.
+    ///
+    /// When placeholders are allowed, the error is recoverable and a placeholder target
+    /// spanning the invalid expression is returned, so the assignment can still be built.
+    #[cold]
+    pub(crate) fn invalid_simple_assignment_target(
+        &mut self,
+        error: OxcDiagnostic,
+        span: Span,
+    ) -> SimpleAssignmentTarget<'a> {
+        if !self.can_insert_placeholder() {
+            return self.fatal_error(error);
+        }
+        self.record_recovery();
+        self.error(error);
+        let ident = self.placeholder_identifier_reference(span);
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(self.alloc(ident))
+    }
+}
+
+// ==================== Recovered Nodes ====================
+//
+// With `ParseOptions::track_recovered_nodes`, the span of the innermost statement, class element
+// or object member around each recovery action is collected for `ParserReturn::recovered_spans`.
+impl ParserImpl<'_> {
+    /// Record that a recovery action (a placeholder node, skipped tokens or an assumed missing
+    /// token) occurred in the node being parsed.
+    #[inline]
+    pub(crate) fn record_recovery(&mut self) {
+        self.recovered = true;
+    }
+
+    /// Parse a statement, class element or object member, and record its span if a recovery
+    /// action occurred inside it, but not inside a nested statement or member.
+    #[inline]
+    pub(crate) fn track_recovered_node<T: GetSpan>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        if !self.options.track_recovered_nodes {
+            return parse(self);
+        }
+        let outer_recovered = std::mem::take(&mut self.recovered);
+        let node = parse(self);
+        if self.recovered {
+            self.recovered_spans.push(node.span());
+        }
+        self.recovered = outer_recovered;
+        node
+    }
+
+    /// Record the current token, which is skipped after a statement starting at `start` did not
+    /// consume it, in place of that statement, which is dropped.
+    #[cold]
+    pub(crate) fn record_skipped_statement_token(&mut self, start: u32) {
+        if !self.options.track_recovered_nodes {
+            return;
+        }
+        while self.recovered_spans.last().is_some_and(|span| span.start >= start) {
+            self.recovered_spans.pop();
+        }
+        self.recovered_spans.push(self.cur_token().span());
+    }
+}
+
+// ==================== Suggested Fixes ====================
+//
+// Recoveries which assume an edit to the source, such as a `;` in an object literal being a `,`,
+// suggest that edit as a fix for the error they report, see `ParserReturn::fixes`.
+// A fix is only suggested where the edited source parses the same without the error.
+impl ParserImpl<'_> {
+    /// Suggest replacing `replace_span` with `replacement` to fix the last error reported.
+    pub(crate) fn suggest_fix(&mut self, replace_span: Span, replacement: &str) {
+        debug_assert!(!self.errors.is_empty());
+        self.fixes.push(SuggestedFix {
+            error_index: self.errors.len() - 1,
+            replace_span,
+            replacement: CompactStr::new(replacement),
+        });
+    }
+}
+
+// ==================== Mismatched Closing Delimiters ====================
+//
+// `(a + b]`, `arr[i).push(1)`: a closing delimiter of the wrong kind is reported once, labeling
+// both delimiters, instead of cascading into further errors.
+// These are only attempted when `ParseOptions::recover_from_errors` is enabled.
+impl ParserImpl<'_> {
+    /// Recover from a closing delimiter which is not the expected `close`.
+    ///
+    /// * If it is transposed with the `close` after it (`({ a )}`), the two are swapped, and the
+    ///   error is reported once.
+    /// * If it closes an enclosing construct (`f(a[i)`), this construct is closed implicitly,
+    ///   and the delimiter is left for the enclosing construct.
+    /// * If `close` follows it (`(a + b])`), it is skipped as a stray delimiter.
+    /// * Otherwise, it is consumed as a mistyped `close` (`arr[i).push(1)`).
+    ///
+    /// Returns `false` if the current token is not a closing delimiter.
+    #[cold]
+    pub(crate) fn recover_mismatched_closing_delimiter(
+        &mut self,
+        close: Kind,
+        opening_span: Span,
+    ) -> bool {
+        let found = self.cur_kind();
+        if self.fatal_error.is_some()
+            || !close.is_closing_delimiter()
+            || !found.is_closing_delimiter()
+        {
+            return false;
+        }
+        self.record_recovery();
+        if self.state.transposed_delimiter.take() == Some(self.cur_token().start()) {
+            self.bump_any();
+            return true;
+        }
+        let opening = match close {
+            Kind::RParen => Kind::LParen,
+            Kind::RBrack => Kind::LBrack,
+            _ => Kind::LCurly,
+        };
+        let found_span = self.cur_token().span();
+        self.error(diagnostics::mismatched_closing_delimiter(
+            close.to_str(),
+            opening.to_str(),
+            found.to_str(),
+            found_span,
+            opening_span,
+        ));
+        let next = self.lexer.peek_token();
+        if self.state.open_delimiters.last() == Some(&found) && next.kind() == close {
+            if next.start() == found_span.end {
+                let swapped = [close.to_str(), found.to_str()].concat();
+                self.suggest_fix(Span::new(found_span.start, next.end()), &swapped);
+            }
+            self.state.transposed_delimiter = Some(next.start());
+            self.bump_any();
+            return true;
+        }
+        if self.state.open_delimiters.contains(&found) {
+            self.suggest_fix(Span::empty(found_span.start), close.to_str());
+            return true;
+        }
+        if next.kind() == close {
+            self.suggest_fix(found_span, "");
+            self.bump_any(); // bump the stray delimiter
+        } else {
+            self.suggest_fix(found_span, close.to_str());
+        }
+        self.bump_any();
+        true
+    }
+}
+
+// ==================== Stray Closing Delimiters ====================
+//
+// `foo());`, `items]`: a closing delimiter left over at the end of an expression statement is
+// reported and skipped, so that the statement ends there instead of stopping parsing.
+// These are only attempted when `ParseOptions::recover_from_errors` is enabled.
+impl ParserImpl<'_> {
+    /// Skip the `)` or `]` after an expression statement, and any repeats of it, unless an
+    /// enclosing construct is open with it (`f(() => { a() )`).
+    #[cold]
+    pub(crate) fn recover_stray_closing_delimiter(&mut self) {
+        let found = self.cur_kind();
+        if self.fatal_error.is_some() || self.state.open_delimiters.contains(&found) {
+            return;
+        }
+        self.record_recovery();
+        let start = self.start_span();
+        while self.at(found) {
+            self.bump_any();
+        }
+        let span = self.end_span(start);
+        self.error(diagnostics::unmatched_closing_delimiter(found.to_str(), span));
+        self.suggest_fix(span, "");
+    }
+}
+
+// ==================== Unclosed Lists ====================
+//
+// `f(g(a, b;`: a `;` in a parenthesized list closes it and all enclosing lists which are also
+// missing their `)`. This is reported once per `;`, labeling each opening `(`, instead of
+// cascading into an error for each list.
+// These are only attempted when `ParseOptions::recover_from_errors` is enabled.
+impl ParserImpl<'_> {
+    /// Recover from a `;` where a `,` or `)` is expected in the list opened at `opening_span`.
+    ///
+    /// The list is closed before the `;`, unless `,` or `)` follows it (`f(g(a;, b)`), in which
+    /// case the `;` is taken as a mistyped `)` and consumed by [`Self::close_unclosed_list`].
+    #[cold]
+    pub(crate) fn recover_unclosed_list(&mut self, opening_span: Span) {
+        self.record_recovery();
+        if let Some(error) = self.unclosed_list_error() {
+            let labels = error.labels.get_or_insert_default();
+            labels.push(opening_span.label("Also unclosed"));
+            return;
+        }
+        let span = self.cur_token().span();
+        self.error(diagnostics::expect_closing_or_separator(
+            Kind::RParen.to_str(),
+            Kind::Comma.to_str(),
+            Kind::Semicolon.to_str(),
+            span,
+            opening_span,
+        ));
+        self.state.unclosed_lists = Some((span.start, self.errors.len() - 1));
+    }
+
+    /// Close a list at the `;` reported by [`Self::recover_unclosed_list`].
+    ///
+    /// Returns `false` if the current token is not that `;`.
+    #[cold]
+    pub(crate) fn close_unclosed_list(&mut self) -> bool {
+        if self.unclosed_list_error().is_none() {
+            return false;
+        }
+        if matches!(self.lexer.peek_token().kind(), Kind::Comma | Kind::RParen) {
+            self.state.unclosed_lists = None;
+            self.bump_any(); // bump the mistyped `)`
+        }
+        true
+    }
+
+    /// The error reported for the `;` at the current token, if any.
+    ///
+    /// The error is checked to still be there, as it is discarded when the parser rewinds.
+    fn unclosed_list_error(&mut self) -> Option<&mut OxcDiagnostic> {
+        let (start, index) = self.state.unclosed_lists?;
+        if start != self.cur_token().start() {
+            return None;
+        }
+        self.errors.get_mut(index).filter(|error| {
+            error.labels.as_ref().and_then(|labels| labels.first()).map(LabeledSpan::offset)
+                == Some(start as usize)
+        })
+    }
 }
 
 // ==================== Merge Conflict Marker Detection ====================
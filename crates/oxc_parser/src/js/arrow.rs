@@ -1,6 +1,6 @@
 use oxc_allocator::Box;
 use oxc_ast::{NONE, ast::*};
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::precedence::Precedence;
 
 use super::{FunctionKind, Tristate};
@@ -20,6 +20,11 @@ impl<'a> ParserImpl<'a> {
         allow_return_type_in_arrow_function: bool,
     ) -> Option<Expression<'a>> {
         match self.is_parenthesized_arrow_function_expression() {
+            Tristate::False if self.is_ts && self.source_type.is_jsx() && self.at(Kind::LAngle) => {
+                self.try_parse_tsx_generic_arrow_function_expression(
+                    allow_return_type_in_arrow_function,
+                )
+            }
             Tristate::False => None,
             Tristate::True => Some(self.parse_parenthesized_arrow_function_expression(
                 /* allow_return_type_in_arrow_function */ true,
@@ -147,6 +152,10 @@ impl<'a> ParserImpl<'a> {
                             Kind::Question => {
                                 self.bump_any();
                                 let fourth = self.cur_kind();
+                                // "(a ? : b)" could also be a conditional missing its consequent.
+                                if fourth == Kind::Colon && self.can_insert_placeholder() {
+                                    return Tristate::Maybe;
+                                }
                                 if matches!(
                                     fourth,
                                     Kind::Colon | Kind::Comma | Kind::Eq | Kind::RParen
@@ -199,6 +208,49 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    /// In TSX, `<T>(x: T) => x` is parsed as the JSX element `<T>`, as it is in TypeScript.
+    /// Detect `<T>(` (or `<const T>(`) followed by arrow function parameters and `=>`, and report
+    /// that a trailing comma is required. In recovery mode, the generic arrow function is kept.
+    ///
+    /// JSX text cannot contain `>`, so this is never a valid JSX element. JSX elements such as
+    /// `<T>(text)</T>` are not followed by `=>`, and are left untouched.
+    #[cold]
+    fn try_parse_tsx_generic_arrow_function_expression(
+        &mut self,
+        allow_return_type_in_arrow_function: bool,
+    ) -> Option<Expression<'a>> {
+        let is_generic_arrow_start = self.lookahead(|parser| {
+            parser.bump_any(); // bump `<`
+            parser.bump(Kind::Const);
+            if !parser.cur_kind().is_binding_identifier() {
+                return false;
+            }
+            parser.bump_any();
+            parser.eat(Kind::RAngle) && parser.at(Kind::LParen)
+        });
+        if !is_generic_arrow_start {
+            return None;
+        }
+
+        let checkpoint = self.checkpoint_with_error_recovery();
+        let head = self.parse_parenthesized_arrow_function_head(/* is_definite_arrow */ false);
+        if self.has_fatal_error() {
+            self.rewind(checkpoint);
+            return None;
+        }
+
+        // `<T>` is always parsed as type parameters here.
+        let span = head.type_parameters.as_ref().map_or(Span::empty(head.span), |t| t.span);
+        let error = diagnostics::tsx_generic_arrow_function(span);
+        if !self.options.recover_from_errors {
+            self.rewind(checkpoint);
+            return Some(self.fatal_error(error));
+        }
+        self.record_recovery();
+        self.error(error);
+        Some(self.parse_arrow_function_expression_body(head, allow_return_type_in_arrow_function))
+    }
+
     fn is_un_parenthesized_async_arrow_function_worker(&mut self) -> bool {
         // Use lookahead to avoid checkpoint/rewind
         self.lookahead(|parser| {
@@ -245,7 +297,12 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
-    fn parse_parenthesized_arrow_function_head(&mut self) -> ArrowFunctionHead<'a> {
+    /// `is_definite_arrow` is set when the head was determined to be an arrow function without
+    /// parsing it, so that it is not rewound on errors.
+    fn parse_parenthesized_arrow_function_head(
+        &mut self,
+        is_definite_arrow: bool,
+    ) -> ArrowFunctionHead<'a> {
         let span = self.start_span();
         let r#async = self.eat(Kind::Async);
 
@@ -264,19 +321,69 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::ts_arrow_function_this_parameter(this_param.span));
         }
 
-        let return_type = if self.is_ts { self.parse_ts_return_type_annotation() } else { None };
+        let (return_type, expect_arrow) = if !self.is_ts {
+            (None, true)
+        } else if is_definite_arrow
+            && self.options.recover_from_errors
+            && self.at(Kind::Colon)
+            && self.lexer.peek_token().kind() == Kind::LCurly
+        {
+            self.parse_arrow_function_type_literal_return_type()
+        } else {
+            (self.parse_ts_return_type_annotation(), true)
+        };
 
         self.ctx = self.ctx.and_await(has_await);
 
-        if self.cur_token().is_on_new_line() {
-            self.error(diagnostics::lineterminator_before_arrow(self.cur_token().span()));
+        if expect_arrow {
+            if self.cur_token().is_on_new_line() {
+                self.error(diagnostics::lineterminator_before_arrow(self.cur_token().span()));
+            }
+            self.expect(Kind::Arrow);
         }
 
-        self.expect(Kind::Arrow);
-
         ArrowFunctionHead { type_parameters, params, return_type, r#async, span }
     }
 
+    /// Parse a return type starting with `{`, which may be the body of the arrow function instead,
+    /// e.g. `(x: T): { return x; }`.
+    ///
+    /// * `(x: T): { a: T } => x` is a type literal return type.
+    /// * `(x: T): { a: T } { return x; }` is a type literal return type with a missing `=>`.
+    /// * Otherwise the type literal is rewound, and the `{` is parsed as the body with a
+    ///   missing return type.
+    ///
+    /// Returns whether `=>` is still expected.
+    #[cold]
+    fn parse_arrow_function_type_literal_return_type(
+        &mut self,
+    ) -> (Option<Box<'a, TSTypeAnnotation<'a>>>, bool) {
+        let checkpoint = self.checkpoint_with_error_recovery();
+        let return_type = self.parse_ts_return_type_annotation();
+        if !self.has_fatal_error() {
+            match self.cur_kind() {
+                Kind::Arrow => return (return_type, true),
+                Kind::LCurly if !self.cur_token().is_on_new_line() => {
+                    self.record_recovery();
+                    self.error(diagnostics::expect_token(
+                        Kind::Arrow.to_str(),
+                        Kind::LCurly.to_str(),
+                        self.cur_token().span(),
+                        None,
+                    ));
+                    self.suggest_fix(Span::empty(self.cur_token().start()), "=> ");
+                    return (return_type, false);
+                }
+                _ => {}
+            }
+        }
+        self.rewind(checkpoint);
+        self.bump_any(); // bump `:`
+        self.record_recovery();
+        self.error(diagnostics::type_expected(self.cur_token().span()));
+        (None, false)
+    }
+
     /// [ConciseBody](https://tc39.es/ecma262/#prod-ConciseBody)
     ///     [lookahead ≠ {] `ExpressionBody`[?In, ~Await]
     ///     { `FunctionBody`[~Yield, ~Await] }
@@ -306,6 +413,9 @@ impl<'a> ParserImpl<'a> {
 
         self.ctx = self.ctx.and_await(has_await).and_yield(has_yield);
 
+        if self.options.collect_stats {
+            self.shape_counts.functions += 1;
+        }
         self.ast.expression_arrow_function(
             self.end_span(span),
             expression,
@@ -324,7 +434,7 @@ impl<'a> ParserImpl<'a> {
         &mut self,
         allow_return_type_in_arrow_function: bool,
     ) -> Expression<'a> {
-        let head = self.parse_parenthesized_arrow_function_head();
+        let head = self.parse_parenthesized_arrow_function_head(/* is_definite_arrow */ true);
         self.parse_arrow_function_expression_body(head, allow_return_type_in_arrow_function)
     }
 
@@ -339,7 +449,7 @@ impl<'a> ParserImpl<'a> {
 
         let checkpoint = self.checkpoint_with_error_recovery();
 
-        let head = self.parse_parenthesized_arrow_function_head();
+        let head = self.parse_parenthesized_arrow_function_head(/* is_definite_arrow */ false);
         if self.has_fatal_error() {
             self.state.not_parenthesized_arrow.insert(pos);
             self.rewind(checkpoint);
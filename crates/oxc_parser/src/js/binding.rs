@@ -27,11 +27,11 @@ impl<'a> ParserImpl<'a> {
     }
 
     pub(crate) fn parse_binding_pattern_kind(&mut self) -> BindingPattern<'a> {
-        match self.cur_kind() {
-            Kind::LCurly => self.parse_object_binding_pattern(),
-            Kind::LBrack => self.parse_array_binding_pattern(),
-            _ => self.parse_binding_pattern_identifier(),
-        }
+        self.with_nesting_guard(|p| match p.cur_kind() {
+            Kind::LCurly => p.parse_object_binding_pattern(),
+            Kind::LBrack => p.parse_array_binding_pattern(),
+            _ => p.parse_binding_pattern_identifier(),
+        })
     }
 
     fn parse_binding_pattern_identifier(&mut self) -> BindingPattern<'a> {
@@ -173,7 +173,7 @@ impl<'a> ParserImpl<'a> {
             //       ^ BindingIdentifier
             if let PropertyKey::StaticIdentifier(ident) = &key {
                 shorthand = true;
-                self.check_identifier_with_span(key_cur_kind, self.ctx, ident.span);
+                self.check_binding_identifier(key_cur_kind, self.ctx, ident.span);
                 let identifier =
                     self.ast.binding_pattern_binding_identifier(ident.span, ident.name);
                 self.context_add(Context::In, |p| p.parse_initializer(span, identifier))
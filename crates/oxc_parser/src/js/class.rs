@@ -1,5 +1,6 @@
 use oxc_allocator::{Box, Vec};
-use oxc_ast::ast::*;
+use oxc_ast::{NONE, ast::*};
+use oxc_diagnostics::LabeledSpan;
 use oxc_ecmascript::PropName;
 use oxc_span::{GetSpan, Span};
 
@@ -11,8 +12,8 @@ use crate::{
 
 use super::FunctionKind;
 
-type Extends<'a> =
-    Vec<'a, (Expression<'a>, Option<Box<'a, TSTypeParameterInstantiation<'a>>>, Span)>;
+type ExtendsEntry<'a> = (Expression<'a>, Option<Box<'a, TSTypeParameterInstantiation<'a>>>, Span);
+type Extends<'a> = Vec<'a, ExtendsEntry<'a>>;
 
 /// Section 15.7 Class Definitions
 impl<'a> ParserImpl<'a> {
@@ -41,7 +42,17 @@ impl<'a> ParserImpl<'a> {
         modifiers: &Modifiers<'a>,
         decorators: Vec<'a, Decorator<'a>>,
     ) -> Box<'a, Class<'a>> {
-        self.parse_class(start_span, ClassType::ClassDeclaration, modifiers, decorators)
+        self.parse_class(start_span, ClassType::ClassDeclaration, true, modifiers, decorators)
+    }
+
+    /// `export default class BindingIdentifier[?Yield, ?Await]opt ClassTail[?Yield, ?Await]`
+    pub(crate) fn parse_export_default_class_declaration(
+        &mut self,
+        start_span: u32,
+        modifiers: &Modifiers<'a>,
+        decorators: Vec<'a, Decorator<'a>>,
+    ) -> Box<'a, Class<'a>> {
+        self.parse_class(start_span, ClassType::ClassDeclaration, false, modifiers, decorators)
     }
 
     /// Section [Class Definitions](https://tc39.es/ecma262/#prod-ClassExpression)
@@ -53,7 +64,8 @@ impl<'a> ParserImpl<'a> {
         modifiers: &Modifiers<'a>,
         decorators: Vec<'a, Decorator<'a>>,
     ) -> Expression<'a> {
-        let class = self.parse_class(span, ClassType::ClassExpression, modifiers, decorators);
+        let class =
+            self.parse_class(span, ClassType::ClassExpression, false, modifiers, decorators);
         Expression::ClassExpression(class)
     }
 
@@ -61,6 +73,7 @@ impl<'a> ParserImpl<'a> {
         &mut self,
         start_span: u32,
         r#type: ClassType,
+        is_id_required: bool,
         modifiers: &Modifiers<'a>,
         decorators: Vec<'a, Decorator<'a>>,
     ) -> Box<'a, Class<'a>> {
@@ -74,14 +87,18 @@ impl<'a> ParserImpl<'a> {
             start_span = d.span.start;
         }
 
+        // All parts of a class are strict mode code.
         let id = if self.cur_kind().is_binding_identifier() && !self.at(Kind::Implements) {
-            Some(self.parse_binding_identifier())
+            Some(self.context_add(Context::StrictMode, Self::parse_binding_identifier))
+        } else if is_id_required && self.can_insert_placeholder() {
+            Some(self.recover_missing_class_name())
         } else {
             None
         };
 
         let type_parameters = if self.is_ts { self.parse_ts_type_parameters() } else { None };
-        let (extends, implements) = self.parse_heritage_clause();
+        let (extends, implements) =
+            self.context_add(Context::StrictMode, Self::parse_heritage_clause);
         let mut super_class = None;
         let mut super_type_parameters = None;
         if let Some(mut extends) = extends
@@ -94,7 +111,13 @@ impl<'a> ParserImpl<'a> {
                 self.error(diagnostics::classes_can_only_extend_single_class(span));
             }
         }
-        let body = self.parse_class_body();
+        let body = if self.is_missing_body() {
+            self.record_recovery();
+            self.error(diagnostics::body_expected("class", Span::empty(self.prev_token_end)));
+            self.ast.alloc_class_body(Span::empty(self.prev_token_end), self.ast.vec())
+        } else {
+            self.context_add(Context::StrictMode, Self::parse_class_body)
+        };
 
         self.verify_modifiers(
             modifiers,
@@ -103,6 +126,9 @@ impl<'a> ParserImpl<'a> {
             diagnostics::modifier_cannot_be_used_here,
         );
 
+        if self.options.collect_stats {
+            self.shape_counts.classes += 1;
+        }
         self.ast.alloc_class(
             self.end_span(start_span),
             r#type,
@@ -118,6 +144,15 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
+    /// Whether the body of a class or interface is missing, e.g. `class Foo extends Bar` at the end
+    /// of the file, or followed by another statement on the next line.
+    pub(crate) fn is_missing_body(&self) -> bool {
+        self.options.recover_from_errors
+            && self.fatal_error.is_none()
+            && !self.at(Kind::LCurly)
+            && (self.at(Kind::Eof) || self.cur_token().is_on_new_line())
+    }
+
     pub(crate) fn parse_heritage_clause(
         &mut self,
     ) -> (Option<Extends<'a>>, Option<(Span, Vec<'a, TSClassImplements<'a>>)>) {
@@ -170,20 +205,14 @@ impl<'a> ParserImpl<'a> {
 
         let mut extends = self.ast.vec();
         loop {
-            let span = self.start_span();
-            let mut extend = self.parse_lhs_expression_or_higher();
-            let type_argument;
-            if let Expression::TSInstantiationExpression(expr) = extend {
-                let expr = expr.unbox();
-                extend = expr.expression;
-                type_argument = Some(expr.type_arguments);
+            let entry = if self.can_insert_placeholder() {
+                self.parse_extends_entry_with_recovery()
             } else {
-                type_argument = self.try_parse_type_arguments();
-            }
-
-            extends.push((extend, type_argument, self.end_span(span)));
+                self.parse_extends_entry()
+            };
+            extends.push(entry);
 
-            if !self.eat(Kind::Comma) {
+            if !self.eat_heritage_clause_comma() {
                 break;
             }
         }
@@ -191,8 +220,134 @@ impl<'a> ParserImpl<'a> {
         extends
     }
 
+    fn parse_extends_entry(&mut self) -> ExtendsEntry<'a> {
+        let span = self.start_span();
+        let mut extend = self.parse_lhs_expression_or_higher();
+        let type_argument;
+        if let Expression::TSInstantiationExpression(expr) = extend {
+            let expr = expr.unbox();
+            extend = expr.expression;
+            type_argument = Some(expr.type_arguments);
+        } else {
+            type_argument = self.try_parse_type_arguments();
+        }
+        (extend, type_argument, self.end_span(span))
+    }
+
+    /// Parse an entry of an `extends` clause, recovering from errors in its expression, e.g.
+    /// `class A extends getBase(a b) { m() {} }` or `class A extends B + { m() {} }`.
+    ///
+    /// Skips to the `{` or `implements` after the entry, so that the class body is still parsed.
+    /// If parsing the entry stopped, it is rewound and replaced with a placeholder.
+    fn parse_extends_entry_with_recovery(&mut self) -> ExtendsEntry<'a> {
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        let entry = self.parse_extends_entry();
+        if let Some(fatal_error) = self.fatal_error.take() {
+            let error_start = fatal_error
+                .error
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map_or(0, LabeledSpan::offset);
+            self.rewind(checkpoint);
+            self.ctx = ctx;
+            let span = self.start_span();
+            if !self.skip_invalid_extends_entry(error_start) {
+                // There is no class body to recover at, so let the error stop parsing.
+                return self.parse_extends_entry();
+            }
+            self.record_recovery();
+            self.error(fatal_error.error);
+            let span = self.end_span(span);
+            return (self.placeholder_expression(span), None, span);
+        }
+        if !self.at_end_of_extends_entry() {
+            let token = self.cur_token();
+            if self.skip_invalid_extends_entry(token.start() as usize) {
+                self.record_recovery();
+                self.error(diagnostics::expect_token(
+                    Kind::LCurly.to_str(),
+                    token.kind().to_str(),
+                    token.span(),
+                    None,
+                ));
+            }
+        }
+        entry
+    }
+
+    fn at_end_of_extends_entry(&self) -> bool {
+        matches!(
+            self.cur_kind(),
+            Kind::LCurly | Kind::Comma | Kind::Extends | Kind::Implements | Kind::Eof
+        ) || self.cur_token().is_on_new_line()
+    }
+
+    /// Skip to the `{` or `implements` after the error at `error_start` in an `extends` entry,
+    /// see [`Self::parse_extends_entry_with_recovery`].
+    ///
+    /// Delimiters are skipped as pairs, so that a `{` inside the entry is not taken as the start
+    /// of the class body (`getBase(a b, { c })`). If they are unbalanced, the first `{` after the
+    /// error is taken instead (`getBase(a; { m() {} }`).
+    ///
+    /// Returns `false`, without skipping, if neither is found.
+    fn skip_invalid_extends_entry(&mut self, error_start: usize) -> bool {
+        let error_start = if self.lookahead(|p| p.skip_extends_entry_tokens(None)) {
+            None
+        } else if self.lookahead(|p| p.skip_extends_entry_tokens(Some(error_start))) {
+            Some(error_start)
+        } else {
+            return false;
+        };
+        self.skip_extends_entry_tokens(error_start)
+    }
+
+    fn skip_extends_entry_tokens(&mut self, error_start: Option<usize>) -> bool {
+        let mut depth = 0u32;
+        loop {
+            let token = self.cur_token();
+            match token.kind() {
+                Kind::Eof | Kind::Undetermined => return false,
+                Kind::LCurly | Kind::Implements
+                    if depth == 0
+                        && error_start.is_none_or(|start| token.start() as usize >= start) =>
+                {
+                    return true;
+                }
+                Kind::LParen | Kind::LBrack | Kind::LCurly if error_start.is_none() => depth += 1,
+                Kind::RParen | Kind::RBrack | Kind::RCurly if error_start.is_none() => {
+                    depth = depth.saturating_sub(1);
+                }
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// Eat the `,` between entries of an `extends` or `implements` clause.
+    ///
+    /// With error recovery, a trailing comma (`extends A, {}`) is reported and `false` is
+    /// returned, so the class body is parsed instead of an empty entry.
+    pub(crate) fn eat_heritage_clause_comma(&mut self) -> bool {
+        let comma_span = self.cur_token().span();
+        if !self.eat(Kind::Comma) {
+            return false;
+        }
+        if self.options.recover_from_errors
+            && matches!(self.cur_kind(), Kind::LCurly | Kind::Extends | Kind::Implements)
+        {
+            self.record_recovery();
+            self.error(diagnostics::heritage_clause_trailing_comma(comma_span));
+            self.suggest_fix(comma_span, "");
+            return false;
+        }
+        true
+    }
+
     fn parse_class_body(&mut self) -> Box<'a, ClassBody<'a>> {
         let span = self.start_span();
+        self.class_body_depth += 1;
         let class_elements = self.parse_normal_list_breakable(Kind::LCurly, Kind::RCurly, |p| {
             // Skip empty class element `;`
             if p.eat(Kind::Semicolon) {
@@ -201,8 +356,14 @@ impl<'a> ParserImpl<'a> {
                     return None;
                 }
             }
-            Some(Self::parse_class_element(p))
+            Some(p.track_recovered_node(|p| {
+                if p.at(Kind::LBrack) && p.can_insert_placeholder() {
+                    return p.parse_computed_class_element_with_recovery();
+                }
+                Self::parse_class_element(p)
+            }))
         });
+        self.class_body_depth -= 1;
         self.ast.alloc_class_body(self.end_span(span), class_elements)
     }
 
@@ -219,6 +380,76 @@ impl<'a> ParserImpl<'a> {
         elem
     }
 
+    /// Parse a class element starting with a computed name, recovering from a syntax error in it,
+    /// e.g. the second member in
+    /// ```ts
+    /// class C {
+    ///     public bar() {}
+    ///     [a, b] = foo();
+    ///     public baz() {}
+    /// }
+    /// ```
+    ///
+    /// Rewinds to the start of the element and skips to its `;`, to the next token on a new line
+    /// which can start a class element, or to the `}` closing the class body, replacing the element
+    /// with a computed [`PropertyDefinition`] whose key is a placeholder.
+    fn parse_computed_class_element_with_recovery(&mut self) -> ClassElement<'a> {
+        let checkpoint = self.checkpoint();
+        let ctx = self.ctx;
+        let span = self.start_span();
+        let element = self.parse_class_element();
+        let Some(fatal_error) = self.fatal_error.take() else {
+            return element;
+        };
+        self.rewind(checkpoint);
+        self.ctx = ctx;
+        self.record_recovery();
+        self.error(fatal_error.error);
+
+        self.bump_any(); // bump `[`
+        let mut depth = 0u32;
+        loop {
+            let token = self.cur_token();
+            match token.kind() {
+                Kind::Eof | Kind::Undetermined => break,
+                Kind::RCurly if depth == 0 => break,
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    break;
+                }
+                kind if depth == 0
+                    && token.is_on_new_line()
+                    && (kind.is_identifier_or_keyword()
+                        || matches!(kind, Kind::LBrack | Kind::At | Kind::Star)) =>
+                {
+                    break;
+                }
+                Kind::LCurly => depth += 1,
+                Kind::RCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump_any();
+        }
+
+        let span = self.end_span(span);
+        self.ast.class_element_property_definition(
+            span,
+            PropertyDefinitionType::PropertyDefinition,
+            self.ast.vec(),
+            PropertyKey::from(self.placeholder_expression(span)),
+            NONE,
+            None,
+            /* computed */ true,
+            /* static */ false,
+            /* declare */ false,
+            /* override */ false,
+            /* optional */ false,
+            /* definite */ false,
+            /* readonly */ false,
+            None,
+        )
+    }
+
     fn parse_class_element_impl(&mut self) -> ClassElement<'a> {
         let span = self.start_span();
 
@@ -257,7 +488,7 @@ impl<'a> ParserImpl<'a> {
             MethodDefinitionType::MethodDefinition
         };
 
-        if self.parse_contextual_modifier(Kind::Get) {
+        if self.parse_accessor_keyword(Kind::Get) {
             return self.parse_accessor_declaration(
                 span,
                 r#type,
@@ -267,7 +498,7 @@ impl<'a> ParserImpl<'a> {
             );
         }
 
-        if self.parse_contextual_modifier(Kind::Set) {
+        if self.parse_accessor_keyword(Kind::Set) {
             return self.parse_accessor_declaration(
                 span,
                 r#type,
@@ -432,12 +663,16 @@ impl<'a> ParserImpl<'a> {
         modifiers: &Modifiers<'a>,
         decorators: Vec<'a, Decorator<'a>>,
     ) -> ClassElement<'a> {
-        let (name, computed) = self.parse_class_element_name(modifiers);
-        let value = self.parse_method(
-            modifiers.contains(ModifierKind::Async),
-            false,
-            FunctionKind::ClassMethod,
-        );
+        let r#async = modifiers.contains(ModifierKind::Async);
+        let has_name = self.can_follow_get_or_set_keyword();
+        let (name, computed, value) = if has_name {
+            let (name, computed) = self.parse_class_element_name(modifiers);
+            (name, computed, self.parse_method(r#async, false, FunctionKind::ClassMethod))
+        } else {
+            let keyword = if kind == MethodDefinitionKind::Set { "set" } else { "get" };
+            let name = self.recover_missing_accessor_name(keyword);
+            (name, false, self.parse_accessor_without_name(r#async))
+        };
         let method_definition = self.ast.alloc_method_definition(
             self.end_span(span),
             r#type,
@@ -451,7 +686,9 @@ impl<'a> ParserImpl<'a> {
             false,
             modifiers.accessibility(),
         );
-        self.check_method_definition_accessor(&method_definition);
+        if has_name {
+            self.check_method_definition_accessor(&method_definition);
+        }
         self.verify_modifiers(
             modifiers,
             !(ModifierFlags::ASYNC | ModifierFlags::DECLARE),
@@ -646,7 +883,12 @@ impl<'a> ParserImpl<'a> {
         if cur_token.kind() == Kind::Semicolon {
             self.bump_any();
         } else if !self.can_insert_semicolon() {
-            let error = diagnostics::expect_token(";", cur_token.kind().to_str(), cur_token.span());
+            let error = diagnostics::expect_token(
+                ";",
+                cur_token.kind().to_str(),
+                cur_token.span(),
+                self.previous_span_at_eof(),
+            );
             return self.fatal_error(error);
         }
 
@@ -691,23 +933,20 @@ impl<'a> ParserImpl<'a> {
     }
 
     #[cold]
-    pub(crate) fn check_getter(&mut self, function: &Function<'a>) {
-        if !function.params.items.is_empty() {
-            self.error(diagnostics::getter_parameters(function.params.span));
+    pub(crate) fn check_getter(&mut self, params: &FormalParameters<'a>) {
+        if !params.items.is_empty() {
+            self.error(diagnostics::getter_parameters(params.span));
         }
     }
 
     #[cold]
-    pub(crate) fn check_setter(&mut self, function: &Function<'a>) {
-        if let Some(rest) = &function.params.rest {
+    pub(crate) fn check_setter(&mut self, params: &FormalParameters<'a>) {
+        if let Some(rest) = &params.rest {
             self.error(diagnostics::setter_with_rest_parameter(rest.span));
-        } else if function.params.parameters_count() != 1 {
-            self.error(diagnostics::setter_with_parameters(
-                function.params.span,
-                function.params.parameters_count(),
-            ));
-        } else if self.is_ts && function.params.items.first().unwrap().initializer.is_some() {
-            self.error(diagnostics::setter_with_assignment_pattern(function.params.span));
+        } else if params.parameters_count() != 1 {
+            self.error(diagnostics::setter_with_parameters(params.span, params.parameters_count()));
+        } else if self.is_ts && params.items.first().unwrap().initializer.is_some() {
+            self.error(diagnostics::setter_with_assignment_pattern(params.span));
         }
     }
 
@@ -737,8 +976,8 @@ impl<'a> ParserImpl<'a> {
         self.check_method_definition(method);
 
         match method.kind {
-            MethodDefinitionKind::Get => self.check_getter(&method.value),
-            MethodDefinitionKind::Set => self.check_setter(&method.value),
+            MethodDefinitionKind::Get => self.check_getter(&method.value.params),
+            MethodDefinitionKind::Set => self.check_setter(&method.value.params),
             _ => {}
         }
         if method.r#type.is_abstract() && method.value.body.is_some() {
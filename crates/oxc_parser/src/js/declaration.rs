@@ -1,6 +1,7 @@
 use oxc_allocator::Box;
 use oxc_ast::ast::*;
-use oxc_span::GetSpan;
+use oxc_ecmascript::BoundNames;
+use oxc_span::{GetSpan, Span};
 
 use super::VariableDeclarationParent;
 use crate::{ParserImpl, StatementContext, diagnostics, lexer::Kind};
@@ -102,7 +103,14 @@ impl<'a> ParserImpl<'a> {
     ) -> VariableDeclarator<'a> {
         let span = self.start_span();
 
+        // `const ...x = y;`
+        if self.options.recover_from_errors && self.at(Kind::Dot3) {
+            self.recover_stray_spread();
+        }
         let id = self.parse_binding_pattern();
+        if kind.is_lexical() && !self.ctx.has_strict_mode() {
+            self.check_let_in_lexical_binding(&id);
+        }
 
         let (type_annotation, definite) = if self.is_ts {
             // const x!: number = 1
@@ -138,6 +146,9 @@ impl<'a> ParserImpl<'a> {
         if decl_parent == VariableDeclarationParent::Statement {
             self.check_missing_initializer(&decl);
         }
+        if self.options.validate_ambient_declarations && self.ctx.has_ambient() {
+            self.check_ambient_initializer(&decl);
+        }
         if let Some(span) = definite {
             if decl.init.is_some() {
                 self.error(diagnostics::variable_declarator_definite(span));
@@ -148,6 +159,30 @@ impl<'a> ParserImpl<'a> {
         decl
     }
 
+    /// Report `var` with [`crate::ParseOptions::warn_var_declarations`], where `var_span` is the
+    /// `var` keyword.
+    pub(crate) fn check_var_declaration(&mut self, var_span: Span, declare: bool) {
+        if self.options.warn_var_declarations && !declare && !self.ctx.has_ambient() {
+            self.error(diagnostics::var_declaration(var_span));
+        }
+    }
+
+    /// LexicalDeclaration : LetOrConst BindingList ;
+    /// * It is a Syntax Error if the BoundNames of BindingList contains "let".
+    ///
+    /// In strict mode code `let` is a reserved word, which semantic analysis reports instead.
+    fn check_let_in_lexical_binding(&mut self, id: &BindingPattern<'a>) {
+        let mut spans = vec![];
+        id.bound_names(&mut |ident| {
+            if ident.name == "let" {
+                spans.push(ident.span);
+            }
+        });
+        for span in spans {
+            self.error(diagnostics::let_in_lexical_declaration(span));
+        }
+    }
+
     pub(crate) fn check_missing_initializer(&mut self, decl: &VariableDeclarator<'a>) {
         if decl.init.is_none() && !self.ctx.has_ambient() {
             if !matches!(decl.id, BindingPattern::BindingIdentifier(_)) {
@@ -159,6 +194,17 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    /// Variables in ambient contexts cannot have initializers, except for `const`s without a type
+    /// annotation, which can be initialized with a literal or a literal enum reference.
+    fn check_ambient_initializer(&mut self, decl: &VariableDeclarator<'a>) {
+        let Some(init) = &decl.init else { return };
+        if decl.kind != VariableDeclarationKind::Const || decl.type_annotation.is_some() {
+            self.error(diagnostics::initializer_in_ambient(init.span()));
+        } else if !is_ambient_const_initializer(init) {
+            self.error(diagnostics::invalid_ambient_const_initializer(init.span()));
+        }
+    }
+
     /// Section 14.3.1 Let, Const, and Using Declarations
     /// UsingDeclaration[In, Yield, Await] :
     /// using [no LineTerminator here] [lookahead ≠ await] BindingList[?In, ?Yield, ?Await, ~Pattern] ;
@@ -176,6 +222,9 @@ impl<'a> ParserImpl<'a> {
         };
 
         self.expect(Kind::Using);
+        if self.options.report_explicit_resource_management {
+            self.error(diagnostics::explicit_resource_management(self.end_span(span)));
+        }
 
         // BindingList[?In, ?Yield, ?Await, ~Pattern]
         let mut declarations = self.ast.vec();
@@ -205,3 +254,37 @@ impl<'a> ParserImpl<'a> {
         self.ast.variable_declaration(self.end_span(span), kind, declarations, false)
     }
 }
+
+/// Whether `init` is a string, numeric, bigint or boolean literal, or a literal enum reference
+/// (`E.A`, `E["A"]`).
+fn is_ambient_const_initializer(init: &Expression) -> bool {
+    match init {
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BigIntLiteral(_)
+        | Expression::BooleanLiteral(_) => true,
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(expr) => {
+            expr.operator == UnaryOperator::UnaryNegation
+                && matches!(
+                    expr.argument,
+                    Expression::NumericLiteral(_) | Expression::BigIntLiteral(_)
+                )
+        }
+        Expression::StaticMemberExpression(expr) => is_entity_name_expression(&expr.object),
+        Expression::ComputedMemberExpression(expr) => {
+            matches!(expr.expression, Expression::StringLiteral(_) | Expression::NumericLiteral(_))
+                && is_entity_name_expression(&expr.object)
+        }
+        _ => false,
+    }
+}
+
+/// `a` or `a.b.c`
+fn is_entity_name_expression(expr: &Expression) -> bool {
+    match expr {
+        Expression::Identifier(_) => true,
+        Expression::StaticMemberExpression(expr) => is_entity_name_expression(&expr.object),
+        _ => false,
+    }
+}
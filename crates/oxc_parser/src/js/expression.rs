@@ -26,7 +26,7 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_paren_expression(&mut self) -> Expression<'a> {
         let opening_span = self.cur_token().span();
         self.expect(Kind::LParen);
-        let expression = self.parse_expr();
+        let expression = self.with_open_delimiter(Kind::RParen, Self::parse_expr);
         self.expect_closing(Kind::RParen, opening_span);
         expression
     }
@@ -64,10 +64,16 @@ impl<'a> ParserImpl<'a> {
         // allow `await` and `yield`, let semantic analysis report error
         let kind = self.cur_kind();
         if !kind.is_identifier_reference(false, false) {
+            if self.can_insert_placeholder() && kind.is_expression_terminator() {
+                return self.recover_missing_expression_operand();
+            }
             return self.unexpected();
         }
         self.check_identifier(kind, self.ctx);
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
+        if self.commonjs_usage.is_some() {
+            self.detect_commonjs_identifier(&name, span);
+        }
         self.ast.identifier_reference(span, name)
     }
 
@@ -83,7 +89,7 @@ impl<'a> ParserImpl<'a> {
                 self.unexpected()
             };
         }
-        self.check_identifier(cur, self.ctx);
+        self.check_binding_identifier(cur, self.ctx, self.cur_token().span());
         let (span, name) = self.parse_identifier_kind(Kind::Ident);
         self.ast.binding_identifier(span, name)
     }
@@ -142,6 +148,22 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
+    /// Same as [`Self::check_identifier_with_span`], but for an identifier being declared.
+    /// The binding is still created, so the declaration is kept in the AST.
+    pub(crate) fn check_binding_identifier(&mut self, kind: Kind, ctx: Context, span: Span) {
+        if ctx.has_await() && kind == Kind::Await {
+            self.error(diagnostics::await_binding_identifier(span));
+        }
+        if ctx.has_yield() && kind == Kind::Yield {
+            self.error(diagnostics::yield_binding_identifier(span));
+        }
+        // `package`, `public`, etc. are only reserved in strict mode code.
+        // `let` is checked by the declarations which cannot bind it.
+        if !ctx.has_ambient() && kind != Kind::Let && kind.is_strict_mode_contextual_keyword() {
+            self.strict_mode_error(diagnostics::strict_mode_reserved_word(kind.to_str(), span));
+        }
+    }
+
     /// Section [PrivateIdentifier](https://tc39.es/ecma262/#prod-PrivateIdentifier)
     /// `PrivateIdentifier` ::
     ///     # `IdentifierName`
@@ -149,6 +171,9 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_private_identifier(&mut self) -> PrivateIdentifier<'a> {
         let span = self.cur_token().span();
         let name = Atom::from(self.cur_string());
+        if self.class_body_depth == 0 && !self.options.allow_private_names_outside_class {
+            self.error(diagnostics::private_name_outside_class(span));
+        }
         self.bump_any();
         self.ast.private_identifier(span, name)
     }
@@ -218,12 +243,14 @@ impl<'a> ParserImpl<'a> {
         self.bump_any(); // `bump` `(`
         let expr_span = self.start_span();
         let (mut expressions, comma_span) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                opening_span,
-                Self::parse_assignment_expression_or_higher,
-            )
+            p.with_open_delimiter(Kind::RParen, |p| {
+                p.parse_delimited_list(
+                    Kind::RParen,
+                    Kind::Comma,
+                    opening_span,
+                    Self::parse_assignment_expression_or_higher,
+                )
+            })
         });
 
         if let Some(comma_span) = comma_span {
@@ -241,7 +268,7 @@ impl<'a> ParserImpl<'a> {
         }
 
         let expr_span = self.end_span(expr_span);
-        self.expect(Kind::RParen);
+        self.expect_closing(Kind::RParen, opening_span);
 
         // ParenthesizedExpression is from acorn --preserveParens
         let mut expression = if expressions.len() == 1 {
@@ -350,6 +377,13 @@ impl<'a> ParserImpl<'a> {
             }
             _ => return self.unexpected(),
         };
+        // `010`, but not `0o10`
+        if kind == Kind::Octal
+            && self.options.disallow_legacy_features
+            && src.as_bytes().get(1).is_some_and(u8::is_ascii_digit)
+        {
+            self.error(diagnostics::legacy_octal_literal(span));
+        }
         self.bump_any();
         self.ast.numeric_literal(span, value, Some(Atom::from(src)), base)
     }
@@ -385,6 +419,9 @@ impl<'a> ParserImpl<'a> {
         let flags_start = pattern_end + 1; // +1 to include right `/`
         let flags_text = &self.source_text[flags_start as usize..span.end as usize];
         let raw = self.cur_src();
+        if flags_error {
+            self.report_invalid_regex_flags(flags_start, flags_text);
+        }
         self.bump_any();
 
         // Parse pattern if options is enabled and also flags are valid
@@ -409,6 +446,26 @@ impl<'a> ParserImpl<'a> {
         self.ast.reg_exp_literal(span, RegExp { pattern, flags }, Some(Atom::from(raw)))
     }
 
+    /// Report unknown and duplicated flags, which are left out of the literal's flags.
+    #[cold]
+    fn report_invalid_regex_flags(&mut self, flags_start: u32, flags_text: &str) {
+        let mut flags = RegExpFlags::empty();
+        // The lexer only consumes ASCII characters as flags.
+        for (offset, c) in (flags_start..).zip(flags_text.chars()) {
+            let span = Span::sized(offset, 1);
+            match RegExpFlags::try_from(c) {
+                Ok(flag) if flags.contains(flag) => {
+                    self.error(diagnostics::reg_exp_flag_twice(c, span));
+                }
+                Ok(flag) => flags |= flag,
+                Err(_) if self.options.normalize_regex_flags => {
+                    self.error(diagnostics::reg_exp_flag_warning(c, span));
+                }
+                Err(_) => self.error(diagnostics::reg_exp_flag(c, span)),
+            }
+        }
+    }
+
     #[cfg(feature = "regular_expression")]
     fn parse_regex_pattern(
         &mut self,
@@ -442,10 +499,48 @@ impl<'a> ParserImpl<'a> {
         let raw = Atom::from(self.cur_src());
         let value = self.cur_string();
         let lone_surrogates = self.cur_token().lone_surrogates();
+        if self.options.disallow_legacy_features && self.cur_token().escaped() {
+            self.check_octal_escape_sequences(span.start, raw.as_str());
+        }
         self.bump_any();
         self.ast.string_literal_with_lone_surrogates(span, value, Some(raw), lone_surrogates)
     }
 
+    /// Report the first octal escape sequence in the string literal `raw` starting at `start`,
+    /// e.g. `\101` and `\00`, but not `\0`.
+    #[cold]
+    fn check_octal_escape_sequences(&mut self, start: u32, raw: &str) {
+        let bytes = raw.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] != b'\\' {
+                i += 1;
+                continue;
+            }
+            let escape_start = i;
+            i += 1;
+            let is_octal = match bytes.get(i) {
+                Some(b'0') => bytes.get(i + 1).is_some_and(u8::is_ascii_digit),
+                Some(b'1'..=b'7') => true,
+                _ => false,
+            };
+            if is_octal {
+                // An octal escape sequence has at most 3 digits, e.g. `\377` but `\40` + `0`.
+                let max_len = if bytes[i] <= b'3' { 3 } else { 2 };
+                let len = bytes[i..]
+                    .iter()
+                    .take(max_len)
+                    .take_while(|b| matches!(b, b'0'..=b'7'))
+                    .count();
+                #[expect(clippy::cast_possible_truncation)]
+                let span = Span::new(start + escape_start as u32, start + (i + len) as u32);
+                self.error(diagnostics::legacy_octal_escape_sequence(span));
+                return;
+            }
+            i += 1;
+        }
+    }
+
     /// Section [Array Expression](https://tc39.es/ecma262/#prod-ArrayLiteral)
     /// `ArrayLiteral`[Yield, Await]:
     ///     [ Elision opt ]
@@ -456,23 +551,29 @@ impl<'a> ParserImpl<'a> {
         let opening_span = self.cur_token().span();
         self.expect(Kind::LBrack);
         let (elements, comma_span) = self.context_add(Context::In, |p| {
-            p.parse_delimited_list(
-                Kind::RBrack,
-                Kind::Comma,
-                opening_span,
-                Self::parse_array_expression_element,
-            )
+            p.with_open_delimiter(Kind::RBrack, |p| {
+                p.parse_delimited_list(Kind::RBrack, Kind::Comma, opening_span, |p| {
+                    p.parse_array_expression_element(span)
+                })
+            })
         });
         if let Some(comma_span) = comma_span {
             self.state.trailing_commas.insert(span, self.end_span(comma_span));
         }
-        self.expect(Kind::RBrack);
+        self.expect_closing(Kind::RBrack, opening_span);
         self.ast.expression_array(self.end_span(span), elements)
     }
 
-    fn parse_array_expression_element(&mut self) -> ArrayExpressionElement<'a> {
+    fn parse_array_expression_element(&mut self, array_span: u32) -> ArrayExpressionElement<'a> {
         match self.cur_kind() {
-            Kind::Comma => self.parse_elision(),
+            Kind::Comma => {
+                if self.options.report_sparse_array_warnings {
+                    // `[1, , 3]`, `[, 1]`: the previous token is `,` or `[`
+                    let span = Span::new(self.prev_token_end - 1, self.cur_token().span().end);
+                    self.state.sparse_array_elisions.entry(array_span).or_default().push(span);
+                }
+                self.parse_elision()
+            }
             Kind::Dot3 => ArrayExpressionElement::SpreadElement(self.parse_spread_element()),
             _ => ArrayExpressionElement::from(self.parse_assignment_expression_or_higher()),
         }
@@ -503,7 +604,7 @@ impl<'a> ParserImpl<'a> {
 
                 quasis.push(self.parse_template_element(tagged));
                 // TemplateHead Expression[+In, ?Yield, ?Await]
-                let expr = self.context_add(Context::In, Self::parse_expr);
+                let expr = self.parse_template_substitution();
                 expressions.push(expr);
                 self.re_lex_template_substitution_tail();
                 while self.fatal_error.is_none() {
@@ -515,7 +616,7 @@ impl<'a> ParserImpl<'a> {
                         Kind::TemplateMiddle => {
                             quasis.push(self.parse_template_element(tagged));
                             // TemplateMiddle Expression[+In, ?Yield, ?Await]
-                            let expr = self.context_add(Context::In, Self::parse_expr);
+                            let expr = self.parse_template_substitution();
                             expressions.push(expr);
                             self.re_lex_template_substitution_tail();
                         }
@@ -534,6 +635,15 @@ impl<'a> ParserImpl<'a> {
         self.ast.template_literal(self.end_span(span), quasis, expressions)
     }
 
+    /// `${ Expression[+In, ?Yield, ?Await] }`, without the `${` and `}`
+    fn parse_template_substitution(&mut self) -> Expression<'a> {
+        let outer = self.state.template_substitution.replace(self.state.open_delimiters.len() + 1);
+        let expr = self
+            .context_add(Context::In, |p| p.with_open_delimiter(Kind::RCurly, Self::parse_expr));
+        self.state.template_substitution = outer;
+        expr
+    }
+
     pub(crate) fn parse_template_literal_expression(&mut self, tagged: bool) -> Expression<'a> {
         let template_lit = self.parse_template_literal(tagged);
         Expression::TemplateLiteral(self.alloc(template_lit))
@@ -621,6 +731,11 @@ impl<'a> ParserImpl<'a> {
                         let property = self.parse_keyword_identifier(Kind::Meta);
                         let span = self.end_span(span);
                         self.module_record_builder.visit_import_meta(span);
+                        // Otherwise left to semantic analysis, as it is not a syntax error that
+                        // stops parsing.
+                        if self.options.recover_from_errors && self.source_type.is_script() {
+                            self.error(diagnostics::import_meta_in_script(span));
+                        }
                         self.ast.expression_meta_property(span, meta, property)
                     }
                     // `import.source(expr)`
@@ -667,8 +782,10 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_v8_intrinsic_argument(&mut self) -> Argument<'a> {
         if self.at(Kind::Dot3) {
-            self.error(diagnostics::v8_intrinsic_spread_elem(self.cur_token().span()));
-            Argument::SpreadElement(self.parse_spread_element())
+            // Keep the spread element so only the argument is reported, not the whole call.
+            let spread = self.parse_spread_element();
+            self.error(diagnostics::v8_intrinsic_spread_elem(spread.span));
+            Argument::SpreadElement(spread)
         } else {
             Argument::from(self.parse_assignment_expression_or_higher())
         }
@@ -853,8 +970,16 @@ impl<'a> ParserImpl<'a> {
                 optional,
             )
         } else {
-            let ident = self.parse_identifier_name();
-            self.ast.member_expression_static(self.end_span(lhs_span), lhs, ident, optional)
+            let ident = if self.can_insert_placeholder() && !self.cur_kind().is_identifier_name() {
+                self.recover_missing_member_name()
+            } else {
+                self.parse_identifier_name()
+            };
+            let span = self.end_span(lhs_span);
+            if self.commonjs_usage.is_some() {
+                self.detect_commonjs_member(&lhs, &ident, span);
+            }
+            self.ast.member_expression_static(span, lhs, ident, optional)
         })
     }
 
@@ -867,9 +992,11 @@ impl<'a> ParserImpl<'a> {
         lhs: Expression<'a>,
         optional: bool,
     ) -> Expression<'a> {
+        let opening_span = self.cur_token().span();
         self.bump_any(); // advance `[`
-        let property = self.context_add(Context::In, Self::parse_expr);
-        self.expect(Kind::RBrack);
+        let property = self
+            .context_add(Context::In, |p| p.with_open_delimiter(Kind::RBrack, Self::parse_expr));
+        self.expect_closing(Kind::RBrack, opening_span);
         self.ast.member_expression_computed(self.end_span(lhs_span), lhs, property, optional).into()
     }
 
@@ -882,6 +1009,14 @@ impl<'a> ParserImpl<'a> {
             return if self.at(Kind::Target) {
                 let property = self.parse_keyword_identifier(Kind::Target);
                 self.ast.expression_meta_property(self.end_span(span), identifier, property)
+            } else if self.can_insert_placeholder() && self.cur_kind().is_identifier_name() {
+                // `new.foo`: continue as if it were `new.target`
+                let property = self.parse_identifier_name();
+                let span = self.end_span(span);
+                self.record_recovery();
+                self.error(diagnostics::new_target(span));
+                let property = self.ast.identifier_name(property.span, "target");
+                self.ast.expression_meta_property(span, identifier, property)
             } else {
                 self.bump_any();
                 self.fatal_error(diagnostics::new_target(self.end_span(span)))
@@ -892,7 +1027,7 @@ impl<'a> ParserImpl<'a> {
         let is_import = self.at(Kind::Import); // Syntax Error for `new import('mod')` but not `new (import('mod'))`.
         let mut optional = false;
         let mut callee = {
-            let lhs = self.parse_primary_expression();
+            let lhs = self.with_nesting_guard(Self::parse_primary_expression);
             self.parse_member_expression_rest(
                 rhs_span,
                 lhs,
@@ -920,14 +1055,16 @@ impl<'a> ParserImpl<'a> {
             // ArgumentList[Yield, Await] :
             //   AssignmentExpression[+In, ?Yield, ?Await]
             let (call_arguments, _) = self.context_add(Context::In, |p| {
-                p.parse_delimited_list(
-                    Kind::RParen,
-                    Kind::Comma,
-                    opening_span,
-                    Self::parse_call_argument,
-                )
+                p.with_open_delimiter(Kind::RParen, |p| {
+                    p.parse_delimited_list(
+                        Kind::RParen,
+                        Kind::Comma,
+                        opening_span,
+                        Self::parse_call_argument,
+                    )
+                })
             });
-            self.expect(Kind::RParen);
+            self.expect_closing(Kind::RParen, opening_span);
             call_arguments
         } else {
             self.ast.vec()
@@ -1016,21 +1153,21 @@ impl<'a> ParserImpl<'a> {
         let opening_span = self.cur_token().span();
         self.expect(Kind::LParen);
         let (call_arguments, _) = self.context(Context::In, Context::Decorator, |p| {
-            p.parse_delimited_list(
-                Kind::RParen,
-                Kind::Comma,
-                opening_span,
-                Self::parse_call_argument,
-            )
+            p.with_open_delimiter(Kind::RParen, |p| {
+                p.parse_delimited_list(
+                    Kind::RParen,
+                    Kind::Comma,
+                    opening_span,
+                    Self::parse_call_argument,
+                )
+            })
         });
-        self.expect(Kind::RParen);
-        self.ast.expression_call(
-            self.end_span(lhs_span),
-            lhs,
-            type_parameters,
-            call_arguments,
-            optional,
-        )
+        self.expect_closing(Kind::RParen, opening_span);
+        let span = self.end_span(lhs_span);
+        if self.commonjs_usage.is_some() {
+            self.detect_commonjs_call(&lhs, span);
+        }
+        self.ast.expression_call(span, lhs, type_parameters, call_arguments, optional)
     }
 
     fn parse_call_argument(&mut self) -> Argument<'a> {
@@ -1048,7 +1185,8 @@ impl<'a> ParserImpl<'a> {
         if kind.is_update_operator() {
             let operator = map_update_operator(kind);
             self.bump_any();
-            let argument = self.parse_unary_expression_or_higher(lhs_span);
+            let argument =
+                self.with_nesting_guard(|p| p.parse_unary_expression_or_higher(lhs_span));
             let argument = SimpleAssignmentTarget::cover(argument, self);
             return self.ast.expression_update(self.end_span(lhs_span), operator, true, argument);
         }
@@ -1110,7 +1248,8 @@ impl<'a> ParserImpl<'a> {
         let operator = map_unary_operator(self.cur_kind());
         self.bump_any();
         let has_pure_comment = self.lexer.trivia_builder.previous_token_has_pure_comment();
-        let mut argument = self.parse_simple_unary_expression(self.start_span());
+        let mut argument =
+            self.with_nesting_guard(|p| p.parse_simple_unary_expression(p.start_span()));
         if has_pure_comment {
             Self::set_pure_on_call_or_new_expr(&mut argument);
         }
@@ -1129,9 +1268,14 @@ impl<'a> ParserImpl<'a> {
             let left = self.parse_private_identifier();
             self.expect(Kind::In);
             let right = self.parse_binary_expression_or_higher(Precedence::Compare);
-            if let Expression::PrivateInExpression(private_in_expr) = right {
+            if let Expression::PrivateInExpression(private_in_expr) = &right {
                 let error = diagnostics::private_in_private(private_in_expr.span);
-                return self.fatal_error(error);
+                if !self.options.recover_from_errors {
+                    return self.fatal_error(error);
+                }
+                // `#x in #y in obj`
+                self.record_recovery();
+                self.error(error);
             }
             self.ast.expression_private_in(self.end_span(lhs_span), left, right)
         } else {
@@ -1264,14 +1408,29 @@ impl<'a> ParserImpl<'a> {
         if !self.eat(Kind::Question) {
             return lhs;
         }
-        let consequent = self.context_add(Context::In, |p| {
-            p.parse_assignment_expression_or_higher_impl(
-                /* allow_return_type_in_arrow_function */ false,
-            )
-        });
-        self.expect_conditional_alternative(question_span);
-        let alternate =
-            self.parse_assignment_expression_or_higher_impl(allow_return_type_in_arrow_function);
+        // `cond ? : b`
+        let consequent = if self.at(Kind::Colon) && self.can_insert_placeholder() {
+            self.recover_missing_conditional_branch(Kind::Question, question_span)
+        } else {
+            self.context_add(Context::In, |p| {
+                p.parse_assignment_expression_or_higher_impl(
+                    /* allow_return_type_in_arrow_function */ false,
+                )
+            })
+        };
+        let alternate = if !self.at(Kind::Colon) && self.can_insert_placeholder() {
+            self.recover_missing_conditional_alternative(question_span)
+        } else {
+            self.expect_conditional_alternative(question_span);
+            // `cond ? a :;`
+            if self.can_insert_placeholder()
+                && (self.cur_kind().is_expression_terminator() || self.at(Kind::Eof))
+            {
+                self.recover_missing_conditional_branch(Kind::Colon, question_span)
+            } else {
+                self.parse_assignment_expression_or_higher_impl(allow_return_type_in_arrow_function)
+            }
+        };
         self.ast.expression_conditional(self.end_span(lhs_span), lhs, consequent, alternate)
     }
 
@@ -1285,6 +1444,15 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_assignment_expression_or_higher_impl(
         &mut self,
         allow_return_type_in_arrow_function: bool,
+    ) -> Expression<'a> {
+        self.with_nesting_guard(|p| {
+            p.parse_assignment_expression_or_higher_guarded(allow_return_type_in_arrow_function)
+        })
+    }
+
+    fn parse_assignment_expression_or_higher_guarded(
+        &mut self,
+        allow_return_type_in_arrow_function: bool,
     ) -> Expression<'a> {
         let has_no_side_effects_comment =
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
@@ -1450,8 +1618,9 @@ impl<'a> ParserImpl<'a> {
             self.error(diagnostics::await_expression(self.cur_token().span()));
         }
         self.bump_any();
-        let argument =
-            self.context_add(Context::Await, |p| p.parse_simple_unary_expression(lhs_span));
+        let argument = self.context_add(Context::Await, |p| {
+            p.with_nesting_guard(|p| p.parse_simple_unary_expression(lhs_span))
+        });
         self.ast.expression_await(self.end_span(span), argument)
     }
 
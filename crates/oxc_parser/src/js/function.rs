@@ -1,10 +1,12 @@
 use oxc_allocator::Box;
-use oxc_ast::ast::*;
+use oxc_ast::{NONE, ast::*};
 use oxc_span::{GetSpan, Span};
 
 use super::FunctionKind;
 use crate::{
-    Context, ParserImpl, StatementContext, diagnostics,
+    Context, ParserImpl, StatementContext,
+    cursor::ParserCheckpoint,
+    diagnostics,
     lexer::Kind,
     modifiers::{ModifierFlags, ModifierKind, Modifiers},
 };
@@ -34,7 +36,9 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::LCurly);
 
         let (directives, statements) = self.context_add(Context::Return, |p| {
-            p.parse_directives_and_statements(/* is_top_level */ false)
+            p.with_open_delimiter(Kind::RCurly, |p| {
+                p.parse_directives_and_statements(/* is_top_level */ false)
+            })
         });
 
         self.expect_closing(Kind::RCurly, opening_span);
@@ -56,17 +60,36 @@ impl<'a> ParserImpl<'a> {
         } else {
             None
         };
-        let (list, rest) = self.parse_formal_parameters_list(func_kind, opening_span);
-        self.expect(Kind::RParen);
+        let (list, rest) = self.parse_formal_parameters_list(func_kind, params_kind, opening_span);
+        if self.at(Kind::Arrow) && self.can_recover_unclosed_arrow_parameters(params_kind) {
+            self.record_recovery();
+            self.error(diagnostics::expect_closing(
+                Kind::RParen.to_str(),
+                Kind::Arrow.to_str(),
+                self.cur_token().span(),
+                opening_span,
+            ));
+            self.suggest_fix(Span::empty(self.cur_token().start()), Kind::RParen.to_str());
+        } else {
+            self.expect(Kind::RParen);
+        }
 
         let formal_parameters =
             self.ast.alloc_formal_parameters(self.end_span(span), params_kind, list, rest);
         (this_param, formal_parameters)
     }
 
+    /// Whether a `=>` can close the parameters of an arrow function which are missing their `)`,
+    /// e.g. `async (a, => a`.
+    fn can_recover_unclosed_arrow_parameters(&self, params_kind: FormalParameterKind) -> bool {
+        params_kind == FormalParameterKind::ArrowFormalParameters
+            && self.options.recover_from_errors
+    }
+
     fn parse_formal_parameters_list(
         &mut self,
         func_kind: FunctionKind,
+        params_kind: FormalParameterKind,
         opening_span: Span,
     ) -> (oxc_allocator::Vec<'a, FormalParameter<'a>>, Option<Box<'a, FormalParameterRest<'a>>>)
     {
@@ -106,6 +129,9 @@ impl<'a> ParserImpl<'a> {
                     }
                     break;
                 }
+                if kind == Kind::Arrow && self.can_recover_unclosed_arrow_parameters(params_kind) {
+                    break;
+                }
             }
 
             if let Some(r) = &rest {
@@ -131,6 +157,13 @@ impl<'a> ParserImpl<'a> {
             } else {
                 list.push(self.parse_formal_parameter(func_kind));
             }
+
+            if let Some(max_parameters) = self.options.max_parameters
+                && list.len() + usize::from(rest.is_some()) == max_parameters as usize + 1
+            {
+                let span = rest.as_ref().map_or_else(|| list[list.len() - 1].span, |r| r.span);
+                self.error(diagnostics::too_many_parameters(max_parameters, span));
+            }
         }
 
         (list, rest)
@@ -167,14 +200,24 @@ impl<'a> ParserImpl<'a> {
 
         // Now parse the initializer if present
         let init = if self.eat(Kind::Eq) {
-            let init =
-                self.context_add(Context::In, ParserImpl::parse_assignment_expression_or_higher);
-            if optional {
-                self.error(diagnostics::a_parameter_cannot_have_question_mark_and_initializer(
-                    pattern.span(),
-                ));
+            // `function f(a = , b) {}`, `(x = ;) => x`
+            if self.options.recover_from_errors
+                && matches!(self.cur_kind(), Kind::Comma | Kind::RParen | Kind::Semicolon)
+            {
+                self.record_recovery();
+                self.error(diagnostics::parameter_default_expected(self.cur_token().span()));
+                self.bump(Kind::Semicolon);
+                None
+            } else {
+                let init = self
+                    .context_add(Context::In, ParserImpl::parse_assignment_expression_or_higher);
+                if optional {
+                    self.error(diagnostics::a_parameter_cannot_have_question_mark_and_initializer(
+                        pattern.span(),
+                    ));
+                }
+                Some(init)
             }
-            Some(init)
         } else {
             None
         };
@@ -223,16 +266,42 @@ impl<'a> ParserImpl<'a> {
     ) -> Box<'a, Function<'a>> {
         let ctx = self.ctx;
         self.ctx = self.ctx.and_in(true).and_await(r#async).and_yield(generator);
+        // An overload signature may be followed by more overloads and the implementation,
+        // so an error inside it should not take the rest of the group with it.
+        let checkpoint = (self.is_ts
+            && self.options.recover_from_errors
+            && matches!(func_kind, FunctionKind::Declaration | FunctionKind::TSDeclaration))
+        .then(|| self.checkpoint());
         let type_parameters = self.parse_ts_type_parameters();
         let (this_param, params) = self.parse_formal_parameters(func_kind, param_kind);
         let return_type = if self.is_ts { self.parse_ts_return_type_annotation() } else { None };
+        if let Some(checkpoint) = checkpoint
+            && self.fatal_error.is_some()
+        {
+            self.ctx = ctx;
+            return self
+                .recover_from_invalid_function_signature(span, id, r#async, generator, checkpoint);
+        }
+        if self.options.recover_from_errors
+            && matches!(self.cur_kind(), Kind::Extends | Kind::Implements)
+        {
+            self.skip_function_heritage_clause();
+        }
         let body = if self.at(Kind::LCurly) || func_kind == FunctionKind::Expression {
+            if self.options.validate_ambient_declarations {
+                // Only the outermost implementation in an ambient context is reported.
+                self.ctx = self.ctx.and_ambient(false);
+            }
             Some(self.parse_function_body())
         } else {
             None
         };
-        self.ctx =
-            self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
+        self.ctx = self
+            .ctx
+            .and_in(ctx.has_in())
+            .and_await(ctx.has_await())
+            .and_yield(ctx.has_yield())
+            .and_ambient(ctx.has_ambient());
         if (!self.is_ts || matches!(func_kind, FunctionKind::ObjectMethod)) && body.is_none() {
             return self.fatal_error(diagnostics::expect_function_body(self.end_span(span)));
         }
@@ -264,7 +333,7 @@ impl<'a> ParserImpl<'a> {
         }
 
         if ctx.has_ambient()
-            && modifiers.contains_declare()
+            && (modifiers.contains_declare() || self.options.validate_ambient_declarations)
             && let Some(body) = &body
         {
             self.error(diagnostics::implementation_in_ambient(Span::empty(body.span.start)));
@@ -276,6 +345,9 @@ impl<'a> ParserImpl<'a> {
             diagnostics::modifier_cannot_be_used_here,
         );
 
+        if self.options.collect_stats {
+            self.shape_counts.functions += 1;
+        }
         self.ast.alloc_function(
             self.end_span(span),
             function_type,
@@ -291,6 +363,131 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
+    /// Recover from a syntax error in the signature of a TypeScript function declaration,
+    /// e.g. the second overload in
+    /// ```ts
+    /// function f(a: string): void;
+    /// function f(a: number: void;
+    /// function f(a) {}
+    /// ```
+    ///
+    /// Rewinds to the start of the signature and skips to the end of its line or semicolon,
+    /// keeping the function as a [`FunctionType::TSDeclareFunction`] without parameters.
+    #[cold]
+    fn recover_from_invalid_function_signature(
+        &mut self,
+        span: u32,
+        id: Option<BindingIdentifier<'a>>,
+        r#async: bool,
+        generator: bool,
+        checkpoint: ParserCheckpoint<'a>,
+    ) -> Box<'a, Function<'a>> {
+        let error = self.fatal_error.take().map(|fatal_error| fatal_error.error);
+        self.rewind(checkpoint);
+        self.record_recovery();
+        if let Some(error) = error {
+            self.error(error);
+        }
+
+        let params_span = self.start_span();
+        while !matches!(self.cur_kind(), Kind::Eof | Kind::Semicolon)
+            && !self.cur_token().is_on_new_line()
+        {
+            self.bump_any();
+        }
+        let params = self.ast.alloc_formal_parameters(
+            self.end_span(params_span),
+            FormalParameterKind::FormalParameter,
+            self.ast.vec(),
+            NONE,
+        );
+        self.bump(Kind::Semicolon);
+
+        if self.options.collect_stats {
+            self.shape_counts.functions += 1;
+        }
+        self.ast.alloc_function(
+            self.end_span(span),
+            FunctionType::TSDeclareFunction,
+            id,
+            generator,
+            r#async,
+            /* declare */ false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            NONE,
+        )
+    }
+
+    /// Skip an `extends` or `implements` clause after the parameters of a function, e.g.
+    /// `extends B` in `function f() extends B {}`, up to the function body.
+    #[cold]
+    fn skip_function_heritage_clause(&mut self) {
+        let span = self.start_span();
+        let keyword = self.cur_kind();
+        self.record_recovery();
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof | Kind::Undetermined => break,
+                Kind::LCurly | Kind::Semicolon if depth == 0 => break,
+                Kind::LParen | Kind::LBrack => depth += 1,
+                Kind::RParen | Kind::RBrack => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+        }
+        let span = self.end_span(span);
+        self.error(diagnostics::function_heritage_clause(keyword.to_str(), span));
+        if self.at(Kind::LCurly) {
+            self.suggest_fix(Span::new(span.start, self.cur_token().start()), "");
+        }
+    }
+
+    /// Parse the rest of an accessor whose name is missing, e.g. the body in `{ get {} }`.
+    ///
+    /// The missing name has already been reported, so the missing parameter list is not, and the
+    /// accessor gets an empty one. The body is parsed if present, otherwise it is left empty,
+    /// so parsing can continue with the next member.
+    #[cold]
+    pub(crate) fn parse_accessor_without_name(&mut self, r#async: bool) -> Box<'a, Function<'a>> {
+        let span = if self.at(Kind::LCurly) { self.start_span() } else { self.prev_token_end };
+        let params = self.ast.alloc_formal_parameters(
+            Span::empty(self.prev_token_end),
+            FormalParameterKind::UniqueFormalParameters,
+            self.ast.vec(),
+            NONE,
+        );
+        let body = if self.at(Kind::LCurly) {
+            let ctx = self.ctx;
+            self.ctx = self.ctx.and_in(true).and_await(r#async).and_yield(false);
+            let body = self.parse_function_body();
+            self.ctx =
+                self.ctx.and_in(ctx.has_in()).and_await(ctx.has_await()).and_yield(ctx.has_yield());
+            body
+        } else {
+            self.ast.alloc_function_body(Span::empty(span), self.ast.vec(), self.ast.vec())
+        };
+        if self.options.collect_stats {
+            self.shape_counts.functions += 1;
+        }
+        self.ast.alloc_function(
+            self.end_span(span),
+            FunctionType::FunctionExpression,
+            None,
+            /* generator */ false,
+            r#async,
+            /* declare */ false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+        )
+    }
+
     /// [Function Declaration](https://tc39.es/ecma262/#prod-FunctionDeclaration)
     pub(crate) fn parse_function_declaration(
         &mut self,
@@ -457,7 +654,7 @@ impl<'a> ParserImpl<'a> {
             if func_kind.is_expression() {
                 ctx = ctx.and_await(r#async).and_yield(generator);
             }
-            self.check_identifier(kind, ctx);
+            self.check_binding_identifier(kind, ctx, self.cur_token().span());
 
             let (span, name) = self.parse_identifier_kind(Kind::Ident);
             Some(self.ast.binding_identifier(span, name))
@@ -38,9 +38,11 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
             Expression::ParenthesizedExpression(expr) => {
                 let span = expr.span;
                 match expr.unbox().expression {
-                    Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => {
-                        p.fatal_error(diagnostics::invalid_assignment(span))
-                    }
+                    Expression::ObjectExpression(_) | Expression::ArrayExpression(_) => p
+                        .invalid_simple_assignment_target(
+                            diagnostics::invalid_assignment(span),
+                            span,
+                        ),
                     expr => SimpleAssignmentTarget::cover(expr, p),
                 }
             }
@@ -51,7 +53,10 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
                 | Expression::PrivateFieldExpression(_) => {
                     SimpleAssignmentTarget::TSAsExpression(expr)
                 }
-                _ => p.fatal_error(diagnostics::invalid_assignment(expr.span())),
+                _ => {
+                    let span = expr.span();
+                    p.invalid_simple_assignment_target(diagnostics::invalid_assignment(span), span)
+                }
             },
             Expression::TSSatisfiesExpression(expr) => {
                 match expr.expression.get_inner_expression() {
@@ -61,7 +66,13 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
                     | Expression::PrivateFieldExpression(_) => {
                         SimpleAssignmentTarget::TSSatisfiesExpression(expr)
                     }
-                    _ => p.fatal_error(diagnostics::invalid_assignment(expr.span())),
+                    _ => {
+                        let span = expr.span();
+                        p.invalid_simple_assignment_target(
+                            diagnostics::invalid_assignment(span),
+                            span,
+                        )
+                    }
                 }
             }
             Expression::TSNonNullExpression(expr) => match expr.expression.get_inner_expression() {
@@ -71,7 +82,10 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
                 | Expression::PrivateFieldExpression(_) => {
                     SimpleAssignmentTarget::TSNonNullExpression(expr)
                 }
-                _ => p.fatal_error(diagnostics::invalid_assignment(expr.span())),
+                _ => {
+                    let span = expr.span();
+                    p.invalid_simple_assignment_target(diagnostics::invalid_assignment(span), span)
+                }
             },
             Expression::TSTypeAssertion(expr) => match expr.expression.get_inner_expression() {
                 Expression::Identifier(_)
@@ -80,12 +94,19 @@ impl<'a> CoverGrammar<'a, Expression<'a>> for SimpleAssignmentTarget<'a> {
                 | Expression::PrivateFieldExpression(_) => {
                     SimpleAssignmentTarget::TSTypeAssertion(expr)
                 }
-                _ => p.fatal_error(diagnostics::invalid_assignment(expr.span())),
+                _ => {
+                    let span = expr.span();
+                    p.invalid_simple_assignment_target(diagnostics::invalid_assignment(span), span)
+                }
             },
             Expression::TSInstantiationExpression(expr) => {
-                p.fatal_error(diagnostics::invalid_lhs_assignment(expr.span()))
+                let span = expr.span();
+                p.invalid_simple_assignment_target(diagnostics::invalid_lhs_assignment(span), span)
+            }
+            expr => {
+                let span = expr.span();
+                p.invalid_simple_assignment_target(diagnostics::invalid_assignment(span), span)
             }
-            expr => p.fatal_error(diagnostics::invalid_assignment(expr.span())),
         }
     }
 }
@@ -95,6 +116,9 @@ impl<'a> CoverGrammar<'a, ArrayExpression<'a>> for ArrayAssignmentTarget<'a> {
         let mut elements = p.ast.vec();
         let mut rest = None;
 
+        // Elisions are common in patterns `[, a] = b`
+        p.state.sparse_array_elisions.remove(&expr.span.start);
+
         let len = expr.elements.len();
         for (i, elem) in expr.elements.into_iter().enumerate() {
             match elem {
@@ -167,6 +191,9 @@ impl<'a> CoverGrammar<'a, ObjectExpression<'a>> for ObjectAssignmentTarget<'a> {
         let mut properties = p.ast.vec();
         let mut rest = None;
 
+        // Duplicate `__proto__` is allowed in patterns
+        p.state.duplicate_proto.remove(&expr.span.start);
+
         let len = expr.properties.len();
         for (i, elem) in expr.properties.into_iter().enumerate() {
             match elem {
@@ -0,0 +1,216 @@
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+
+use crate::{ExpressionRestriction, ParserImpl, diagnostics, lexer::Kind};
+
+impl<'a> ParserImpl<'a> {
+    /// Parse a single JSON or JSON5 value followed by the end of the source,
+    /// see [`ParseOptions::expression_restriction`](crate::ParseOptions::expression_restriction).
+    pub(crate) fn parse_json_text(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        let expr = self.parse_json_value(restriction);
+        self.expect(Kind::Eof);
+        if restriction == ExpressionRestriction::Json {
+            for comment in &self.lexer.trivia_builder.comments {
+                self.errors.push(diagnostics::json_comment(comment.span));
+            }
+        }
+        expr
+    }
+
+    fn parse_json_value(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        self.with_nesting_guard(|p| p.parse_json_value_guarded(restriction))
+    }
+
+    fn parse_json_value_guarded(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        match self.cur_kind() {
+            Kind::LCurly => self.parse_json_object(restriction),
+            Kind::LBrack => self.parse_json_array(restriction),
+            Kind::Str => {
+                let lit = self.parse_json_string(restriction);
+                Expression::StringLiteral(self.alloc(lit))
+            }
+            Kind::True | Kind::False | Kind::Null => self.parse_literal_expression(),
+            Kind::Minus | Kind::Plus => self.parse_json_signed_number(restriction),
+            kind if kind.is_number() => self.parse_json_number(restriction),
+            Kind::Ident if self.is_json5_number_identifier(restriction) => {
+                self.parse_identifier_expression()
+            }
+            _ => {
+                // Parse the whole expression, so that the error covers all of it.
+                let expr = self.parse_assignment_expression_or_higher();
+                let format = restriction.as_str();
+                let error = match &expr {
+                    Expression::Identifier(ident) => {
+                        diagnostics::json_identifier(format, ident.span)
+                    }
+                    expr => diagnostics::json_unsupported_expression(format, expr.span()),
+                };
+                self.error(error);
+                expr
+            }
+        }
+    }
+
+    /// `{ "a": 1, "b": 2 }`
+    fn parse_json_object(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        let span = self.start_span();
+        let opening_span = self.cur_token().span();
+        self.expect(Kind::LCurly);
+        let (properties, comma_span) =
+            self.parse_delimited_list(Kind::RCurly, Kind::Comma, opening_span, |p| {
+                p.parse_json_property(restriction)
+            });
+        if let Some(comma_span) = comma_span {
+            self.check_json_trailing_comma(comma_span, restriction);
+        }
+        self.expect(Kind::RCurly);
+        self.ast.expression_object(self.end_span(span), properties)
+    }
+
+    fn parse_json_property(
+        &mut self,
+        restriction: ExpressionRestriction,
+    ) -> ObjectPropertyKind<'a> {
+        let span = self.start_span();
+        let kind = self.cur_kind();
+        let key = if kind == Kind::Str {
+            let lit = self.parse_json_string(restriction);
+            PropertyKey::StringLiteral(self.alloc(lit))
+        } else if restriction == ExpressionRestriction::Json5 && kind.is_identifier_name() {
+            let ident = self.parse_identifier_name();
+            PropertyKey::StaticIdentifier(self.alloc(ident))
+        } else {
+            let (key, _) = self.parse_property_name();
+            let expected = match restriction {
+                ExpressionRestriction::Json => "a string",
+                ExpressionRestriction::Json5 => "a string or an identifier",
+            };
+            self.error(diagnostics::json_invalid_property_key(
+                restriction.as_str(),
+                expected,
+                key.span(),
+            ));
+            key
+        };
+        self.expect(Kind::Colon);
+        let value = self.parse_json_value(restriction);
+        self.ast.object_property_kind_object_property(
+            self.end_span(span),
+            PropertyKind::Init,
+            key,
+            value,
+            /* method */ false,
+            /* shorthand */ false,
+            /* computed */ false,
+        )
+    }
+
+    /// `[1, 2]`
+    fn parse_json_array(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        let span = self.start_span();
+        let opening_span = self.cur_token().span();
+        self.expect(Kind::LBrack);
+        let (elements, comma_span) =
+            self.parse_delimited_list(Kind::RBrack, Kind::Comma, opening_span, |p| {
+                ArrayExpressionElement::from(p.parse_json_value(restriction))
+            });
+        if let Some(comma_span) = comma_span {
+            self.check_json_trailing_comma(comma_span, restriction);
+        }
+        self.expect(Kind::RBrack);
+        self.ast.expression_array(self.end_span(span), elements)
+    }
+
+    fn check_json_trailing_comma(&mut self, comma_span: u32, restriction: ExpressionRestriction) {
+        if restriction == ExpressionRestriction::Json {
+            self.error(diagnostics::json_trailing_comma(Span::sized(comma_span, 1)));
+        }
+    }
+
+    fn parse_json_string(&mut self, restriction: ExpressionRestriction) -> StringLiteral<'a> {
+        let lit = self.parse_literal_string();
+        if restriction == ExpressionRestriction::Json
+            && lit.span.source_text(self.source_text).starts_with('\'')
+        {
+            self.error(diagnostics::json_single_quoted_string(lit.span));
+        }
+        lit
+    }
+
+    /// `-1`, and in JSON5 `+1`, `-Infinity` and `+NaN`
+    fn parse_json_signed_number(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        let span = self.start_span();
+        let sign_span = self.cur_token().span();
+        let operator = if self.at(Kind::Minus) {
+            UnaryOperator::UnaryNegation
+        } else {
+            UnaryOperator::UnaryPlus
+        };
+        let format = restriction.as_str();
+        let sign_allowed =
+            operator == UnaryOperator::UnaryNegation || restriction == ExpressionRestriction::Json5;
+        if !sign_allowed {
+            self.error(diagnostics::json_plus_sign(format, sign_span));
+        }
+        self.bump_any();
+        let argument = if self.cur_kind().is_number() {
+            self.parse_json_number(restriction)
+        } else if self.is_json5_number_identifier(restriction) {
+            self.parse_identifier_expression()
+        } else {
+            let argument = self.parse_json_value(restriction);
+            if sign_allowed {
+                self.error(diagnostics::json_invalid_sign(format, operator.as_str(), sign_span));
+            }
+            argument
+        };
+        self.ast.expression_unary(self.end_span(span), operator, argument)
+    }
+
+    fn parse_json_number(&mut self, restriction: ExpressionRestriction) -> Expression<'a> {
+        let token = self.cur_token();
+        let valid =
+            !token.has_separator() && is_json_number(token.kind(), self.cur_src(), restriction);
+        let expr = self.parse_literal_expression();
+        if !valid {
+            self.error(diagnostics::json_invalid_number(restriction.as_str(), expr.span()));
+        }
+        expr
+    }
+
+    /// `Infinity` and `NaN` are numbers in JSON5.
+    fn is_json5_number_identifier(&self, restriction: ExpressionRestriction) -> bool {
+        restriction == ExpressionRestriction::Json5
+            && self.at(Kind::Ident)
+            && matches!(self.cur_src(), "Infinity" | "NaN")
+    }
+}
+
+/// Whether `raw` is an unsigned number in `restriction`.
+///
+/// JSON numbers are `(0|[1-9][0-9]*)(\.[0-9]+)?([eE][+-]?[0-9]+)?`.
+/// JSON5 also allows hexadecimal numbers and a leading or trailing `.` (`.5`, `5.`).
+fn is_json_number(kind: Kind, raw: &str, restriction: ExpressionRestriction) -> bool {
+    let json5 = restriction == ExpressionRestriction::Json5;
+    match kind {
+        Kind::Hex => json5,
+        Kind::Decimal | Kind::Float | Kind::PositiveExponential | Kind::NegativeExponential => {
+            // The lexer has already checked the exponent.
+            let mantissa = raw.split(['e', 'E']).next().unwrap_or(raw);
+            let (int, fraction) = match mantissa.split_once('.') {
+                Some((int, fraction)) => (int, Some(fraction)),
+                None => (mantissa, None),
+            };
+            if int.len() > 1 && int.starts_with('0') {
+                return false;
+            }
+            match fraction {
+                None => true,
+                Some("") => json5,
+                Some(_) => json5 || !int.is_empty(),
+            }
+        }
+        // Binary, octal and BigInt literals
+        _ => false,
+    }
+}
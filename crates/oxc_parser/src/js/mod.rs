@@ -8,6 +8,7 @@ mod class;
 mod declaration;
 mod expression;
 mod function;
+mod json;
 mod module;
 mod object;
 mod operator;
@@ -1,11 +1,11 @@
 use oxc_allocator::{Box, Vec};
 use oxc_ast::{NONE, ast::*};
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashMap;
 
 use super::FunctionKind;
 use crate::{
-    ParserImpl, StatementContext, diagnostics,
+    ExportDefaultSpan, ParserImpl, StatementContext, diagnostics,
     lexer::Kind,
     modifiers::{Modifier, ModifierFlags, ModifierKind, Modifiers},
 };
@@ -242,6 +242,14 @@ impl<'a> ParserImpl<'a> {
         );
 
         if should_record_module_record {
+            if self.options.module_system_hints
+                && self.is_ts
+                && self.source_type.is_script()
+                && !self.source_type.is_typescript_definition()
+                && import_kind.is_value()
+            {
+                self.error(diagnostics::esm_import_in_commonjs(span));
+            }
             self.module_record_builder.visit_import_declaration(&import_decl);
         }
 
@@ -389,10 +397,21 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::Eq);
         let expression = self.parse_assignment_expression_or_higher();
         self.asi();
+        let span = self.end_span(start_span);
+        if !self.is_ts {
+            self.error(diagnostics::export_assignment_can_only_be_used_in_typescript_files(span));
+        }
         if stmt_ctx.is_top_level() {
-            self.module_record_builder.found_ts_export();
+            if self.options.module_system_hints
+                && self.is_ts
+                && self.source_type.is_module()
+                && !self.source_type.is_typescript_definition()
+            {
+                self.error(diagnostics::export_assignment_in_module(span));
+            }
+            self.module_record_builder.found_ts_export(span);
         }
-        self.ast.alloc_ts_export_assignment(self.end_span(start_span), expression)
+        self.ast.alloc_ts_export_assignment(span, expression)
     }
 
     pub(crate) fn parse_ts_export_namespace(
@@ -404,10 +423,11 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::Namespace);
         let id = self.parse_identifier_name();
         self.asi();
+        let span = self.end_span(start_span);
         if stmt_ctx.is_top_level() {
-            self.module_record_builder.found_ts_export();
+            self.module_record_builder.found_ts_export(span);
         }
-        self.ast.alloc_ts_namespace_export_declaration(self.end_span(start_span), id)
+        self.ast.alloc_ts_namespace_export_declaration(span, id)
     }
 
     /// [Exports](https://tc39.es/ecma262/#sec-exports)
@@ -417,6 +437,7 @@ impl<'a> ParserImpl<'a> {
         mut decorators: Vec<'a, Decorator<'a>>,
         stmt_ctx: StatementContext,
     ) -> Statement<'a> {
+        let export_start = self.cur_token().start();
         self.bump_any(); // bump `export`
         let decl = match self.cur_kind() {
             // `export import A = B`
@@ -469,7 +490,7 @@ impl<'a> ParserImpl<'a> {
                 }
                 ModuleDeclaration::ExportNamedDeclaration(export_named_decl)
             }
-            Kind::Eq if self.is_ts => ModuleDeclaration::TSExportAssignment(
+            Kind::Eq => ModuleDeclaration::TSExportAssignment(
                 self.parse_ts_export_assignment_declaration(span, stmt_ctx),
             ),
             Kind::As if self.is_ts && self.lexer.peek_token().kind() == Kind::Namespace => {
@@ -478,8 +499,11 @@ impl<'a> ParserImpl<'a> {
                     self.parse_ts_export_namespace(span, stmt_ctx),
                 )
             }
+            Kind::Default if self.at_export_default_variable_declaration() => {
+                return self.parse_export_default_variable_declaration(stmt_ctx);
+            }
             Kind::Default => ModuleDeclaration::ExportDefaultDeclaration(
-                self.parse_export_default_declaration(span, decorators, stmt_ctx),
+                self.parse_export_default_declaration(span, export_start, decorators, stmt_ctx),
             ),
             Kind::Star => ModuleDeclaration::ExportAllDeclaration(
                 self.parse_export_all_declaration(span, stmt_ctx),
@@ -631,12 +655,55 @@ impl<'a> ParserImpl<'a> {
         export_named_decl
     }
 
+    /// `export default const x = 1;`, `export default let x;` or `export default var x;`
+    fn at_export_default_variable_declaration(&mut self) -> bool {
+        self.lookahead(|p| {
+            p.bump_any(); // bump `default`
+            match p.cur_kind() {
+                Kind::Var => true,
+                Kind::Const => {
+                    p.bump_any();
+                    !(p.is_ts && p.at(Kind::Enum))
+                }
+                Kind::Let => p.at_lexical_declaration(),
+                _ => false,
+            }
+        })
+    }
+
+    /// Parse `export default` followed by a variable declaration.
+    ///
+    /// In recovery mode, the variable declaration is kept as a statement of its own,
+    /// and no default export is recorded.
+    #[cold]
+    fn parse_export_default_variable_declaration(
+        &mut self,
+        stmt_ctx: StatementContext,
+    ) -> Statement<'a> {
+        self.bump_remap(Kind::Default);
+        let kind_span = self.cur_token().span();
+        let error = diagnostics::export_default_variable_declaration(kind_span);
+        if !self.options.recover_from_errors {
+            return self.fatal_error(error);
+        }
+        self.record_recovery();
+        self.error(error);
+        let kind = match self.cur_kind() {
+            Kind::Var => VariableDeclarationKind::Var,
+            Kind::Let => VariableDeclarationKind::Let,
+            _ => VariableDeclarationKind::Const,
+        };
+        self.bump_any();
+        self.parse_variable_statement(kind_span.start, kind, stmt_ctx)
+    }
+
     // export default HoistableDeclaration[~Yield, +Await, +Default]
     // export default ClassDeclaration[~Yield, +Await, +Default]
     // export default AssignmentExpression[+In, ~Yield, +Await] ;
     fn parse_export_default_declaration(
         &mut self,
         span: u32,
+        export_start: u32,
         decorators: Vec<'a, Decorator<'a>>,
         stmt_ctx: StatementContext,
     ) -> Box<'a, ExportDefaultDeclaration<'a>> {
@@ -648,6 +715,10 @@ impl<'a> ParserImpl<'a> {
         if stmt_ctx.is_top_level() {
             self.module_record_builder
                 .visit_export_default_declaration(&export_default_decl, default_keyword_span);
+            self.export_default_span.get_or_insert(ExportDefaultSpan {
+                keyword: Span::new(export_start, default_keyword_span.end),
+                value: export_default_decl.declaration.span(),
+            });
         }
         export_default_decl
     }
@@ -701,10 +772,24 @@ impl<'a> ParserImpl<'a> {
                         .vec1(Modifier::new(self.end_span(modifier_span), ModifierKind::Abstract));
                     let modifiers = Modifiers::new(Some(modifiers), ModifierFlags::ABSTRACT);
                     return ExportDefaultDeclarationKind::ClassDeclaration(
-                        self.parse_class_declaration(decl_span, &modifiers, decorators),
+                        self.parse_export_default_class_declaration(
+                            decl_span, &modifiers, decorators,
+                        ),
                     );
                 }
 
+                // export default abstract function ...
+                if is_abstract
+                    && self.options.recover_from_errors
+                    && matches!(kind, Kind::Function | Kind::Async | Kind::Interface)
+                {
+                    let modifier =
+                        Modifier::new(self.end_span(modifier_span), ModifierKind::Abstract);
+                    self.record_recovery();
+                    self.error(diagnostics::modifier_cannot_be_used_here(&modifier, None));
+                    return self.parse_export_default_declaration_kind(decorators);
+                }
+
                 // export default async function ...
                 if is_async && kind == Kind::Function {
                     for decorator in &decorators {
@@ -739,11 +824,13 @@ impl<'a> ParserImpl<'a> {
         let kind = self.cur_kind();
         // export default class ...
         if kind == Kind::Class {
-            return ExportDefaultDeclarationKind::ClassDeclaration(self.parse_class_declaration(
-                decl_span,
-                &Modifiers::empty(),
-                decorators,
-            ));
+            return ExportDefaultDeclarationKind::ClassDeclaration(
+                self.parse_export_default_class_declaration(
+                    decl_span,
+                    &Modifiers::empty(),
+                    decorators,
+                ),
+            );
         }
 
         for decorator in &decorators {
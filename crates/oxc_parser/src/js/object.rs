@@ -1,5 +1,7 @@
 use oxc_allocator::Box;
 use oxc_ast::ast::*;
+use oxc_ecmascript::PropName;
+use oxc_span::Span;
 use oxc_syntax::operator::AssignmentOperator;
 
 use crate::{
@@ -21,27 +23,52 @@ impl<'a> ParserImpl<'a> {
         let opening_span = self.cur_token().span();
         self.expect(Kind::LCurly);
         let (object_expression_properties, comma_span) = self.context_add(Context::In, |p| {
-            p.parse_delimited_list(
-                Kind::RCurly,
-                Kind::Comma,
-                opening_span,
-                Self::parse_object_expression_property,
-            )
+            p.with_open_delimiter(Kind::RCurly, |p| {
+                p.parse_object_literal_list(opening_span, Self::parse_object_expression_property)
+            })
         });
         if let Some(comma_span) = comma_span {
             self.state.trailing_commas.insert(span, self.end_span(comma_span));
         }
-        self.expect(Kind::RCurly);
+        self.expect_closing(Kind::RCurly, opening_span);
+        self.check_duplicate_proto(span, &object_expression_properties);
         self.ast.alloc_object_expression(self.end_span(span), object_expression_properties)
     }
 
-    fn parse_object_expression_property(&mut self) -> ObjectPropertyKind<'a> {
-        match self.cur_kind() {
-            Kind::Dot3 => ObjectPropertyKind::SpreadProperty(self.parse_spread_element()),
-            _ => ObjectPropertyKind::ObjectProperty(self.parse_object_literal_element()),
+    /// ObjectLiteral : { PropertyDefinitionList }
+    /// It is a Syntax Error if PropertyNameList of PropertyDefinitionList contains any duplicate entries for "__proto__"
+    /// and at least two of those entries were obtained from productions of the form PropertyDefinition : PropertyName : AssignmentExpression
+    ///
+    /// The object may still turn out to be an assignment pattern, where duplicates are allowed,
+    /// so the error is reported in `check_unfinished_errors` unless the cover grammar removes it.
+    fn check_duplicate_proto(&mut self, span: u32, properties: &[ObjectPropertyKind<'a>]) {
+        let mut prev_proto: Option<Span> = None;
+        let mut duplicates = vec![];
+        for prop in properties {
+            if let ObjectPropertyKind::ObjectProperty(prop) = prop
+                && prop.kind == PropertyKind::Init
+                && !prop.method
+                && let Some((name, key_span)) = prop.prop_name()
+                && name == "__proto__"
+            {
+                if let Some(prev_span) = prev_proto {
+                    duplicates.push((prev_span, key_span));
+                }
+                prev_proto = Some(key_span);
+            }
+        }
+        if !duplicates.is_empty() {
+            self.state.duplicate_proto.insert(span, duplicates);
         }
     }
 
+    fn parse_object_expression_property(&mut self) -> ObjectPropertyKind<'a> {
+        self.track_recovered_node(|p| match p.cur_kind() {
+            Kind::Dot3 => ObjectPropertyKind::SpreadProperty(p.parse_spread_element()),
+            _ => ObjectPropertyKind::ObjectProperty(p.parse_object_literal_element()),
+        })
+    }
+
     /// `PropertyDefinition`[Yield, Await]
     fn parse_object_literal_element(&mut self) -> Box<'a, ObjectProperty<'a>> {
         let span = self.start_span();
@@ -51,11 +78,11 @@ impl<'a> ParserImpl<'a> {
             /* stop_on_start_of_class_static_block */ false,
         );
 
-        if self.parse_contextual_modifier(Kind::Get) {
+        if self.parse_accessor_keyword(Kind::Get) {
             return self.parse_method_getter_setter(span, PropertyKind::Get, &modifiers);
         }
 
-        if self.parse_contextual_modifier(Kind::Set) {
+        if self.parse_accessor_keyword(Kind::Set) {
             return self.parse_method_getter_setter(span, PropertyKind::Set, &modifiers);
         }
 
@@ -98,6 +125,9 @@ impl<'a> ParserImpl<'a> {
 
         if is_shorthand_property_assignment {
             if let PropertyKey::StaticIdentifier(identifier_name) = key {
+                if self.commonjs_usage.is_some() {
+                    self.detect_commonjs_identifier(&identifier_name.name, identifier_name.span);
+                }
                 let identifier_reference =
                     self.ast.identifier_reference(identifier_name.span, identifier_name.name);
                 let value = Expression::Identifier(self.alloc(identifier_reference.clone()));
@@ -202,13 +232,20 @@ impl<'a> ParserImpl<'a> {
         kind: PropertyKind,
         modifiers: &Modifiers<'a>,
     ) -> Box<'a, ObjectProperty<'a>> {
-        let (key, computed) = self.parse_property_name();
-        let function = self.parse_method(false, false, FunctionKind::ObjectMethod);
-        match kind {
-            PropertyKind::Get => self.check_getter(&function),
-            PropertyKind::Set => self.check_setter(&function),
-            PropertyKind::Init => {}
-        }
+        let (key, computed, function) = if self.can_follow_get_or_set_keyword() {
+            let (key, computed) = self.parse_property_name();
+            let function = self.parse_method(false, false, FunctionKind::ObjectMethod);
+            match kind {
+                PropertyKind::Get => self.check_getter(&function.params),
+                PropertyKind::Set => self.check_setter(&function.params),
+                PropertyKind::Init => {}
+            }
+            (key, computed, function)
+        } else {
+            let keyword = if kind == PropertyKind::Set { "set" } else { "get" };
+            let key = self.recover_missing_accessor_name(keyword);
+            (key, false, self.parse_accessor_without_name(false))
+        };
         self.verify_modifiers(
             modifiers,
             ModifierFlags::empty(),
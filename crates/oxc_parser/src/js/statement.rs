@@ -42,36 +42,107 @@ impl<'a> ParserImpl<'a> {
             StatementContext::StatementList
         };
 
+        let ctx = self.ctx;
         let mut expecting_directives = true;
+        while let Some(stmt) = self.parse_next_statement_list_item(
+            stmt_ctx,
+            &mut directives,
+            &mut expecting_directives,
+        ) {
+            statements.push(stmt);
+        }
+        if !is_top_level {
+            self.ctx = self.ctx.and_strict_mode(ctx.has_strict_mode());
+        }
+
+        (directives, statements)
+    }
+
+    /// Parse up to the next statement in a statement list, adding directives before it to
+    /// `directives` while `expecting_directives`.
+    ///
+    /// Returns `None` at the end of the list.
+    pub(crate) fn parse_next_statement_list_item(
+        &mut self,
+        stmt_ctx: StatementContext,
+        directives: &mut Vec<'a, Directive<'a>>,
+        expecting_directives: &mut bool,
+    ) -> Option<Statement<'a>> {
+        let is_top_level = matches!(stmt_ctx, StatementContext::TopLevelStatementList);
         while !self.has_fatal_error() {
             if !is_top_level && self.at(Kind::RCurly) {
                 break;
             }
+            // A directive must start with a string literal token.
+            if *expecting_directives && !self.at(Kind::Str) {
+                *expecting_directives = false;
+            }
+            let start = self.cur_token().start();
             let stmt = self.parse_statement_list_item(stmt_ctx);
+            // A stray closing delimiter, e.g. the `}` in `a; }`, is reported as a missing
+            // expression without being consumed. Skip it, otherwise it would be parsed forever.
+            if self.cur_token().start() == start && !self.has_fatal_error() {
+                self.record_skipped_statement_token(start);
+                self.bump_any();
+                continue;
+            }
 
-            // Section 11.2.1 Directive Prologue
-            // The only way to get a correct directive is to parse the statement first and check if it is a string literal.
-            // All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/v7.26.2/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
-            if expecting_directives {
-                if let Statement::ExpressionStatement(expr) = &stmt
-                    && let Expression::StringLiteral(string) = &expr.expression
-                {
-                    // span start will mismatch if they are parenthesized when `preserve_parens = false`
-                    if expr.span.start == string.span.start {
-                        let src = &self.source_text
-                            [string.span.start as usize + 1..string.span.end as usize - 1];
-                        let directive =
-                            self.ast.directive(expr.span, (*string).clone(), Atom::from(src));
-                        directives.push(directive);
-                        continue;
+            if *expecting_directives {
+                if let Some(directive) = self.statement_to_directive(&stmt) {
+                    if directive.directive == "use strict" {
+                        self.ctx = self.ctx.and_strict_mode(true);
                     }
+                    directives.push(directive);
+                    continue;
                 }
-                expecting_directives = false;
+                *expecting_directives = false;
+            }
+            if self.is_kept_in_statement_list(&stmt) {
+                return Some(stmt);
             }
-            statements.push(stmt);
         }
+        None
+    }
 
-        (directives, statements)
+    /// Whether `stmt` is kept in a statement list, see [`crate::ParseOptions::preserve_empty_statements`].
+    #[inline]
+    fn is_kept_in_statement_list(&self, stmt: &Statement<'a>) -> bool {
+        self.options.preserve_empty_statements || !matches!(stmt, Statement::EmptyStatement(_))
+    }
+
+    /// Parse only the directive prologue, stopping at the first statement which is not a directive.
+    pub(crate) fn parse_directive_prologue(&mut self) -> Vec<'a, Directive<'a>> {
+        let mut directives = self.ast.vec();
+        // A directive must start with a string literal token, so any other token ends the prologue
+        // without needing to parse the statement.
+        while !self.has_fatal_error() && self.at(Kind::Str) {
+            let stmt = self.parse_statement_list_item(StatementContext::TopLevelStatementList);
+            let Some(directive) = self.statement_to_directive(&stmt) else { break };
+            directives.push(directive);
+        }
+        directives
+    }
+
+    /// Section 11.2.1 Directive Prologue
+    /// The only way to get a correct directive is to parse the statement first and check if it is a string literal.
+    /// All other method are flawed, see test cases in [babel](https://github.com/babel/babel/blob/v7.26.2/packages/babel-parser/test/fixtures/core/categorized/not-directive/input.js)
+    fn statement_to_directive(&self, stmt: &Statement<'a>) -> Option<Directive<'a>> {
+        if let Statement::ExpressionStatement(expr) = stmt
+            && let Expression::StringLiteral(string) = &expr.expression
+            // span start will mismatch if they are parenthesized when `preserve_parens = false`
+            && expr.span.start == string.span.start
+        {
+            // The directive is the raw text between the quotes, with escapes left as they are.
+            let directive = match string.raw {
+                Some(raw) => {
+                    let raw = raw.as_str();
+                    Atom::from(&raw[1..raw.len() - 1])
+                }
+                None => string.value,
+            };
+            return Some(self.ast.directive(expr.span, (*string).clone(), directive));
+        }
+        None
     }
 
     /// `StatementListItem`[Yield, Await, Return] :
@@ -81,6 +152,12 @@ impl<'a> ParserImpl<'a> {
         &mut self,
         stmt_ctx: StatementContext,
     ) -> Statement<'a> {
+        self.track_recovered_node(|p| {
+            p.with_nesting_guard(|p| p.parse_statement_list_item_guarded(stmt_ctx))
+        })
+    }
+
+    fn parse_statement_list_item_guarded(&mut self, stmt_ctx: StatementContext) -> Statement<'a> {
         let has_no_side_effects_comment =
             self.lexer.trivia_builder.previous_token_has_no_side_effects_comment();
 
@@ -143,6 +220,31 @@ impl<'a> ParserImpl<'a> {
             {
                 self.parse_ts_declaration_statement(self.start_span())
             }
+            Kind::Abstract
+                if !self.is_ts
+                    && self.options.recover_from_errors
+                    && self.lookahead(Self::at_abstract_declaration) =>
+            {
+                self.parse_abstract_declaration_statement()
+            }
+            Kind::PrivateIdentifier
+                if self.class_body_depth == 0
+                    && self.can_insert_placeholder()
+                    && self.lexer.peek_token().kind() != Kind::In =>
+            {
+                self.parse_private_name_statement()
+            }
+            Kind::Case | Kind::Default
+                if self.options.recover_from_errors
+                    && (self.at(Kind::Case) || self.lexer.peek_token().kind() == Kind::Colon) =>
+            {
+                self.parse_orphaned_case_clause()
+            }
+            // `...rest = getThings();`
+            Kind::Dot3 if self.options.recover_from_errors => {
+                self.recover_stray_spread();
+                self.parse_expression_or_labeled_statement()
+            }
             _ => self.parse_expression_or_labeled_statement(),
         };
 
@@ -197,27 +299,168 @@ impl<'a> ParserImpl<'a> {
             // Avoids lookahead for a labeled statement, which is on a hot path
             if self.eat(Kind::Colon) {
                 let label = self.ast.label_identifier(ident.span, ident.name);
-                let body = self.parse_statement_list_item(StatementContext::Label);
+                let body = if self.options.recover_from_errors && self.at_lexical_declaration() {
+                    // `lbl: let x = 1;`, `lbl: using x = f();`, `lbl: class C {}`
+                    self.record_recovery();
+                    self.error(diagnostics::labeled_lexical_declaration(label.span));
+                    self.parse_statement_list_item(StatementContext::StatementList)
+                } else {
+                    self.parse_statement_list_item(StatementContext::Label)
+                };
                 return self.ast.statement_labeled(self.end_span(span), label, body);
             }
         }
         self.parse_expression_statement(span, expr)
     }
 
+    /// Whether the current token starts a `let`, `const`, `using`, `await using` or `class`
+    /// declaration.
+    ///
+    /// `let` followed by a newline and an identifier is an identifier expression,
+    /// e.g. `lbl: let \n x = 1` in sloppy mode.
+    pub(crate) fn at_lexical_declaration(&mut self) -> bool {
+        match self.cur_kind() {
+            Kind::Const | Kind::Class => true,
+            Kind::Using => self.is_using_declaration(),
+            Kind::Await => self.is_using_statement(),
+            Kind::Let if !self.cur_token().escaped() => self.lookahead(|p| {
+                p.bump_any();
+                p.at(Kind::LBrack)
+                    || (!p.cur_token().is_on_new_line()
+                        && (p.at(Kind::LCurly) || p.cur_kind().is_binding_identifier()))
+            }),
+            _ => false,
+        }
+    }
+
     /// Section 14.2 Block Statement
     pub(crate) fn parse_block(&mut self) -> Box<'a, BlockStatement<'a>> {
         let span = self.start_span();
-        let body = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
+        let mut body = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
             p.parse_statement_list_item(StatementContext::StatementList)
         });
+        if !self.options.preserve_empty_statements {
+            body.retain(|stmt| self.is_kept_in_statement_list(stmt));
+        }
         self.ast.alloc_block_statement(self.end_span(span), body)
     }
 
     pub(crate) fn parse_block_statement(&mut self) -> Statement<'a> {
+        // `{ a } = obj;`
+        if self.options.recover_from_errors
+            && let Some(pattern_span) = self.lookahead(Self::block_followed_by_eq)
+        {
+            return self.parse_object_destructuring_statement(pattern_span);
+        }
         let block = self.parse_block();
         Statement::BlockStatement(block)
     }
 
+    /// The span of the block starting at the current `{`, if it is followed by `=`,
+    /// e.g. `{ a }` in `{ a } = obj;`.
+    ///
+    /// Gives up at the first `;` in the block, which cannot appear in an object pattern.
+    fn block_followed_by_eq(&mut self) -> Option<Span> {
+        let span = self.start_span();
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::LCurly | Kind::LBrack | Kind::LParen => depth += 1,
+                Kind::RCurly | Kind::RBrack | Kind::RParen => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        self.bump_any();
+                        return self.at(Kind::Eq).then(|| self.end_span(span));
+                    }
+                }
+                Kind::Semicolon if depth == 1 => return None,
+                Kind::Eof | Kind::Undetermined => return None,
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
+    /// Parse a block followed by `=` as a destructuring assignment, e.g. `{ a } = obj;`,
+    /// which needs parentheses to not be parsed as a block.
+    #[cold]
+    fn parse_object_destructuring_statement(&mut self, pattern_span: Span) -> Statement<'a> {
+        self.record_recovery();
+        self.error(diagnostics::object_destructuring_statement(pattern_span));
+        let span = self.start_span();
+        let expr = self.parse_expr();
+        self.parse_expression_statement(span, expr)
+    }
+
+    /// Whether the current `abstract` is followed by a declaration on the same line,
+    /// e.g. `abstract class C {}` or `abstract const x = 1;`.
+    fn at_abstract_declaration(&mut self) -> bool {
+        self.bump_any();
+        !self.cur_token().is_on_new_line()
+            && (matches!(self.cur_kind(), Kind::Class | Kind::Const | Kind::Var | Kind::Let)
+                || self.at_function_with_async())
+    }
+
+    /// Parse a declaration with the `abstract` modifier in JavaScript,
+    /// reporting the modifier and dropping it from the declaration.
+    #[cold]
+    fn parse_abstract_declaration_statement(&mut self) -> Statement<'a> {
+        let span = self.start_span();
+        self.bump_any(); // bump `abstract`
+        let modifier = Modifier::new(self.end_span(span), ModifierKind::Abstract);
+        self.record_recovery();
+        self.error(diagnostics::modifier_cannot_be_used_here(&modifier, None));
+        let decl = self.parse_declaration(span, &Modifiers::empty(), self.ast.vec());
+        Statement::from(decl)
+    }
+
+    /// Skip a class element outside of a class, e.g. `#field = 1;`,
+    /// and replace it by an expression statement with a placeholder.
+    #[cold]
+    fn parse_private_name_statement(&mut self) -> Statement<'a> {
+        let span = self.start_span();
+        self.record_recovery();
+        self.error(diagnostics::private_name_outside_class(self.cur_token().span()));
+        self.bump_any(); // bump `#name`
+        let mut depth = 0u32;
+        loop {
+            let token = self.cur_token();
+            match token.kind() {
+                Kind::Eof | Kind::Undetermined => break,
+                Kind::RCurly if depth == 0 => break,
+                Kind::Semicolon if depth == 0 => {
+                    self.bump_any();
+                    break;
+                }
+                _ if depth == 0 && token.is_on_new_line() => break,
+                Kind::LCurly => depth += 1,
+                Kind::RCurly => depth -= 1,
+                _ => {}
+            }
+            self.bump_any();
+        }
+        let span = self.end_span(span);
+        self.ast.statement_expression(span, self.placeholder_expression(span))
+    }
+
+    /// Skip the head of a `case` or `default` clause outside of a `switch`, e.g. `case 1:`,
+    /// and replace it by an empty statement. The statements of the clause are parsed as if
+    /// they followed the statement before it.
+    #[cold]
+    fn parse_orphaned_case_clause(&mut self) -> Statement<'a> {
+        let span = self.start_span();
+        self.record_recovery();
+        let token = self.cur_token();
+        self.error(diagnostics::case_clause_outside_switch(token.kind().to_str(), token.span()));
+        if self.eat(Kind::Case) {
+            self.parse_expr();
+        } else {
+            self.bump_any(); // bump `default`
+        }
+        self.expect(Kind::Colon);
+        self.ast.statement_empty(self.end_span(span))
+    }
+
     /// Section 14.3.2 Variable Statement
     pub(crate) fn parse_variable_statement(
         &mut self,
@@ -225,6 +468,9 @@ impl<'a> ParserImpl<'a> {
         kind: VariableDeclarationKind,
         stmt_ctx: StatementContext,
     ) -> Statement<'a> {
+        if kind == VariableDeclarationKind::Var {
+            self.check_var_declaration(Span::sized(start_span, 3), false);
+        }
         let decl = self.parse_variable_declaration(
             start_span,
             kind,
@@ -252,19 +498,55 @@ impl<'a> ParserImpl<'a> {
         span: u32,
         expression: Expression<'a>,
     ) -> Statement<'a> {
+        if self.options.recover_from_errors
+            && matches!(self.cur_kind(), Kind::RParen | Kind::RBrack)
+        {
+            self.recover_stray_closing_delimiter();
+        }
         self.asi();
         self.ast.statement_expression(self.end_span(span), expression)
     }
 
     /// Section 14.6 If Statement
+    ///
+    /// An `else if` chain is parsed in a loop rather than by recursion, so that long chains don't
+    /// overflow the stack. The `if` statements are then built from the last one, and all end
+    /// where the chain ends.
     fn parse_if_statement(&mut self) -> Statement<'a> {
-        let span = self.start_span();
-        self.bump_any(); // bump `if`
-        let test = self.parse_paren_expression();
-        let consequent = self.parse_statement_list_item(StatementContext::If);
-        let alternate =
-            self.eat(Kind::Else).then(|| self.parse_statement_list_item(StatementContext::If));
-        self.ast.statement_if(self.end_span(span), test, consequent, alternate)
+        // (start, test, consequent, whether a recovery action occurred in this `if`)
+        let mut ifs = vec![];
+        let alternate = loop {
+            let start = self.start_span();
+            self.bump_any(); // bump `if`
+            let test = self.parse_paren_expression();
+            let consequent = self.parse_statement_list_item(StatementContext::If);
+            if !self.eat(Kind::Else) {
+                ifs.push((start, test, consequent, self.recovered));
+                break None;
+            }
+            if !self.at(Kind::If) {
+                let alternate = self.parse_statement_list_item(StatementContext::If);
+                ifs.push((start, test, consequent, self.recovered));
+                break Some(alternate);
+            }
+            // The next `if` is tracked as a node of its own, see `track_recovered_node`.
+            ifs.push((start, test, consequent, std::mem::take(&mut self.recovered)));
+        };
+
+        let mut alternate = alternate;
+        while let Some((start, test, consequent, recovered)) = ifs.pop() {
+            let span = self.end_span(start);
+            if ifs.is_empty() {
+                // The outermost `if` is tracked by the caller.
+                self.recovered = recovered;
+                return self.ast.statement_if(span, test, consequent, alternate);
+            }
+            if recovered && self.options.track_recovered_nodes {
+                self.recovered_spans.push(span);
+            }
+            alternate = Some(self.ast.statement_if(span, test, consequent, alternate));
+        }
+        unreachable!()
     }
 
     /// Section 14.7.2 Do-While Statement
@@ -272,6 +554,13 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         self.bump_any(); // advance `do`
         let body = self.parse_statement_list_item(StatementContext::Do);
+        // `do {} foo();`, a placeholder stands in for the missing test
+        if !self.at(Kind::While) && self.fatal_error.is_none() && self.can_insert_placeholder() {
+            self.record_recovery();
+            self.error(diagnostics::do_while_expected(self.cur_token().span()));
+            let test = self.placeholder_expression(Span::empty(self.prev_token_end));
+            return self.ast.statement_do_while(self.end_span(span), body, test);
+        }
         self.expect(Kind::While);
         let test = self.parse_paren_expression();
         self.bump(Kind::Semicolon);
@@ -417,6 +706,12 @@ impl<'a> ParserImpl<'a> {
                 let for_stmt_left = ForStatementLeft::from(target);
                 self.parse_for_of_loop(span, parenthesis_opening_span, r#await, for_stmt_left)
             }
+            _ if self.options.recover_from_errors && !self.at(Kind::Semicolon) => self
+                .recover_malformed_for_loop_head(
+                    span,
+                    parenthesis_opening_span,
+                    ForStatementInit::from(init_expression),
+                ),
             _ => self.parse_for_loop(
                 span,
                 parenthesis_opening_span,
@@ -434,6 +729,9 @@ impl<'a> ParserImpl<'a> {
         decl_kind: VariableDeclarationKind,
         r#await: bool,
     ) -> Statement<'a> {
+        if decl_kind == VariableDeclarationKind::Var {
+            self.check_var_declaration(Span::sized(start_span, 3), false);
+        }
         let init_declaration = self.context_remove(Context::In, |p| {
             p.parse_variable_declaration(
                 start_span,
@@ -496,6 +794,21 @@ impl<'a> ParserImpl<'a> {
                 r#await,
                 ForStatementLeft::VariableDeclaration(init_declaration),
             ),
+            // `for (const item items)`
+            kind if self.options.recover_from_errors
+                && init_declaration.declarations.len() == 1
+                && init_declaration.declarations[0].init.is_none()
+                && (kind.is_identifier_name()
+                    || kind.is_literal()
+                    || matches!(kind, Kind::LBrack | Kind::LCurly | Kind::LParen)) =>
+            {
+                self.recover_missing_for_of_keyword(
+                    span,
+                    parenthesis_opening_span,
+                    r#await,
+                    ForStatementLeft::VariableDeclaration(init_declaration),
+                )
+            }
             _ => self.parse_for_loop(
                 span,
                 parenthesis_opening_span,
@@ -573,6 +886,61 @@ impl<'a> ParserImpl<'a> {
         self.ast.statement_for_of(span, r#await, left, right, body)
     }
 
+    /// Recover from a missing `of` after the declaration in a for-statement head, e.g.
+    /// `for (const item items)`, by assuming `of`, which is the most common intent.
+    #[cold]
+    fn recover_missing_for_of_keyword(
+        &mut self,
+        span: u32,
+        parenthesis_opening_span: Span,
+        r#await: bool,
+        left: ForStatementLeft<'a>,
+    ) -> Statement<'a> {
+        self.record_recovery();
+        self.error(diagnostics::for_loop_head_keyword(self.cur_token().span()));
+        let right = self.parse_assignment_expression_or_higher();
+        self.skip_for_loop_head();
+        self.expect_closing(Kind::RParen, parenthesis_opening_span);
+
+        let body = self.parse_statement_list_item(StatementContext::For);
+        let span = self.end_span(span);
+        self.ast.statement_for_of(span, r#await, left, right, body)
+    }
+
+    /// Recover from a for-statement head which is not followed by `of`, `in` or `;` after
+    /// its initializer, e.g. `for (x y z)`, by skipping the rest of the head.
+    #[cold]
+    fn recover_malformed_for_loop_head(
+        &mut self,
+        span: u32,
+        parenthesis_opening_span: Span,
+        init: ForStatementInit<'a>,
+    ) -> Statement<'a> {
+        self.record_recovery();
+        self.error(diagnostics::for_loop_head_keyword(self.cur_token().span()));
+        self.skip_for_loop_head();
+        self.expect_closing(Kind::RParen, parenthesis_opening_span);
+
+        let body = self.parse_statement_list_item(StatementContext::For);
+        self.ast.statement_for(self.end_span(span), Some(init), None, None, body)
+    }
+
+    /// Skip tokens up to the `)` closing a for-statement head, so nested braces, brackets and
+    /// parentheses are skipped as a whole.
+    fn skip_for_loop_head(&mut self) {
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof | Kind::Undetermined => break,
+                Kind::RParen if depth == 0 => break,
+                Kind::LCurly | Kind::LBrack | Kind::LParen => depth += 1,
+                Kind::RCurly | Kind::RBrack | Kind::RParen => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
     /// Section 14.8 Continue Statement
     fn parse_continue_statement(&mut self) -> Statement<'a> {
         let span = self.start_span();
@@ -603,6 +971,10 @@ impl<'a> ParserImpl<'a> {
         let argument = if self.eat(Kind::Semicolon) || self.can_insert_semicolon() {
             None
         } else {
+            // `return ...values;`
+            if self.options.recover_from_errors && self.at(Kind::Dot3) {
+                self.recover_stray_spread();
+            }
             let expr = self.context_add(Context::In, ParserImpl::parse_expr);
             self.asi();
             Some(expr)
@@ -617,6 +989,12 @@ impl<'a> ParserImpl<'a> {
     fn parse_with_statement(&mut self) -> Statement<'a> {
         let span = self.start_span();
         self.bump_any(); // bump `with`
+        let error = diagnostics::with_statement(Span::sized(span, 4));
+        if self.is_ts || self.options.disallow_legacy_features {
+            self.error(error);
+        } else {
+            self.strict_mode_error(error);
+        }
         let object = self.parse_paren_expression();
         let body = self.parse_statement_list_item(StatementContext::With);
         let span = self.end_span(span);
@@ -628,7 +1006,20 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         self.bump_any(); // advance `switch`
         let discriminant = self.parse_paren_expression();
-        let cases = self.parse_normal_list(Kind::LCurly, Kind::RCurly, Self::parse_switch_case);
+        // Spans of `default` keywords
+        let mut defaults = vec![];
+        let cases = self.parse_normal_list(Kind::LCurly, Kind::RCurly, |p| {
+            if p.at(Kind::Default) {
+                defaults.push(p.cur_token().span());
+            }
+            p.parse_switch_case()
+        });
+        // It is a Syntax Error if `CaseBlock` contains more than one `DefaultClause`.
+        if let [first, duplicates @ ..] = defaults.as_slice()
+            && !duplicates.is_empty()
+        {
+            self.error(diagnostics::multiple_default_clauses(*first, duplicates));
+        }
         self.ast.statement_switch(self.end_span(span), discriminant, cases)
     }
 
@@ -670,7 +1061,9 @@ impl<'a> ParserImpl<'a> {
                     stmt.span(),
                 ));
             }
-            consequent.push(stmt);
+            if self.is_kept_in_statement_list(&stmt) {
+                consequent.push(stmt);
+            }
         }
         self.ast.switch_case(self.end_span(span), test, consequent)
     }
@@ -686,7 +1079,18 @@ impl<'a> ParserImpl<'a> {
                 self.cur_token().span(),
             ));
         }
-        let argument = self.parse_expr();
+        // `throw;`, `throw }`
+        let argument = if matches!(self.cur_kind(), Kind::Semicolon | Kind::RCurly | Kind::Eof) {
+            let error = diagnostics::throw_expression_expected(self.cur_token().span());
+            if !self.can_insert_placeholder() {
+                return self.fatal_error(error);
+            }
+            self.record_recovery();
+            self.error(error);
+            self.placeholder_expression(Span::empty(self.prev_token_end))
+        } else {
+            self.parse_expr()
+        };
         self.asi();
         self.ast.statement_throw(self.end_span(span), argument)
     }
@@ -70,6 +70,9 @@ impl<'a> ParserImpl<'a> {
             }
             (children, Some(closing_element))
         };
+        if self.options.collect_stats {
+            self.shape_counts.jsx_elements += 1;
+        }
         self.ast.alloc_jsx_element(self.end_span(span), opening_element, children, closing_element)
     }
 
@@ -84,6 +87,10 @@ impl<'a> ParserImpl<'a> {
         bool, // `true` if self-closing
     ) {
         let name = self.parse_jsx_element_name();
+        // Reported once for the opening element, not again for the closing element
+        if let JSXElementName::NamespacedName(name) = &name {
+            self.check_jsx_namespaced_name(name.span);
+        }
         // <Component<TsType> for tsx
         let type_arguments = if self.is_ts { self.try_parse_type_arguments() } else { None };
         let attributes = self.parse_jsx_attributes();
@@ -236,6 +243,10 @@ impl<'a> ParserImpl<'a> {
     ///   `JSXFragment`
     ///   { `JSXChildExpression_opt` }
     fn parse_jsx_child(&mut self) -> Option<JSXChild<'a>> {
+        self.with_nesting_guard(Self::parse_jsx_child_guarded)
+    }
+
+    fn parse_jsx_child_guarded(&mut self) -> Option<JSXChild<'a>> {
         match self.cur_kind() {
             Kind::LAngle => {
                 let span = self.start_span();
@@ -378,16 +389,24 @@ impl<'a> ParserImpl<'a> {
 
         if self.eat(Kind::Colon) {
             let property = self.parse_jsx_identifier();
-            return self.ast.jsx_attribute_name_namespaced_name(
-                self.end_span(span),
-                identifier,
-                property,
-            );
+            let span = self.end_span(span);
+            self.check_jsx_namespaced_name(span);
+            return self.ast.jsx_attribute_name_namespaced_name(span, identifier, property);
         }
 
         JSXAttributeName::Identifier(self.alloc(identifier))
     }
 
+    /// Report a namespaced name, e.g. `svg:rect`, unless [`ParseOptions::jsx_namespace_support`]
+    /// is enabled.
+    ///
+    /// [`ParseOptions::jsx_namespace_support`]: crate::ParseOptions::jsx_namespace_support
+    fn check_jsx_namespaced_name(&mut self, span: Span) {
+        if !self.options.jsx_namespace_support {
+            self.error(diagnostics::jsx_namespaced_name(span));
+        }
+    }
+
     fn parse_jsx_attribute_value(&mut self) -> JSXAttributeValue<'a> {
         match self.cur_kind() {
             Kind::Str => {
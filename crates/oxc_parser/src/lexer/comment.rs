@@ -3,7 +3,7 @@ use memchr::memmem::Finder;
 use oxc_ast::CommentKind;
 use oxc_syntax::line_terminator::is_line_terminator;
 
-use crate::diagnostics;
+use crate::{UnterminatedConstructKind, diagnostics};
 
 use super::{
     Kind, Lexer, cold_branch,
@@ -146,7 +146,8 @@ impl<'a> Lexer<'a> {
                 }
             },
             handle_eof: {
-                self.error(diagnostics::unterminated_multi_line_comment(self.unterminated_range()));
+                let span = self.unterminated(UnterminatedConstructKind::Comment);
+                self.error(diagnostics::unterminated_multi_line_comment(span));
                 return Kind::Eof;
             },
         };
@@ -182,7 +183,8 @@ impl<'a> Lexer<'a> {
             Kind::Skip
         } else {
             self.source.advance_to_end();
-            self.error(diagnostics::unterminated_multi_line_comment(self.unterminated_range()));
+            let span = self.unterminated(UnterminatedConstructKind::Comment);
+            self.error(diagnostics::unterminated_multi_line_comment(span));
             Kind::Eof
         }
     }
@@ -193,6 +195,8 @@ impl<'a> Lexer<'a> {
     /// Next 2 bytes must be `#!`.
     pub(super) unsafe fn read_hashbang_comment(&mut self) -> Kind {
         debug_assert!(self.peek_2_bytes() == Some([b'#', b'!']));
+        // Not at offset 0 if a byte order mark was skipped.
+        self.token.set_start(self.offset());
 
         // SAFETY: Caller guarantees next 2 bytes are `#!`
         unsafe {
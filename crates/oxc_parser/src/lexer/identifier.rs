@@ -99,6 +99,7 @@ impl<'a> Lexer<'a> {
     fn identifier_tail_unicode(&mut self, start_pos: SourcePosition<'a>) -> &'a str {
         let c = self.peek_char().unwrap();
         if is_identifier_part_unicode(c) {
+            self.check_ascii_identifier_char(c);
             self.consume_char();
             self.identifier_tail_after_unicode(start_pos)
         } else {
@@ -107,6 +108,20 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    /// Report the non-ASCII identifier character `c` at the current position, with
+    /// [`crate::IdentifierValidation::Ascii`].
+    ///
+    /// Only the first non-ASCII character of an identifier is reported.
+    #[inline]
+    pub(super) fn check_ascii_identifier_char(&mut self, c: char) {
+        if self.ascii_identifiers {
+            let start = self.offset();
+            #[expect(clippy::cast_possible_truncation)]
+            let span = Span::sized(start, c.len_utf8() as u32);
+            self.error(diagnostics::non_ascii_identifier_character(c, span));
+        }
+    }
+
     /// Handle identifier after first char (which was Unicode) is dealt with.
     ///
     /// First char should have been consumed from `self.source` prior to calling this.
@@ -172,6 +187,7 @@ impl<'a> Lexer<'a> {
         mut str: StringBuilder<'a>,
         mut is_start: bool,
     ) -> &'a str {
+        let mut checked_ascii = false;
         'outer: loop {
             // Consume `\`
             self.consume_char();
@@ -184,7 +200,13 @@ impl<'a> Lexer<'a> {
             let chunk_start = self.source.position();
             loop {
                 let maybe_char = self.peek_char();
-                if maybe_char.is_some_and(is_identifier_part) {
+                if let Some(c) = maybe_char
+                    && is_identifier_part(c)
+                {
+                    if !c.is_ascii() && !checked_ascii {
+                        checked_ascii = true;
+                        self.check_ascii_identifier_char(c);
+                    }
                     self.consume_char();
                     continue;
                 }
@@ -281,6 +303,7 @@ impl<'a> Lexer<'a> {
             let c = self.peek_char().unwrap();
             if is_identifier_start_unicode(c) {
                 let start_pos = self.source.position();
+                self.check_ascii_identifier_char(c);
                 self.consume_char();
                 self.identifier_tail_after_unicode(start_pos);
                 return Kind::PrivateIdentifier;
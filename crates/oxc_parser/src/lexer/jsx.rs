@@ -3,7 +3,7 @@ use memchr::memchr;
 use oxc_span::Span;
 use oxc_syntax::identifier::is_identifier_part;
 
-use crate::diagnostics;
+use crate::{UnterminatedConstructKind, diagnostics};
 
 use super::{
     Kind, Lexer, Token, cold_branch,
@@ -48,7 +48,8 @@ impl Lexer<'_> {
             Kind::Str
         } else {
             self.source.advance_to_end();
-            self.error(diagnostics::unterminated_string(self.unterminated_range()));
+            let span = self.unterminated(UnterminatedConstructKind::String);
+            self.error(diagnostics::unterminated_string(span));
             Kind::Eof
         }
     }
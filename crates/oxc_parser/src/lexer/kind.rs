@@ -2,12 +2,18 @@
 
 use std::fmt::{self, Display};
 
-/// Lexer token kind
+/// The kind of a token produced by the lexer.
 ///
-/// Exported for other oxc crates to use. You generally don't need to use this directly.
+/// Keywords and punctuators have one variant each, named after the token they represent.
+/// Identifiers, literals, templates and JSX text are classified by kind only; their value
+/// lives in the source text covered by the token's span.
+///
+/// Use [`Kind::to_str`] (or the [`Display`] impl) to get a human readable name for a kind.
+/// New kinds may be added in minor releases.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 #[repr(u8)]
 #[non_exhaustive]
+#[expect(missing_docs)] // Variants are named after their token, see `Kind::to_str`.
 pub enum Kind {
     #[default]
     Eof = 0,
@@ -207,6 +213,7 @@ pub enum Kind {
 use Kind::*;
 
 impl Kind {
+    /// End of file.
     #[inline]
     pub fn is_eof(self) -> bool {
         self == Eof
@@ -218,6 +225,10 @@ impl Kind {
         matches!(self as u8, x if x >= Decimal as u8 && x <= HexBigInt as u8)
     }
 
+    /// Whether `b` is a valid digit for a `Decimal`, `Binary`, `Octal` or `Hex` numeric kind.
+    ///
+    /// # Panics
+    /// Panics if `self` is any other kind.
     #[inline] // Inline into `read_non_decimal` - see comment there as to why
     pub fn matches_number_byte(self, b: u8) -> bool {
         match self {
@@ -298,6 +309,20 @@ impl Kind {
         matches!(self, Null | True | False | Str | RegExp) || self.is_number()
     }
 
+    /// Tokens which can only close or separate an expression, never start one.
+    /// Used for recovering from a missing expression, e.g. `a + ;`.
+    #[inline]
+    pub fn is_expression_terminator(self) -> bool {
+        matches!(self, RParen | RBrack | RCurly | Semicolon | Comma | Colon)
+    }
+
+    /// `)`, `]` and `}`
+    #[inline]
+    pub fn is_closing_delimiter(self) -> bool {
+        matches!(self, RParen | RBrack | RCurly)
+    }
+
+    /// Whether this token can start the operand of an `await` or `yield` expression.
     #[inline]
     pub fn is_after_await_or_yield(self) -> bool {
         !self.is_binary_operator() && (self.is_literal() || self.is_identifier_name())
@@ -313,11 +338,13 @@ impl Kind {
         self.is_identifier_name() || self == Str || self.is_number()
     }
 
+    /// `LiteralPropertyName` or a private identifier.
     #[inline]
     pub fn is_identifier_or_keyword(self) -> bool {
         self.is_literal_property_name() || self == Self::PrivateIdentifier
     }
 
+    /// `=` and the compound assignment operators, e.g. `+=` or `??=`.
     #[rustfmt::skip]
     #[inline]
     pub fn is_assignment_operator(self) -> bool {
@@ -328,6 +355,7 @@ impl Kind {
         )
     }
 
+    /// Binary operators, excluding the logical operators `||`, `&&` and `??`.
     #[rustfmt::skip]
     #[inline]
     pub fn is_binary_operator(self) -> bool {
@@ -338,16 +366,19 @@ impl Kind {
         )
     }
 
+    /// `||`, `&&` and `??`
     #[inline]
     pub fn is_logical_operator(self) -> bool {
         matches!(self, Pipe2 | Amp2 | Question2)
     }
 
+    /// `-`, `+`, `!`, `~`, `typeof`, `void` and `delete`
     #[inline]
     pub fn is_unary_operator(self) -> bool {
         matches!(self, Minus | Plus | Bang | Tilde | Typeof | Void | Delete)
     }
 
+    /// `++` and `--`
     #[inline]
     pub fn is_update_operator(self) -> bool {
         matches!(self, Plus2 | Minus2)
@@ -360,6 +391,7 @@ impl Kind {
         matches!(self as u8, x if x >= Await as u8 && x <= Null as u8)
     }
 
+    /// [ReservedWord](https://tc39.es/ecma262/#prod-ReservedWord)
     #[rustfmt::skip]
     #[inline]
     pub fn is_reserved_keyword(self) -> bool {
@@ -372,12 +404,15 @@ impl Kind {
         )
     }
 
+    /// Keywords which are only reserved in strict mode code.
     #[rustfmt::skip]
     #[inline]
     pub fn is_strict_mode_contextual_keyword(self) -> bool {
         matches!(self, Let | Static | Implements | Interface | Package | Private | Protected | Public)
     }
 
+    /// Keywords which are valid identifiers outside of the context that gives them meaning,
+    /// e.g. `of`, `async` or the TypeScript type keywords.
     #[rustfmt::skip]
     #[inline]
     pub fn is_contextual_keyword(self) -> bool {
@@ -390,17 +425,20 @@ impl Kind {
         )
     }
 
+    /// Future reserved words in strict mode code.
     #[rustfmt::skip]
     #[inline]
     pub fn is_future_reserved_keyword(self) -> bool {
         matches!(self, Implements | Interface | Package | Private | Protected | Public | Static)
     }
 
+    /// Tokens which start the template of a tagged template, e.g. `` tag`a${b}` ``.
     #[inline]
     pub fn is_template_start_of_tagged_template(self) -> bool {
         matches!(self, NoSubstitutionTemplate | TemplateHead)
     }
 
+    /// TypeScript modifiers, e.g. `public`, `readonly` or `declare`.
     #[rustfmt::skip]
     #[inline]
     pub fn is_modifier_kind(self) -> bool {
@@ -412,11 +450,13 @@ impl Kind {
         )
     }
 
+    /// `BindingIdentifier`, a private identifier or the start of a binding pattern.
     #[inline]
     pub fn is_binding_identifier_or_private_identifier_or_pattern(self) -> bool {
         matches!(self, LCurly | LBrack | PrivateIdentifier) || self.is_binding_identifier()
     }
 
+    /// Keyword kind for `s`, or `Ident` if `s` is not a keyword.
     #[cold]
     pub fn match_keyword(s: &str) -> Self {
         let len = s.len();
@@ -529,6 +569,17 @@ impl Kind {
         }
     }
 
+    /// Human readable name of the token kind, as used in diagnostics.
+    ///
+    /// Keywords and punctuators map to their source text, so for any keyword
+    /// `Kind::match_keyword(kind.to_str()) == kind`.
+    ///
+    /// Every other kind maps to a short, non-empty description of the token rather than
+    /// its text, e.g. `Identifier`, `string`, `float` or `EOF`. These names are for
+    /// display only and are not guaranteed to be unique or to stay the same between
+    /// releases: a `Str` literal is `string` like the `String` keyword, `TemplateTail` is `}`
+    /// like `RCurly`, and all float literal kinds are `float`. Match on the `Kind` itself
+    /// to tell tokens apart.
     pub fn to_str(self) -> &'static str {
         #[expect(clippy::match_same_arms)]
         match self {
@@ -685,7 +736,7 @@ impl Kind {
             Is => "is",
             KeyOf => "keyof",
             Module => "module",
-            Namespace => "namaespace",
+            Namespace => "namespace",
             Never => "never",
             Out => "out",
             Require => "require",
@@ -707,3 +758,50 @@ impl Display for Kind {
         self.to_str().fmt(f)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashMap;
+
+    use super::Kind;
+
+    fn all_kinds() -> impl Iterator<Item = Kind> {
+        // SAFETY: `Kind` is `#[repr(u8)]` with contiguous discriminants from `Eof` (0) to `At`
+        (0..=Kind::At as u8).map(|n| unsafe { std::mem::transmute::<u8, Kind>(n) })
+    }
+
+    #[test]
+    fn names_are_non_empty() {
+        for kind in all_kinds() {
+            assert!(!kind.to_str().trim().is_empty(), "{kind:?} has no name");
+            assert_eq!(kind.to_string(), kind.to_str());
+        }
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let mut kinds_by_name = FxHashMap::<&str, Vec<Kind>>::default();
+        for kind in all_kinds() {
+            kinds_by_name.entry(kind.to_str()).or_default().push(kind);
+        }
+        let mut duplicates =
+            kinds_by_name.into_values().filter(|kinds| kinds.len() > 1).collect::<Vec<_>>();
+        duplicates.sort_by_key(|kinds| kinds[0] as u8);
+        // Only kinds which are indistinguishable to a reader share a name.
+        assert_eq!(
+            duplicates,
+            [
+                vec![Kind::String, Kind::Str],
+                vec![Kind::RCurly, Kind::TemplateTail],
+                vec![Kind::Float, Kind::PositiveExponential, Kind::NegativeExponential],
+            ]
+        );
+    }
+
+    #[test]
+    fn keyword_names_round_trip() {
+        for kind in all_kinds().filter(|kind| kind.is_any_keyword()) {
+            assert_eq!(Kind::match_keyword(kind.to_str()), kind);
+        }
+    }
+}
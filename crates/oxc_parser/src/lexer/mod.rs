@@ -12,7 +12,7 @@ use oxc_ast::ast::RegExpFlags;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{SourceType, Span};
 
-use crate::{UniquePromise, diagnostics};
+use crate::{UniquePromise, UnterminatedConstruct, UnterminatedConstructKind, diagnostics};
 
 mod byte_handlers;
 mod comment;
@@ -45,6 +45,7 @@ pub struct LexerCheckpoint<'a> {
     source_position: SourcePosition<'a>,
     token: Token,
     errors_snapshot: ErrorSnapshot,
+    unterminated_constructs_len: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +74,10 @@ pub struct Lexer<'a> {
 
     pub(crate) errors: Vec<OxcDiagnostic>,
 
+    /// Unterminated strings, templates, regular expressions and comments,
+    /// see [`crate::ParserReturn::unterminated_constructs`]
+    pub(crate) unterminated_constructs: Vec<UnterminatedConstruct>,
+
     context: LexerContext,
 
     pub(crate) trivia_builder: TriviaBuilder,
@@ -84,6 +89,14 @@ pub struct Lexer<'a> {
     /// `None` is saved when the string contains an invalid escape sequence.
     pub escaped_templates: FxHashMap<u32, Option<&'a str>>,
 
+    /// Lex confusable characters such as `−` (U+2212) as their ASCII lookalikes after reporting
+    /// them, see [`crate::ParseOptions::recover_from_errors`]
+    pub(crate) substitute_confusable_characters: bool,
+
+    /// Report non-ASCII characters in identifiers, see
+    /// [`crate::ParseOptions::identifier_validation`]
+    pub(crate) ascii_identifiers: bool,
+
     /// `memchr` Finder for end of multi-line comments. Created lazily when first used.
     multi_line_comment_end_finder: Option<memchr::memmem::Finder<'static>>,
 }
@@ -109,10 +122,13 @@ impl<'a> Lexer<'a> {
             source_type,
             token,
             errors: vec![],
+            unterminated_constructs: vec![],
             context: LexerContext::Regular,
             trivia_builder: TriviaBuilder::default(),
             escaped_strings: FxHashMap::default(),
             escaped_templates: FxHashMap::default(),
+            substitute_confusable_characters: false,
+            ascii_identifiers: false,
             multi_line_comment_end_finder: None,
         }
     }
@@ -153,6 +169,7 @@ impl<'a> Lexer<'a> {
             source_position: self.source.position(),
             token: self.token,
             errors_snapshot,
+            unterminated_constructs_len: self.unterminated_constructs.len(),
         }
     }
 
@@ -168,6 +185,7 @@ impl<'a> Lexer<'a> {
             source_position: self.source.position(),
             token: self.token,
             errors_snapshot,
+            unterminated_constructs_len: self.unterminated_constructs.len(),
         }
     }
 
@@ -178,6 +196,7 @@ impl<'a> Lexer<'a> {
             ErrorSnapshot::Count(len) => self.errors.truncate(len),
             ErrorSnapshot::Full(errors) => self.errors = errors,
         }
+        self.unterminated_constructs.truncate(checkpoint.unterminated_constructs_len);
         self.source.set_position(checkpoint.source_position);
         self.token = checkpoint.token;
     }
@@ -189,6 +208,21 @@ impl<'a> Lexer<'a> {
         token
     }
 
+    /// Skip the first `len` bytes of the source, which are not part of the program.
+    ///
+    /// # Panics
+    /// Panics if the lexer is not at the start of the source, or `len` is past its end or not on
+    /// a UTF-8 character boundary.
+    pub(crate) fn skip_prefix(&mut self, len: usize) {
+        let source_text = self.source.whole();
+        assert!(
+            self.offset() == 0 && len <= source_text.len() && source_text.is_char_boundary(len)
+        );
+        // SAFETY: `len` is within the source, and on a UTF-8 character boundary.
+        let pos = unsafe { self.source.position().add(len) };
+        self.source.set_position(pos);
+    }
+
     /// Set context
     pub fn set_context(&mut self, context: LexerContext) {
         self.context = context;
@@ -255,6 +289,13 @@ impl<'a> Lexer<'a> {
         Span::new(self.token.start(), self.offset())
     }
 
+    /// Record the current unterminated token range as an unterminated construct, and return it
+    fn unterminated(&mut self, kind: UnterminatedConstructKind) -> Span {
+        let span = self.unterminated_range();
+        self.unterminated_constructs.push(UnterminatedConstruct { kind, span });
+        span
+    }
+
     /// Consume the current char if not at EOF
     #[inline]
     fn next_char(&mut self) -> Option<char> {
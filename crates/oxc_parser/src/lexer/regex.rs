@@ -1,6 +1,6 @@
 use oxc_syntax::line_terminator::is_line_terminator;
 
-use crate::diagnostics;
+use crate::{UnterminatedConstructKind, diagnostics};
 
 use super::{Kind, Lexer, RegExpFlags, Token};
 
@@ -32,12 +32,14 @@ impl Lexer<'_> {
         loop {
             match self.next_char() {
                 None => {
-                    self.error(diagnostics::unterminated_reg_exp(self.unterminated_range()));
+                    let span = self.unterminated(UnterminatedConstructKind::RegExp);
+                    self.error(diagnostics::unterminated_reg_exp(span));
                     self.advance_to_end();
                     break;
                 }
                 Some(c) if is_line_terminator(c) => {
-                    self.error(diagnostics::unterminated_reg_exp(self.unterminated_range()));
+                    let span = self.unterminated(UnterminatedConstructKind::RegExp);
+                    self.error(diagnostics::unterminated_reg_exp(span));
                     self.advance_to_end();
                     break;
                 }
@@ -59,30 +61,18 @@ impl Lexer<'_> {
 
         let pattern_end = self.offset() - 1; // -1 to exclude `/`
         let mut flags = RegExpFlags::empty();
-        // To prevent parsing `oxc_regular_expression` with invalid flags in the parser
+        // Invalid flags are reported by the parser, and prevent parsing the pattern with
+        // `oxc_regular_expression`
         let mut flags_error = false;
 
         while let Some(b @ (b'$' | b'_' | b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9')) =
             self.peek_byte()
         {
             self.consume_char();
-            let Ok(flag) = RegExpFlags::try_from(b) else {
-                self.error(diagnostics::reg_exp_flag(
-                    b as char,
-                    self.current_offset().expand_left(1),
-                ));
-                flags_error = true;
-                continue;
-            };
-            if flags.contains(flag) {
-                self.error(diagnostics::reg_exp_flag_twice(
-                    b as char,
-                    self.current_offset().expand_left(1),
-                ));
-                flags_error = true;
-                continue;
+            match RegExpFlags::try_from(b) {
+                Ok(flag) if !flags.contains(flag) => flags |= flag,
+                _ => flags_error = true,
             }
-            flags |= flag;
         }
 
         (pattern_end, flags, flags_error)
@@ -2,7 +2,7 @@ use std::cmp::max;
 
 use oxc_allocator::StringBuilder;
 
-use crate::diagnostics;
+use crate::{UnterminatedConstructKind, diagnostics};
 
 use super::{
     Kind, Lexer, LexerContext, Span, Token, cold_branch,
@@ -67,7 +67,8 @@ macro_rules! handle_string_literal {
             table: $table,
             start: after_opening_quote,
             handle_eof: {
-                $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
+                let span = $lexer.unterminated(UnterminatedConstructKind::String);
+                $lexer.error(diagnostics::unterminated_string(span));
                 return Kind::Undetermined;
             },
         };
@@ -94,7 +95,8 @@ macro_rules! handle_string_literal {
                 cold_branch(|| {
                     debug_assert!(matches!(next_byte, b'\r' | b'\n'));
                     $lexer.consume_char();
-                    $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
+                    let span = $lexer.unterminated(UnterminatedConstructKind::String);
+                    $lexer.error(diagnostics::unterminated_string(span));
                     Kind::Undetermined
                 })
             }
@@ -182,7 +184,8 @@ macro_rules! handle_string_literal_escape {
                         return cold_branch(|| {
                             debug_assert!(matches!(b, b'\r' | b'\n'));
                             $lexer.consume_char();
-                            $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
+                            let span = $lexer.unterminated(UnterminatedConstructKind::String);
+                            $lexer.error(diagnostics::unterminated_string(span));
                             Kind::Undetermined
                         });
                     }
@@ -190,7 +193,8 @@ macro_rules! handle_string_literal_escape {
             }
 
             // EOF
-            $lexer.error(diagnostics::unterminated_string($lexer.unterminated_range()));
+            let span = $lexer.unterminated(UnterminatedConstructKind::String);
+            $lexer.error(diagnostics::unterminated_string(span));
             return Kind::Undetermined;
         }
 
@@ -2,7 +2,7 @@ use std::{cmp::max, str};
 
 use oxc_allocator::StringBuilder;
 
-use crate::diagnostics;
+use crate::{UnterminatedConstructKind, diagnostics};
 
 use super::{
     Kind, Lexer, SourcePosition, Token, cold_branch,
@@ -91,7 +91,8 @@ impl<'a> Lexer<'a> {
                 }
             },
             handle_eof: {
-                self.error(diagnostics::unterminated_string(self.unterminated_range()));
+                let span = self.unterminated(UnterminatedConstructKind::Template);
+                self.error(diagnostics::unterminated_string(span));
                 return Kind::Undetermined;
             },
         };
@@ -122,7 +123,8 @@ impl<'a> Lexer<'a> {
         if pos.is_end_of(&self.source) {
             return cold_branch(|| {
                 self.source.advance_to_end();
-                self.error(diagnostics::unterminated_string(self.unterminated_range()));
+                let span = self.unterminated(UnterminatedConstructKind::Template);
+                self.error(diagnostics::unterminated_string(span));
                 Kind::Undetermined
             });
         }
@@ -375,7 +377,8 @@ impl<'a> Lexer<'a> {
                 }
             },
             handle_eof: {
-                self.error(diagnostics::unterminated_string(self.unterminated_range()));
+                let span = self.unterminated(UnterminatedConstructKind::Template);
+                self.error(diagnostics::unterminated_string(span));
                 return Kind::Undetermined;
             },
         };
@@ -393,6 +396,19 @@ impl<'a> Lexer<'a> {
         self.finish_next(kind)
     }
 
+    /// Re-tokenize the `` ` `` at `start` as a `TemplateTail` closing a template substitution
+    /// which is missing its `}`, e.g. the second `` ` `` in `` `${a.` ``.
+    ///
+    /// The token starts in place of the missing `}`, one byte before the `` ` ``.
+    pub(crate) fn re_lex_template_tail_at(&mut self, start: u32) -> Token {
+        let after_backtick = start + 1;
+        if self.offset() > after_backtick {
+            self.source.back((self.offset() - after_backtick) as usize);
+        }
+        self.token.set_start(start - 1);
+        self.finish_next(Kind::TemplateTail)
+    }
+
     /// Save escaped template string
     fn save_template_string(&mut self, is_valid_escape_sequence: bool, s: &'a str) {
         self.escaped_templates.insert(self.token.start(), is_valid_escape_sequence.then_some(s));
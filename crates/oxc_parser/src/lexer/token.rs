@@ -191,6 +191,19 @@ impl Token {
         self.0 |= u128::from(value) << HAS_SEPARATOR_SHIFT;
     }
 
+    /// Checks if this token is a keyword which can also be an identifier, depending on context.
+    ///
+    /// e.g. `of` in `for (x of y)` is a keyword, but `of` in `let of = 1` is an identifier.
+    /// This includes `yield`, `await` and `let`, which are reserved only in some contexts.
+    /// The parser remaps these tokens to [`Kind::Ident`] when they are used as identifiers.
+    #[inline]
+    pub fn is_keyword_context_sensitive(&self) -> bool {
+        let kind = self.kind();
+        kind.is_contextual_keyword()
+            || kind.is_strict_mode_contextual_keyword()
+            || matches!(kind, Kind::Yield | Kind::Await)
+    }
+
     /// Read `bool` from 8 bits starting at bit position `shift`.
     ///
     /// # SAFETY
@@ -451,4 +464,19 @@ mod test {
         token.set_has_separator(false);
         assert!(!token.has_separator());
     }
+
+    #[test]
+    fn is_keyword_context_sensitive() {
+        let mut token = Token::default();
+        for kind in
+            [Kind::As, Kind::From, Kind::Of, Kind::Yield, Kind::Await, Kind::Let, Kind::Using]
+        {
+            token.set_kind(kind);
+            assert!(token.is_keyword_context_sensitive(), "{kind:?}");
+        }
+        for kind in [Kind::Ident, Kind::Const, Kind::Var, Kind::If, Kind::Eof] {
+            token.set_kind(kind);
+            assert!(!token.is_keyword_context_sensitive(), "{kind:?}");
+        }
+    }
 }
@@ -9,11 +9,31 @@ use oxc_syntax::{
         FF, TAB, VT, is_identifier_part, is_identifier_start, is_identifier_start_unicode,
         is_irregular_whitespace,
     },
-    line_terminator::{CR, LF, LS, PS, is_irregular_line_terminator},
+    line_terminator::{CR, LF, LS, PS, is_irregular_line_terminator, is_line_terminator},
 };
 
 use super::{Kind, Lexer, Span};
 
+/// The ASCII lookalike of a character which is often pasted into source by mistake,
+/// e.g. `−` (U+2212 minus sign) for `-`.
+fn confusable_ascii(c: char) -> Option<char> {
+    let ascii = match c {
+        // Minus sign, en dash, em dash, non-breaking hyphen
+        '\u{2212}' | '\u{2013}' | '\u{2014}' | '\u{2011}' => '-',
+        // Curly quotes
+        '\u{2018}' | '\u{2019}' => '\'',
+        '\u{201C}' | '\u{201D}' => '"',
+        // Fullwidth punctuation
+        '\u{FF08}' => '(',
+        '\u{FF09}' => ')',
+        '\u{FF3B}' => '[',
+        '\u{FF3D}' => ']',
+        '\u{FF1B}' => ';',
+        _ => return None,
+    };
+    Some(ascii)
+}
+
 /// A Unicode escape sequence.
 ///
 /// `\u Hex4Digits`, `\u Hex4Digits \u Hex4Digits`, or `\u{ HexDigits }`.
@@ -35,6 +55,7 @@ impl<'a> Lexer<'a> {
         match c {
             c if is_identifier_start_unicode(c) => {
                 let start_pos = self.source.position();
+                self.check_ascii_identifier_char(c);
                 self.consume_char();
                 self.identifier_tail_after_unicode(start_pos);
                 Kind::Ident
@@ -63,10 +84,49 @@ impl<'a> Lexer<'a> {
     #[cold]
     fn handle_invalid_unicode_char(&mut self, c: char) -> Kind {
         self.consume_char();
+        if let Some(ascii) = confusable_ascii(c) {
+            self.error(diagnostics::confusable_character(c, ascii, self.unterminated_range()));
+            if self.substitute_confusable_characters {
+                return self.read_confusable_substitute(ascii);
+            }
+            return Kind::Undetermined;
+        }
         self.error(diagnostics::invalid_character(c, self.unterminated_range()));
         Kind::Undetermined
     }
 
+    /// Read the token for a confusable character as if it were `ascii`, after consuming it.
+    ///
+    /// A curly quote starts a string, which ends at a closing curly quote or `ascii`.
+    fn read_confusable_substitute(&mut self, ascii: char) -> Kind {
+        let closing_quotes = match ascii {
+            '-' => return Kind::Minus,
+            '(' => return Kind::LParen,
+            ')' => return Kind::RParen,
+            '[' => return Kind::LBrack,
+            ']' => return Kind::RBrack,
+            ';' => return Kind::Semicolon,
+            '"' => ['\u{201C}', '\u{201D}', '"'],
+            _ => ['\u{2018}', '\u{2019}', '\''],
+        };
+        let after_opening_quote = self.source.position();
+        while let Some(c) = self.peek_char() {
+            if closing_quotes.contains(&c) {
+                let value = self.source.str_from_pos_to_current(after_opening_quote);
+                self.consume_char();
+                self.save_string(true, value);
+                return Kind::Str;
+            }
+            if is_line_terminator(c) {
+                break;
+            }
+            self.consume_char();
+        }
+        // Unterminated, so only the quote is invalid.
+        self.source.set_position(after_opening_quote);
+        Kind::Undetermined
+    }
+
     /// Identifier `UnicodeEscapeSequence`
     ///   \u `Hex4Digits`
     ///   \u{ `CodePoint` }
@@ -66,12 +66,16 @@
 
 #![warn(missing_docs)]
 
+mod commonjs;
 mod context;
 mod cursor;
+mod embedded_wrappers;
 mod error_handler;
 mod modifiers;
 mod module_record;
 mod state;
+mod todo_comments;
+mod top_level;
 
 mod js;
 mod jsx;
@@ -79,6 +83,9 @@ mod ts;
 
 mod diagnostics;
 
+#[cfg(feature = "serialize")]
+mod serialize;
+
 // Expose lexer only in benchmarks
 #[cfg(not(feature = "benchmarking"))]
 mod lexer;
@@ -86,17 +93,29 @@ mod lexer;
 #[doc(hidden)]
 pub mod lexer;
 
-use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
+use std::{cell::Cell, fmt::Write, mem, sync::Arc};
+
+use oxc_allocator::{Allocator, Box as ArenaBox, Dummy, Vec as ArenaVec};
 use oxc_ast::{
     AstBuilder,
-    ast::{Expression, Program},
+    ast::{Directive, Expression, Hashbang, Program, Statement},
+};
+use oxc_diagnostics::{GraphicalReportHandler, GraphicalTheme, NamedSource, OxcDiagnostic};
+use oxc_span::{CompactStr, ModuleKind, SourceType, Span};
+use oxc_syntax::{
+    identifier::{ZWNBSP, is_identifier_part, is_white_space_single_line},
+    module_record::ModuleRecord,
 };
-use oxc_diagnostics::OxcDiagnostic;
-use oxc_span::{ModuleKind, SourceType, Span};
-use oxc_syntax::module_record::ModuleRecord;
 
+pub use crate::{
+    commonjs::CommonJsUsage,
+    lexer::Kind,
+    todo_comments::{DEFAULT_TODO_COMMENT_TAGS, TodoComment},
+    top_level::TopLevelIter,
+};
 use crate::{
     context::{Context, StatementContext},
+    embedded_wrappers::find_embedded_wrappers,
     error_handler::FatalError,
     lexer::{Lexer, Token},
     module_record::ModuleRecordBuilder,
@@ -180,12 +199,289 @@ pub struct ParserReturn<'a> {
 
     /// Whether the file is [flow](https://flow.org).
     pub is_flow_language: bool,
+
+    /// Contextual keywords used as keywords, in source order.
+    ///
+    /// Identifiers spelled like a contextual keyword (e.g. `of` in `let of = 1`) are not included.
+    /// Only collected when [`ParseOptions::collect_contextual_keywords`] is enabled.
+    pub contextual_keywords: Vec<(Span, ContextualKeyword)>,
+
+    /// Runs of whitespace between tokens, in source order.
+    ///
+    /// A run ends at a comment, so the runs and [`Program::comments`] together cover all of the
+    /// source between tokens. Whitespace inside tokens, such as in strings or JSX text, is not
+    /// included. Only collected when [`ParseOptions::capture_token_trivia`] is enabled,
+    /// and empty if the parser panicked.
+    ///
+    /// [`Program::comments`]: oxc_ast::ast::Program::comments
+    pub whitespace_runs: Box<[Span]>,
+
+    /// Comment lines starting with a tag such as `TODO`, in source order.
+    ///
+    /// Only collected when [`ParseOptions::collect_todo_comments`] has tags,
+    /// and empty if the parser panicked.
+    pub todo_comments: Box<[TodoComment<'a>]>,
+
+    /// Spans of the statements, class elements and object members which error recovery
+    /// patched up, in source order.
+    ///
+    /// A node is included if a recovery action (a placeholder node, skipped tokens or an assumed
+    /// missing token) occurred inside it, and not inside a nested statement or member, so it is
+    /// included once however many recoveries it contains. Nodes not included were parsed from the
+    /// source as written, but may still have errors which do not need recovery.
+    /// Only collected when [`ParseOptions::track_recovered_nodes`] is enabled.
+    pub recovered_spans: Box<[Span]>,
+
+    /// Bytes used in the [`Allocator`] during this parse, for the AST and the source's strings.
+    ///
+    /// This is the growth of [`Allocator::used_bytes`] over the parse, so when an allocator is
+    /// shared across parses, each parse reports its own usage rather than the total.
+    /// It is approximate, as it includes alignment padding and allocations left unused after
+    /// rewinding. Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub arena_bytes_allocated: usize,
+
+    /// Approximate number of AST nodes allocated during this parse.
+    ///
+    /// Only nodes boxed by the parser itself are counted, so this is a lower bound, which is
+    /// useful for comparing files rather than as an exact count.
+    /// Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub node_count: usize,
+
+    /// Number of JSX elements, not including fragments.
+    ///
+    /// Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub jsx_elements_count: usize,
+
+    /// Number of functions, including methods, accessors and arrow functions.
+    ///
+    /// Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub functions_count: usize,
+
+    /// Number of class declarations and expressions.
+    ///
+    /// Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub classes_count: usize,
+
+    /// Deepest nesting of statements, expressions and types reached while parsing, as counted by
+    /// [`ParseOptions::max_nesting_depth`].
+    ///
+    /// Includes nesting inside source which was parsed speculatively and then parsed again
+    /// differently, e.g. the parameters of what turned out not to be an arrow function.
+    /// Only collected when [`ParseOptions::collect_stats`] is enabled.
+    pub max_nesting_depth_reached: u32,
+
+    /// Spans of the top-level `export default` declaration, if the module has one.
+    ///
+    /// If there are several (which is an error), this is the first.
+    /// `None` if the parser panicked.
+    pub export_default_span: Option<ExportDefaultSpan>,
+
+    /// Constructs which are still open at the end of the file, in source order, e.g. so that an
+    /// editor can show "2 unclosed braces".
+    ///
+    /// Unclosed braces, parentheses and brackets are only included when parsing reached the end
+    /// of the file with them open, so none after a syntax error which stopped parsing early are
+    /// included. Strings and regular expressions which end at a line break are included, as they
+    /// are unterminated too. Included even if the parser panicked.
+    pub unterminated_constructs: Box<[UnterminatedConstruct]>,
+
+    /// Span of the first import, export or `import.meta`, if the program has module syntax.
+    ///
+    /// For [`SourceType::unambiguous`] sources, this is what made the parser classify the program
+    /// as a module, and `None` means it was classified as a script.
+    pub module_syntax_evidence: Option<Span>,
+
+    /// Edits which fix errors that error recovery patched up, in the order of their errors.
+    ///
+    /// A fix is only suggested where applying it gives the source which recovery assumed, such
+    /// as replacing the `;` in `({ a: 1; b: 2 })` with `,`, so that it parses the same without
+    /// errors. Empty if the parser panicked.
+    pub fixes: Box<[SuggestedFix]>,
+
+    /// Whether the source starts with a UTF-8 byte order mark (`U+FEFF`).
+    ///
+    /// The byte order mark is skipped, so the [`Program`] and its first token start after it,
+    /// but spans are still offsets into the source text including it.
+    pub had_bom: bool,
+}
+
+impl ParserReturn<'_> {
+    /// Render [`errors`](ParserReturn::errors) as a report with source snippets, for display.
+    ///
+    /// Each error is printed with its message, the lines of source it covers, and its labels
+    /// underlined, including secondary labels and spans over multiple lines.
+    /// `path` names the source file in the report. Colors and links are not used, so the
+    /// output is stable plain text. Returns an empty string if there are no errors.
+    pub fn render_errors(&self, path: &str) -> String {
+        let handler = GraphicalReportHandler::new()
+            .with_links(false)
+            .with_theme(GraphicalTheme::unicode_nocolor());
+        let source_text: Arc<str> = Arc::from(self.program.source_text);
+        let mut output = String::new();
+        for error in &self.errors {
+            let error =
+                error.clone().with_source_code(NamedSource::new(path, Arc::clone(&source_text)));
+            if handler.render_report(&mut output, error.as_ref()).is_err() {
+                let _ = writeln!(output, "{error}");
+            }
+        }
+        output
+    }
+}
+
+/// Spans of an `export default` declaration, see [`ParserReturn::export_default_span`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportDefaultSpan {
+    /// The `export default` keywords.
+    pub keyword: Span,
+    /// The exported declaration or expression, e.g. `foo()` in `export default foo();`.
+    pub value: Span,
+}
+
+/// An edit which fixes an error, see [`ParserReturn::fixes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedFix {
+    /// Index of the fixed error in [`ParserReturn::errors`].
+    pub error_index: usize,
+    /// The source to replace, empty for an insertion.
+    pub replace_span: Span,
+    /// The text to replace it with, empty for a deletion.
+    pub replacement: CompactStr,
+}
+
+/// A construct which is still open at the end of the file,
+/// see [`ParserReturn::unterminated_constructs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnterminatedConstruct {
+    /// What kind of construct it is.
+    pub kind: UnterminatedConstructKind,
+    /// The opening delimiter for braces, parentheses and brackets, otherwise the construct from
+    /// its start to where it ends unterminated.
+    pub span: Span,
+}
+
+/// See [`UnterminatedConstruct`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnterminatedConstructKind {
+    /// `{`
+    Brace,
+    /// `(`
+    Paren,
+    /// `[`
+    Bracket,
+    /// A string literal or JSX attribute string.
+    String,
+    /// A template literal.
+    Template,
+    /// A regular expression literal.
+    RegExp,
+    /// A multi-line comment.
+    Comment,
+}
+
+/// A keyword which is only reserved in some positions, and is an identifier elsewhere.
+///
+/// See [`ParserReturn::contextual_keywords`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextualKeyword {
+    /// `as` in imports, exports and TypeScript type assertions.
+    As,
+    /// `from` in imports and exports.
+    From,
+    /// `of` in `for...of` statements.
+    Of,
+    /// `yield` in a yield expression.
+    Yield,
+    /// `await` in an await expression or `for await...of` statement.
+    Await,
+    /// `let` in a variable declaration.
+    Let,
+    /// `using` in a using declaration.
+    Using,
+}
+
+impl ContextualKeyword {
+    pub(crate) fn from_kind(kind: Kind) -> Option<Self> {
+        match kind {
+            Kind::As => Some(Self::As),
+            Kind::From => Some(Self::From),
+            Kind::Of => Some(Self::Of),
+            Kind::Yield => Some(Self::Yield),
+            Kind::Await => Some(Self::Await),
+            Kind::Let => Some(Self::Let),
+            Kind::Using => Some(Self::Using),
+            _ => None,
+        }
+    }
+}
+
+/// A subset of expressions accepted by [`Parser::parse_expression`].
+///
+/// See [`ParseOptions::expression_restriction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpressionRestriction {
+    /// [JSON](https://www.json.org): double-quoted strings, decimal numbers with an optional
+    /// leading `-`, `true`, `false`, `null`, arrays and objects with string keys.
+    /// Comments and trailing commas are not allowed.
+    Json,
+    /// [JSON5](https://json5.org): JSON, plus comments, trailing commas, single-quoted strings,
+    /// identifier keys, hexadecimal numbers, numbers with a leading or trailing `.`, a leading
+    /// `+`, and `Infinity` and `NaN`.
+    Json5,
+}
+
+impl ExpressionRestriction {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Json5 => "JSON5",
+        }
+    }
+}
+
+/// How identifier characters are validated, see [`ParseOptions::identifier_validation`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierValidation {
+    /// Identifiers may contain any character with the Unicode `ID_Start` and `ID_Continue`
+    /// properties, as in the specification.
+    #[default]
+    Full,
+    /// Identifiers may only contain ASCII characters. Unicode escapes such as `\u00e9` are
+    /// still allowed, as they are written in ASCII.
+    Ascii,
+}
+
+/// Return value of [`Parser::parse_cjs_detect`].
+#[non_exhaustive]
+pub struct CjsDetectReturn<'a> {
+    /// The result of parsing the file, as returned by [`Parser::parse`].
+    pub parser_return: ParserReturn<'a>,
+
+    /// CommonJS usage found while parsing.
+    ///
+    /// Collected up to the error even if the parser panicked.
+    pub commonjs_usage: CommonJsUsage,
+}
+
+/// Return value of [`Parser::parse_directive_prologue_only`] consisting of the hashbang,
+/// directives and errors.
+#[non_exhaustive]
+pub struct DirectivePrologueReturn<'a> {
+    /// The hashbang, e.g. `#!/usr/bin/env node`.
+    pub hashbang: Option<Hashbang<'a>>,
+
+    /// Directives in the prologue, e.g. `"use client"`.
+    pub directives: ArenaVec<'a, Directive<'a>>,
+
+    /// Syntax errors encountered while parsing the prologue.
+    pub errors: Vec<OxcDiagnostic>,
 }
 
 /// Parse options
 ///
-/// You may provide options to the [`Parser`] using [`Parser::with_options`].
-#[derive(Debug, Clone, Copy)]
+/// You may provide options to the [`Parser`] using [`Parser::with_options`],
+/// or build them with [`ParseOptions::builder`] and use [`Parser::with_options_builder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ParseOptions {
     /// Whether to parse regular expressions or not.
     ///
@@ -197,11 +493,48 @@ pub struct ParseOptions {
     ///
     /// By default, a return statement at the top level raises an error (`false`).
     ///
+    /// `break` and `continue` without an enclosing loop, and `super` outside of methods, are
+    /// not checked by the parser, but by semantic analysis, where they can be allowed with
+    /// [`SemanticBuilder::with_allow_break_outside_loop`],
+    /// [`SemanticBuilder::with_allow_continue_outside_loop`] and
+    /// [`SemanticBuilder::with_allow_super_outside_method`].
+    ///
     /// Default: `false`
     ///
     /// [`return`]: oxc_ast::ast::ReturnStatement
+    /// [`SemanticBuilder::with_allow_break_outside_loop`]: https://docs.rs/oxc_semantic/latest/oxc_semantic/struct.SemanticBuilder.html#method.with_allow_break_outside_loop
+    /// [`SemanticBuilder::with_allow_continue_outside_loop`]: https://docs.rs/oxc_semantic/latest/oxc_semantic/struct.SemanticBuilder.html#method.with_allow_continue_outside_loop
+    /// [`SemanticBuilder::with_allow_super_outside_method`]: https://docs.rs/oxc_semantic/latest/oxc_semantic/struct.SemanticBuilder.html#method.with_allow_super_outside_method
     pub allow_return_outside_function: bool,
 
+    /// Allow top-level [`await`] in scripts, as in modules.
+    ///
+    /// This is useful for REPLs, which evaluate each input as a script, e.g. `await fetch(url)`.
+    /// `await` is then parsed as an [`AwaitExpression`] at the top level, and cannot be used
+    /// as an identifier there, while inside functions which are not `async` it is still an error.
+    ///
+    /// This is independent of [`allow_return_outside_function`]: a REPL wrapping each input in
+    /// an async function body should enable both, so that top-level `return` is also accepted.
+    ///
+    /// Has no effect on modules, which always allow top-level `await`.
+    ///
+    /// Default: `false`
+    ///
+    /// [`await`]: oxc_ast::ast::AwaitExpression
+    /// [`AwaitExpression`]: oxc_ast::ast::AwaitExpression
+    /// [`allow_return_outside_function`]: ParseOptions::allow_return_outside_function
+    pub allow_top_level_await_in_script: bool,
+
+    /// Allow private names outside of class bodies, e.g. `this.#x` and `#x in obj`.
+    ///
+    /// This is useful for tools analyzing code extracted from a class, such as the body of a
+    /// method. Private field accesses and `#x in obj` checks are then parsed without reporting
+    /// that private names are only allowed in class bodies. Class elements outside of a class,
+    /// e.g. `#field = 1;`, are still reported.
+    ///
+    /// Default: `false`
+    pub allow_private_names_outside_class: bool,
+
     /// Emit [`ParenthesizedExpression`]s and [`TSParenthesizedType`] in AST.
     ///
     /// If this option is `true`, parenthesized expressions are represented by
@@ -214,6 +547,17 @@ pub struct ParseOptions {
     /// [`TSParenthesizedType`]: oxc_ast::ast::TSParenthesizedType
     pub preserve_parens: bool,
 
+    /// Emit [`EmptyStatement`]s (`;`) in statement lists.
+    ///
+    /// If this option is `false`, empty statements are omitted from the statements of programs,
+    /// blocks, function bodies, namespaces and `switch` cases. They are kept where a single
+    /// statement is required, e.g. in `if (a);`.
+    ///
+    /// Default: `true`
+    ///
+    /// [`EmptyStatement`]: oxc_ast::ast::EmptyStatement
+    pub preserve_empty_statements: bool,
+
     /// Allow V8 runtime calls in the AST.
     /// See: [V8's Parser::ParseV8Intrinsic](https://chromium.googlesource.com/v8/v8/+/35a14c75e397302655d7b3fbe648f9490ae84b7d/src/parsing/parser.cc#4811).
     ///
@@ -221,6 +565,253 @@ pub struct ParseOptions {
     ///
     /// [`V8IntrinsicExpression`]: oxc_ast::ast::V8IntrinsicExpression
     pub allow_v8_intrinsics: bool,
+
+    /// Allow namespaced JSX names, e.g. `<svg:rect>` and `xlink:href` attributes.
+    ///
+    /// Namespaced names are valid JSX, but are not supported by React.
+    /// When `false`, they are still parsed into [`JSXNamespacedName`]s, and reported as errors.
+    ///
+    /// Default: `true`
+    ///
+    /// [`JSXNamespacedName`]: oxc_ast::ast::JSXNamespacedName
+    pub jsx_namespace_support: bool,
+
+    /// Report legacy syntax which is only allowed in sloppy mode scripts, regardless of the
+    /// source type and strict mode:
+    ///
+    /// * legacy octal literals, e.g. `010`
+    /// * octal escape sequences in strings, e.g. `'\\101'`
+    /// * HTML-like comments, e.g. `<!-- comment` and `--> comment`
+    /// * `with` statements
+    ///
+    /// These are still parsed into the AST, and the errors do not stop parsing.
+    /// Modules do not have HTML-like comments, so `<!--` and `-->` are not reported as comments
+    /// there.
+    ///
+    /// Default: `false`
+    pub disallow_legacy_features: bool,
+
+    /// Report irregular whitespace (e.g. `U+00A0` no-break space or `U+200B` zero-width space)
+    /// which splits a token, pointing at the offending character:
+    ///
+    /// * inside a would-be identifier or number, also when mixed with spaces and tabs,
+    ///   e.g. `foo\u{a0}bar`, `foo \u{a0}bar` or `1\u{a0}000`, which otherwise fails with a
+    ///   generic error about the tokens on either side
+    /// * between `.` and a property name, e.g. `a.\u{a0}b`
+    ///
+    /// Irregular whitespace next to a keyword (`return\u{a0}x`) is not reported. All irregular
+    /// whitespace between tokens is collected into [`ParserReturn::irregular_whitespaces`]
+    /// regardless of this option.
+    ///
+    /// Default: `false`
+    pub report_irregular_whitespace_in_tokens: bool,
+
+    /// Recover from syntax errors which would otherwise stop parsing.
+    ///
+    /// When `false`, the parser stops at the first unrecoverable syntax error and returns an
+    /// empty [`Program`] with [`ParserReturn::panicked`] set.
+    ///
+    /// When `true`, the parser reports these errors in [`ParserReturn::errors`] and keeps going
+    /// where it can, e.g. for invalid assignment targets (`1 = x`, `a + b = c`), missing
+    /// expressions (`a + ;`), and malformed TypeScript overload signatures.
+    ///
+    /// Default: `false`
+    pub recover_from_errors: bool,
+
+    /// Allow error recovery to insert placeholder nodes.
+    ///
+    /// Some recoveries need a node where the source has none (e.g. the operand in `a + ;`),
+    /// or has a node which is not valid in that position (e.g. the assignment target in `1 = x`).
+    /// A placeholder is always an identifier with an empty name, or [`TSUnknownKeyword`] where a
+    /// type is needed ([`TSAnyKeyword`] for a tuple element, as in TypeScript). It spans the
+    /// source it stands in for, or is empty if there is none. Source text can't produce an empty
+    /// identifier or keyword, so placeholders can always be told apart from nodes in the source.
+    ///
+    /// When `false`, these errors stay fatal even if [`recover_from_errors`] is enabled.
+    ///
+    /// Has no effect unless [`recover_from_errors`] is enabled.
+    ///
+    /// Default: `true`
+    ///
+    /// [`TSUnknownKeyword`]: oxc_ast::ast::TSUnknownKeyword
+    /// [`TSAnyKeyword`]: oxc_ast::ast::TSAnyKeyword
+    /// [`recover_from_errors`]: ParseOptions::recover_from_errors
+    pub emit_placeholder_nodes: bool,
+
+    /// Collect the spans of the nodes patched up by error recovery into
+    /// [`ParserReturn::recovered_spans`], e.g. so that a type checker can skip diagnostics in them.
+    ///
+    /// Has no effect unless [`recover_from_errors`] is enabled.
+    ///
+    /// Default: `false`
+    ///
+    /// [`recover_from_errors`]: ParseOptions::recover_from_errors
+    pub track_recovered_nodes: bool,
+
+    /// Report only the "Flow is not supported" error for [Flow](https://flow.org) files.
+    ///
+    /// When a JavaScript file with a `@flow` pragma fails to parse, the syntax errors are most
+    /// likely caused by Flow syntax, so by default (`true`) they are replaced by a single Flow
+    /// error. When `false`, the Flow error is reported alongside the underlying syntax errors.
+    ///
+    /// In both cases, [`ParserReturn::is_flow_language`] is set. Files with a `@flow` pragma
+    /// which parse without errors are not affected.
+    ///
+    /// Default: `true`
+    pub report_flow_errors_only: bool,
+
+    /// Maximum nesting depth of expressions, types, binding patterns, statements and JSX.
+    ///
+    /// The parser is recursive descent, so deeply nested input such as `((((...))))` recurses
+    /// once per level. Exceeding this depth is a fatal error, which guards against stack
+    /// overflow on adversarial input. Each level of nesting in the source may count as more than
+    /// one level, e.g. a nested function counts its body statement and the expression it is in.
+    /// A limit of `256` fits in the 2 MiB stack of a spawned thread, even in debug builds.
+    /// `None` for no limit.
+    ///
+    /// Default: `None`
+    pub max_nesting_depth: Option<u32>,
+
+    /// Maximum number of parameters in a function or arrow function, including a rest parameter.
+    ///
+    /// Exceeding it is a recoverable error reported at the first parameter over the limit, and
+    /// all parameters are still parsed. `None` for no limit.
+    ///
+    /// Default: `None`
+    pub max_parameters: Option<u32>,
+
+    /// Report a warning for each empty element in a sparse array literal, e.g. `[1, , 3]`.
+    ///
+    /// Sparse arrays are valid, but are often caused by a typo. Trailing commas (`[1, 2,]`) and
+    /// empty elements in destructuring assignments (`[, a] = b`) are not reported.
+    ///
+    /// Default: `false`
+    pub report_sparse_array_warnings: bool,
+
+    /// Report a warning for each `using` and `await using` declaration, for tools which target
+    /// syntax levels without explicit resource management.
+    ///
+    /// The declarations are still parsed as usual.
+    ///
+    /// Default: `false`
+    pub report_explicit_resource_management: bool,
+
+    /// Report a warning for each block comment which spans more than
+    /// [`suspicious_comment_max_lines`] lines and contains a line ending in `;` or `{`.
+    ///
+    /// Such a comment has most likely swallowed code, because its `*/` was left out and the
+    /// comment ends at a later `*/` instead.
+    ///
+    /// Default: `false`
+    ///
+    /// [`suspicious_comment_max_lines`]: ParseOptions::suspicious_comment_max_lines
+    pub warn_suspicious_comments: bool,
+
+    /// Number of lines a block comment may span before [`warn_suspicious_comments`] considers it.
+    ///
+    /// Default: `30`
+    ///
+    /// [`warn_suspicious_comments`]: ParseOptions::warn_suspicious_comments
+    pub suspicious_comment_max_lines: u32,
+
+    /// Report warnings for code which does not fit the module system of the [`SourceType`]:
+    ///
+    /// * an ES module `import` in a CommonJS TypeScript file (`.cts`),
+    /// * `export =` in a TypeScript ES module (`.mts`),
+    /// * a call to `require` in an ES module (`.mjs`, `.mts`).
+    ///
+    /// `.js` and `.ts` files parse as ES modules by default, and get the same hints as `.mjs`
+    /// and `.mts` files. The hints never change what parses successfully.
+    ///
+    /// Default: `false`
+    pub module_system_hints: bool,
+
+    /// Report a warning for each `var` declaration, suggesting `let` or `const` instead.
+    ///
+    /// `var` declarations in ambient contexts (`declare var x: T;`) are not reported.
+    ///
+    /// Default: `false`
+    pub warn_var_declarations: bool,
+
+    /// Skip a markdown code fence (```` ```js ````) or `<script>` tag on the first line, and a
+    /// closing fence or `</script>` on the last line, with a warning for each.
+    ///
+    /// This is for source extracted from documentation. Only whole lines at the very start and
+    /// end of the file are skipped, and all spans still refer to the original source text.
+    ///
+    /// Default: `false`
+    pub skip_embedded_wrappers: bool,
+
+    /// Collect the positions of contextual keywords such as `of` and `await` into
+    /// [`ParserReturn::contextual_keywords`].
+    ///
+    /// Default: `false`
+    pub collect_contextual_keywords: bool,
+
+    /// Collect the whitespace between tokens into [`ParserReturn::whitespace_runs`], for
+    /// transforms which preserve the source's formatting.
+    ///
+    /// Default: `false`
+    pub capture_token_trivia: bool,
+
+    /// Report TypeScript errors for declarations in ambient contexts, i.e. in `.d.ts` files and
+    /// inside `declare` declarations.
+    ///
+    /// These are function bodies (`export function f() {}` in a `.d.ts` file) and variable
+    /// initializers (`declare let x = 1`, except literals on `const`s without a type annotation).
+    /// The errors are recoverable, so the full AST is still returned.
+    /// Without this option, only bodies of functions declared with `declare` are reported.
+    ///
+    /// Default: `false`
+    pub validate_ambient_declarations: bool,
+
+    /// Restrict [`Parser::parse_expression`] to a JSON-compatible subset of expressions.
+    ///
+    /// Constructs outside the subset, such as identifiers, function calls and template literals,
+    /// are reported with targeted errors while parsing. [`Parser::parse`] ignores this option.
+    ///
+    /// Default: `None`
+    pub expression_restriction: Option<ExpressionRestriction>,
+
+    /// Report unknown regular expression flags (`/a/x`) as warnings instead of errors.
+    ///
+    /// Unknown and duplicated flags (`/a/gg`) are reported with a diagnostic naming the flag,
+    /// and are left out of [`RegExp::flags`], so the literal keeps only its valid flags.
+    /// Duplicated flags are always errors. Flags are checked with or without the
+    /// `regular_expression` feature.
+    ///
+    /// Default: `false`
+    ///
+    /// [`RegExp::flags`]: oxc_ast::ast::RegExp::flags
+    pub normalize_regex_flags: bool,
+
+    /// How identifier characters are validated.
+    ///
+    /// With [`IdentifierValidation::Ascii`], a non-ASCII character in an identifier, such as `é`
+    /// in `café`, is reported as an error, for tools which know their input is ASCII-only.
+    /// The identifier is still lexed as with [`IdentifierValidation::Full`].
+    ///
+    /// Default: [`IdentifierValidation::Full`]
+    pub identifier_validation: IdentifierValidation,
+
+    /// Tags of the comments to collect into [`ParserReturn::todo_comments`], e.g.
+    /// [`DEFAULT_TODO_COMMENT_TAGS`] for linters which report `TODO` and `FIXME` comments.
+    ///
+    /// A comment line is collected if it starts with one of the tags, which are case-sensitive.
+    /// No comments are collected if this is empty.
+    ///
+    /// Default: `&[]`
+    pub collect_todo_comments: &'static [&'static str],
+
+    /// Collect statistics about the parse, for enforcing memory budgets per file, and about the
+    /// shape of the file, e.g. for bundler heuristics.
+    ///
+    /// See [`ParserReturn::arena_bytes_allocated`], [`ParserReturn::node_count`],
+    /// [`ParserReturn::jsx_elements_count`], [`ParserReturn::functions_count`],
+    /// [`ParserReturn::classes_count`] and [`ParserReturn::max_nesting_depth_reached`].
+    ///
+    /// Default: `false`
+    pub collect_stats: bool,
 }
 
 impl Default for ParseOptions {
@@ -229,93 +820,419 @@ impl Default for ParseOptions {
             #[cfg(feature = "regular_expression")]
             parse_regular_expression: false,
             allow_return_outside_function: false,
+            allow_top_level_await_in_script: false,
+            allow_private_names_outside_class: false,
             preserve_parens: true,
+            preserve_empty_statements: true,
             allow_v8_intrinsics: false,
+            jsx_namespace_support: true,
+            disallow_legacy_features: false,
+            report_irregular_whitespace_in_tokens: false,
+            recover_from_errors: false,
+            emit_placeholder_nodes: true,
+            track_recovered_nodes: false,
+            report_flow_errors_only: true,
+            max_nesting_depth: None,
+            max_parameters: None,
+            report_sparse_array_warnings: false,
+            report_explicit_resource_management: false,
+            warn_suspicious_comments: false,
+            suspicious_comment_max_lines: 30,
+            module_system_hints: false,
+            warn_var_declarations: false,
+            skip_embedded_wrappers: false,
+            collect_contextual_keywords: false,
+            capture_token_trivia: false,
+            validate_ambient_declarations: false,
+            expression_restriction: None,
+            normalize_regex_flags: false,
+            identifier_validation: IdentifierValidation::Full,
+            collect_todo_comments: &[],
+            collect_stats: false,
         }
     }
 }
 
-/// Recursive Descent Parser for ECMAScript and TypeScript
+impl ParseOptions {
+    /// Create a [`ParseOptionsBuilder`], starting from the default options.
+    pub fn builder() -> ParseOptionsBuilder {
+        ParseOptionsBuilder::default()
+    }
+}
+
+/// Builder for [`ParseOptions`].
 ///
-/// See [`Parser::parse`] for entry function.
-pub struct Parser<'a> {
-    allocator: &'a Allocator,
-    source_text: &'a str,
-    source_type: SourceType,
+/// Unlike a struct literal, the builder has the same methods regardless of which cargo features
+/// are enabled. Nonsensical configurations are reported as warnings, which
+/// [`Parser::with_options_builder`] surfaces in [`ParserReturn::errors`].
+#[derive(Debug, Default, Clone)]
+#[must_use]
+pub struct ParseOptionsBuilder {
     options: ParseOptions,
+    diagnostics: Vec<OxcDiagnostic>,
+    /// Whether `emit_placeholder_nodes` was explicitly enabled, as opposed to being the default.
+    requested_placeholder_nodes: bool,
 }
 
-impl<'a> Parser<'a> {
-    /// Create a new [`Parser`]
+impl ParseOptionsBuilder {
+    /// See [`ParseOptions::parse_regular_expression`].
     ///
-    /// # Parameters
-    /// - `allocator`: [Memory arena](oxc_allocator::Allocator) for allocating AST nodes
-    /// - `source_text`: Source code to parse
-    /// - `source_type`: Source type (e.g. JavaScript, TypeScript, JSX, ESM Module, Script)
-    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
-        let options = ParseOptions::default();
-        Self { allocator, source_text, source_type, options }
+    /// Has no effect, and reports a warning if enabled, when the `regular_expression`
+    /// feature is disabled.
+    pub fn parse_regular_expression(mut self, yes: bool) -> Self {
+        #[cfg(feature = "regular_expression")]
+        {
+            self.options.parse_regular_expression = yes;
+        }
+        #[cfg(not(feature = "regular_expression"))]
+        if yes {
+            self.diagnostics.push(diagnostics::regular_expression_feature_disabled());
+        }
+        self
     }
 
-    /// Set parse options
-    #[must_use]
-    pub fn with_options(mut self, options: ParseOptions) -> Self {
-        self.options = options;
+    /// See [`ParseOptions::allow_return_outside_function`].
+    pub fn allow_return_outside_function(mut self, yes: bool) -> Self {
+        self.options.allow_return_outside_function = yes;
         self
     }
-}
 
-mod parser_parse {
-    use super::*;
+    /// See [`ParseOptions::allow_top_level_await_in_script`].
+    pub fn allow_top_level_await_in_script(mut self, yes: bool) -> Self {
+        self.options.allow_top_level_await_in_script = yes;
+        self
+    }
 
-    /// `UniquePromise` is a way to use the type system to enforce the invariant that only
-    /// a single `ParserImpl`, `Lexer` and `lexer::Source` can exist at any time on a thread.
-    /// This constraint is required to guarantee the soundness of some methods of these types
-    /// e.g. `Source::set_position`.
-    ///
-    /// `ParserImpl::new`, `Lexer::new` and `lexer::Source::new` all require a `UniquePromise`
-    /// to be provided to them. `UniquePromise::new` is not visible outside this module, so only
-    /// `Parser::parse` can create one, and it only calls `ParserImpl::new` once.
-    /// This enforces the invariant throughout the entire parser.
-    ///
-    /// `UniquePromise` is a zero-sized type and has no runtime cost. It's purely for the type-checker.
-    ///
-    /// `UniquePromise::new_for_tests_and_benchmarks` is a backdoor for tests/benchmarks, so they can
-    /// create a `ParserImpl` or `Lexer`, and manipulate it directly, for testing/benchmarking purposes.
-    pub struct UniquePromise(());
+    /// See [`ParseOptions::allow_private_names_outside_class`].
+    pub fn allow_private_names_outside_class(mut self, yes: bool) -> Self {
+        self.options.allow_private_names_outside_class = yes;
+        self
+    }
 
-    impl UniquePromise {
-        #[inline]
-        fn new() -> Self {
-            Self(())
-        }
+    /// See [`ParseOptions::preserve_parens`].
+    pub fn preserve_parens(mut self, yes: bool) -> Self {
+        self.options.preserve_parens = yes;
+        self
+    }
 
-        /// Backdoor for tests/benchmarks to create a `UniquePromise` (see above).
-        /// This function must NOT be exposed outside of tests and benchmarks,
-        /// as it allows circumventing safety invariants of the parser.
-        #[cfg(any(test, feature = "benchmarking"))]
-        pub fn new_for_tests_and_benchmarks() -> Self {
-            Self(())
-        }
+    /// See [`ParseOptions::preserve_empty_statements`].
+    pub fn preserve_empty_statements(mut self, yes: bool) -> Self {
+        self.options.preserve_empty_statements = yes;
+        self
     }
 
-    impl<'a> Parser<'a> {
-        /// Main entry point
-        ///
+    /// See [`ParseOptions::allow_v8_intrinsics`].
+    pub fn allow_v8_intrinsics(mut self, yes: bool) -> Self {
+        self.options.allow_v8_intrinsics = yes;
+        self
+    }
+
+    /// See [`ParseOptions::jsx_namespace_support`].
+    pub fn jsx_namespace_support(mut self, yes: bool) -> Self {
+        self.options.jsx_namespace_support = yes;
+        self
+    }
+
+    /// See [`ParseOptions::disallow_legacy_features`].
+    pub fn disallow_legacy_features(mut self, yes: bool) -> Self {
+        self.options.disallow_legacy_features = yes;
+        self
+    }
+
+    /// See [`ParseOptions::report_irregular_whitespace_in_tokens`].
+    pub fn report_irregular_whitespace_in_tokens(mut self, yes: bool) -> Self {
+        self.options.report_irregular_whitespace_in_tokens = yes;
+        self
+    }
+
+    /// See [`ParseOptions::recover_from_errors`].
+    pub fn recover_from_errors(mut self, yes: bool) -> Self {
+        self.options.recover_from_errors = yes;
+        self
+    }
+
+    /// See [`ParseOptions::emit_placeholder_nodes`].
+    pub fn emit_placeholder_nodes(mut self, yes: bool) -> Self {
+        self.options.emit_placeholder_nodes = yes;
+        self.requested_placeholder_nodes = yes;
+        self
+    }
+
+    /// See [`ParseOptions::track_recovered_nodes`].
+    pub fn track_recovered_nodes(mut self, yes: bool) -> Self {
+        self.options.track_recovered_nodes = yes;
+        self
+    }
+
+    /// See [`ParseOptions::report_flow_errors_only`].
+    pub fn report_flow_errors_only(mut self, yes: bool) -> Self {
+        self.options.report_flow_errors_only = yes;
+        self
+    }
+
+    /// See [`ParseOptions::max_nesting_depth`].
+    pub fn max_nesting_depth(mut self, max_nesting_depth: Option<u32>) -> Self {
+        self.options.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// See [`ParseOptions::max_parameters`].
+    pub fn max_parameters(mut self, max_parameters: Option<u32>) -> Self {
+        self.options.max_parameters = max_parameters;
+        self
+    }
+
+    /// See [`ParseOptions::report_sparse_array_warnings`].
+    pub fn report_sparse_array_warnings(mut self, yes: bool) -> Self {
+        self.options.report_sparse_array_warnings = yes;
+        self
+    }
+
+    /// See [`ParseOptions::report_explicit_resource_management`].
+    pub fn report_explicit_resource_management(mut self, yes: bool) -> Self {
+        self.options.report_explicit_resource_management = yes;
+        self
+    }
+
+    /// See [`ParseOptions::warn_suspicious_comments`].
+    pub fn warn_suspicious_comments(mut self, yes: bool) -> Self {
+        self.options.warn_suspicious_comments = yes;
+        self
+    }
+
+    /// See [`ParseOptions::suspicious_comment_max_lines`].
+    pub fn suspicious_comment_max_lines(mut self, lines: u32) -> Self {
+        self.options.suspicious_comment_max_lines = lines;
+        self
+    }
+
+    /// See [`ParseOptions::module_system_hints`].
+    pub fn module_system_hints(mut self, yes: bool) -> Self {
+        self.options.module_system_hints = yes;
+        self
+    }
+
+    /// See [`ParseOptions::warn_var_declarations`].
+    pub fn warn_var_declarations(mut self, yes: bool) -> Self {
+        self.options.warn_var_declarations = yes;
+        self
+    }
+
+    /// See [`ParseOptions::skip_embedded_wrappers`].
+    pub fn skip_embedded_wrappers(mut self, yes: bool) -> Self {
+        self.options.skip_embedded_wrappers = yes;
+        self
+    }
+
+    /// See [`ParseOptions::collect_contextual_keywords`].
+    pub fn collect_contextual_keywords(mut self, yes: bool) -> Self {
+        self.options.collect_contextual_keywords = yes;
+        self
+    }
+
+    /// See [`ParseOptions::capture_token_trivia`].
+    pub fn capture_token_trivia(mut self, yes: bool) -> Self {
+        self.options.capture_token_trivia = yes;
+        self
+    }
+
+    /// See [`ParseOptions::validate_ambient_declarations`].
+    pub fn validate_ambient_declarations(mut self, yes: bool) -> Self {
+        self.options.validate_ambient_declarations = yes;
+        self
+    }
+
+    /// See [`ParseOptions::expression_restriction`].
+    pub fn expression_restriction(mut self, restriction: Option<ExpressionRestriction>) -> Self {
+        self.options.expression_restriction = restriction;
+        self
+    }
+
+    /// See [`ParseOptions::normalize_regex_flags`].
+    pub fn normalize_regex_flags(mut self, yes: bool) -> Self {
+        self.options.normalize_regex_flags = yes;
+        self
+    }
+
+    /// See [`ParseOptions::identifier_validation`].
+    pub fn identifier_validation(mut self, validation: IdentifierValidation) -> Self {
+        self.options.identifier_validation = validation;
+        self
+    }
+
+    /// See [`ParseOptions::collect_todo_comments`].
+    pub fn collect_todo_comments(mut self, tags: &'static [&'static str]) -> Self {
+        self.options.collect_todo_comments = tags;
+        self
+    }
+
+    /// See [`ParseOptions::collect_stats`].
+    pub fn collect_stats(mut self, yes: bool) -> Self {
+        self.options.collect_stats = yes;
+        self
+    }
+
+    /// Build the [`ParseOptions`], along with warnings about the configuration.
+    pub fn build(self) -> (ParseOptions, Vec<OxcDiagnostic>) {
+        let Self { options, mut diagnostics, requested_placeholder_nodes } = self;
+        if requested_placeholder_nodes && !options.recover_from_errors {
+            diagnostics.push(diagnostics::placeholder_nodes_without_recovery());
+        }
+        (options, diagnostics)
+    }
+}
+
+/// Recursive Descent Parser for ECMAScript and TypeScript
+///
+/// See [`Parser::parse`] for entry function.
+pub struct Parser<'a> {
+    ast: AstBuilder<'a>,
+    source_text: &'a str,
+    source_type: SourceType,
+    options: ParseOptions,
+    options_diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Parser<'a> {
+    /// Create a new [`Parser`]
+    ///
+    /// # Parameters
+    /// - `allocator`: [Memory arena](oxc_allocator::Allocator) for allocating AST nodes
+    /// - `source_text`: Source code to parse
+    /// - `source_type`: Source type (e.g. JavaScript, TypeScript, JSX, ESM Module, Script)
+    ///
+    /// To parse several files into one arena, pass the same `allocator` to each parser, and
+    /// optionally share an [`AstBuilder`] between them with [`Parser::with_ast_builder`].
+    /// [`ParseOptions::collect_stats`] reports how much of the arena each parse used.
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, source_type: SourceType) -> Self {
+        let options = ParseOptions::default();
+        let ast = AstBuilder::new(allocator);
+        Self { ast, source_text, source_type, options, options_diagnostics: vec![] }
+    }
+
+    /// Build the AST with `builder`, e.g. to share its atom cache with other parses.
+    ///
+    /// Parsers given copies of a builder created by [`AstBuilder::with_atom_cache`] intern the
+    /// names of identifiers and the values of string literals, template literals and JSX text
+    /// in one cache. The same name in several files then refers to a single string in the arena,
+    /// so equal atoms are pointer-equal across parses, and an escaped string is only kept once.
+    ///
+    /// # Sharing a builder
+    ///
+    /// Interning is safe because parsers never run at the same time. Each entry point consumes
+    /// the [`Parser`] and completes before returning, and the `UniquePromise` invariant
+    /// guarantees only one parser exists on a thread at a time, so the cache is never borrowed
+    /// by two parsers at once. The builder's allocator is not `Sync`, so a builder can't be
+    /// shared between threads either. Interned strings can come from any of the parsed source
+    /// texts, which is why every source text must live as long as the allocator (`'a`).
+    ///
+    /// The cache lives in the arena, so it is freed when the allocator is reset. Create a new
+    /// builder after each reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `builder` allocates in a different allocator than the one passed to
+    /// [`Parser::new`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use oxc_allocator::Allocator;
+    /// use oxc_ast::{AstBuilder, ast::Statement};
+    /// use oxc_parser::Parser;
+    /// use oxc_span::SourceType;
+    ///
+    /// let allocator = Allocator::new();
+    /// let builder = AstBuilder::with_atom_cache(&allocator);
+    /// let first = "let foo;";
+    /// let second = "let f\\u006fo;";
+    /// let name = |src| {
+    ///     let parser = Parser::new(&allocator, src, SourceType::mjs());
+    ///     let ret = parser.with_ast_builder(builder).parse();
+    ///     let Statement::VariableDeclaration(decl) = &ret.program.body[0] else { unreachable!() };
+    ///     decl.declarations[0].id.get_binding_identifier().unwrap().name
+    /// };
+    /// assert!(std::ptr::eq(name(first).as_str(), name(second).as_str()));
+    /// ```
+    #[must_use]
+    pub fn with_ast_builder(mut self, builder: AstBuilder<'a>) -> Self {
+        assert!(
+            std::ptr::eq(builder.allocator, self.ast.allocator),
+            "`AstBuilder` must use the parser's allocator"
+        );
+        self.ast = builder;
+        self
+    }
+
+    /// Set parse options
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set parse options from a [`ParseOptionsBuilder`].
+    ///
+    /// Warnings about the configuration are appended to the errors returned by [`Parser::parse`],
+    /// [`Parser::parse_cjs_detect`], [`Parser::parse_directive_prologue_only`] and
+    /// [`Parser::iter_top_level`].
+    #[must_use]
+    pub fn with_options_builder(mut self, builder: ParseOptionsBuilder) -> Self {
+        let (options, diagnostics) = builder.build();
+        self.options = options;
+        self.options_diagnostics = diagnostics;
+        self
+    }
+}
+
+mod parser_parse {
+    use super::*;
+
+    /// `UniquePromise` is a way to use the type system to enforce the invariant that only
+    /// a single `ParserImpl`, `Lexer` and `lexer::Source` can exist at any time on a thread.
+    /// This constraint is required to guarantee the soundness of some methods of these types
+    /// e.g. `Source::set_position`.
+    ///
+    /// `ParserImpl::new`, `Lexer::new` and `lexer::Source::new` all require a `UniquePromise`
+    /// to be provided to them. `UniquePromise::new` is not visible outside this module, so only
+    /// `Parser::parse` can create one, and it only calls `ParserImpl::new` once.
+    /// This enforces the invariant throughout the entire parser.
+    ///
+    /// `UniquePromise` is a zero-sized type and has no runtime cost. It's purely for the type-checker.
+    ///
+    /// `UniquePromise::new_for_tests_and_benchmarks` is a backdoor for tests/benchmarks, so they can
+    /// create a `ParserImpl` or `Lexer`, and manipulate it directly, for testing/benchmarking purposes.
+    pub struct UniquePromise(());
+
+    impl UniquePromise {
+        #[inline]
+        fn new() -> Self {
+            Self(())
+        }
+
+        /// Backdoor for tests/benchmarks to create a `UniquePromise` (see above).
+        /// This function must NOT be exposed outside of tests and benchmarks,
+        /// as it allows circumventing safety invariants of the parser.
+        #[cfg(any(test, feature = "benchmarking"))]
+        pub fn new_for_tests_and_benchmarks() -> Self {
+            Self(())
+        }
+    }
+
+    impl<'a> Parser<'a> {
+        /// Main entry point
+        ///
         /// Returns an empty `Program` on unrecoverable error,
         /// Recoverable errors are stored inside `errors`.
         ///
         /// See the [module-level documentation](crate) for examples and more information.
         pub fn parse(self) -> ParserReturn<'a> {
             let unique = UniquePromise::new();
-            let parser = ParserImpl::new(
-                self.allocator,
-                self.source_text,
-                self.source_type,
-                self.options,
-                unique,
-            );
-            parser.parse()
+            let parser =
+                ParserImpl::new(self.ast, self.source_text, self.source_type, self.options, unique);
+            let mut ret = parser.parse();
+            ret.errors.extend(self.options_diagnostics);
+            ret
         }
 
         /// Parse a single [`Expression`].
@@ -339,15 +1256,92 @@ mod parser_parse {
         /// If the source code being parsed has syntax errors.
         pub fn parse_expression(self) -> Result<Expression<'a>, Vec<OxcDiagnostic>> {
             let unique = UniquePromise::new();
-            let parser = ParserImpl::new(
-                self.allocator,
-                self.source_text,
-                self.source_type,
-                self.options,
-                unique,
-            );
+            let parser =
+                ParserImpl::new(self.ast, self.source_text, self.source_type, self.options, unique);
             parser.parse_expression()
         }
+
+        /// Parse only the hashbang and directive prologue, e.g. to find `"use client"`.
+        ///
+        /// Stops at the first statement which is not a directive, without parsing the rest
+        /// of the file.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = "'use client';\nexport default function App() {}";
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, src, SourceType::mjs()).parse_directive_prologue_only();
+        /// assert_eq!(ret.directives[0].directive.as_str(), "use client");
+        /// ```
+        pub fn parse_directive_prologue_only(self) -> DirectivePrologueReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser =
+                ParserImpl::new(self.ast, self.source_text, self.source_type, self.options, unique);
+            let mut ret = parser.parse_directive_prologue_only();
+            ret.errors.extend(self.options_diagnostics);
+            ret
+        }
+
+        /// Parse the file, and detect whether it uses CommonJS: `require(...)` calls,
+        /// `module.exports`, `exports.foo`, `__dirname` or `__filename`.
+        ///
+        /// Detection is syntactic and adds no work to [`Parser::parse`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = "const fs = require('fs');\nmodule.exports = fs;";
+        /// let allocator = Allocator::new();
+        /// let ret = Parser::new(&allocator, src, SourceType::cjs()).parse_cjs_detect();
+        /// assert!(ret.commonjs_usage.is_commonjs());
+        /// assert_eq!(ret.commonjs_usage.require.unwrap().source_text(src), "require('fs')");
+        /// ```
+        pub fn parse_cjs_detect(self) -> CjsDetectReturn<'a> {
+            let unique = UniquePromise::new();
+            let parser =
+                ParserImpl::new(self.ast, self.source_text, self.source_type, self.options, unique);
+            let mut ret = parser.parse_cjs_detect();
+            ret.parser_return.errors.extend(self.options_diagnostics);
+            ret
+        }
+
+        /// Parse the top-level statements lazily, one at a time, e.g. to scan a large bundle for
+        /// the first `export default` without parsing the rest of it.
+        ///
+        /// See [`TopLevelIter`].
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use oxc_allocator::Allocator;
+        /// use oxc_ast::ast::Statement;
+        /// use oxc_parser::Parser;
+        /// use oxc_span::SourceType;
+        ///
+        /// let src = "import a from 'a';\nexport default a;\nlet b = 1;";
+        /// let allocator = Allocator::new();
+        /// let mut iter = Parser::new(&allocator, src, SourceType::mjs()).iter_top_level();
+        /// let export_default =
+        ///     iter.find(|stmt| matches!(stmt, Statement::ExportDefaultDeclaration(_)));
+        /// assert!(export_default.is_some());
+        /// let ret = iter.finish();
+        /// assert_eq!(ret.module_record.import_entries.len(), 1);
+        /// ```
+        pub fn iter_top_level(self) -> TopLevelIter<'a> {
+            let unique = UniquePromise::new();
+            let parser =
+                ParserImpl::new(self.ast, self.source_text, self.source_type, self.options, unique);
+            TopLevelIter::new(parser, self.options_diagnostics)
+        }
     }
 }
 use parser_parse::UniquePromise;
@@ -371,6 +1365,10 @@ struct ParserImpl<'a> {
 
     fatal_error: Option<FatalError>,
 
+    /// Errors for strict mode code in an unambiguous source, reported if it turns out to be
+    /// a module, see [`ParserImpl::strict_mode_error`]
+    deferred_strict_mode_errors: Vec<OxcDiagnostic>,
+
     /// The current parsing token
     token: Token,
 
@@ -391,6 +1389,78 @@ struct ParserImpl<'a> {
 
     /// Precomputed typescript detection
     is_ts: bool,
+
+    /// Current depth of recursive productions, see [`ParseOptions::max_nesting_depth`]
+    nesting_depth: u32,
+
+    /// Number of enclosing class bodies, where private names are allowed.
+    class_body_depth: u32,
+
+    /// See [`ParseOptions::collect_contextual_keywords`]
+    contextual_keywords: Vec<(Span, ContextualKeyword)>,
+
+    /// Source between consecutive tokens, including comments, see
+    /// [`ParseOptions::capture_token_trivia`]
+    token_gaps: Vec<Span>,
+
+    /// Whether a recovery action occurred in the innermost statement or member being parsed,
+    /// see [`ParseOptions::track_recovered_nodes`]
+    recovered: bool,
+
+    /// See [`ParseOptions::track_recovered_nodes`]
+    recovered_spans: Vec<Span>,
+
+    /// See [`ParserReturn::fixes`], with indexes into `errors`
+    fixes: Vec<SuggestedFix>,
+
+    /// [`Allocator::used_bytes`] before parsing, see [`ParseOptions::collect_stats`]
+    arena_bytes_before: usize,
+
+    /// See [`ParserReturn::node_count`]
+    node_count: Cell<usize>,
+
+    /// See [`ParseOptions::collect_stats`]
+    shape_counts: ShapeCounts,
+
+    /// See [`ParserReturn::max_nesting_depth_reached`]
+    max_nesting_depth_reached: u32,
+
+    /// See [`ParserReturn::export_default_span`]
+    export_default_span: Option<ExportDefaultSpan>,
+
+    /// Unclosed braces, parentheses and brackets, see [`ParserReturn::unterminated_constructs`]
+    unclosed_delimiters: Vec<UnterminatedConstruct>,
+
+    /// `None` unless detecting CommonJS usage, see [`Parser::parse_cjs_detect`]
+    commonjs_usage: Option<CommonJsUsage>,
+}
+
+/// Number of nodes of some kinds, see [`ParseOptions::collect_stats`].
+///
+/// Part of [`ParserCheckpoint`](cursor::ParserCheckpoint), so that nodes discarded by rewinding
+/// are not counted.
+#[derive(Debug, Default, Clone, Copy)]
+struct ShapeCounts {
+    jsx_elements: usize,
+    functions: usize,
+    classes: usize,
+}
+
+/// Length of the byte order mark at the start of `source_text`, or 0 if it has none.
+fn bom_len(source_text: &str) -> usize {
+    if source_text.starts_with(ZWNBSP) { ZWNBSP.len_utf8() } else { 0 }
+}
+
+/// Append `other` to `errors`, reusing `other` if `errors` is empty.
+fn append_errors(
+    mut errors: Vec<OxcDiagnostic>,
+    mut other: Vec<OxcDiagnostic>,
+) -> Vec<OxcDiagnostic> {
+    if errors.is_empty() {
+        return other;
+    }
+    errors.append(&mut other);
+    errors
 }
 
 impl<'a> ParserImpl<'a> {
@@ -400,26 +1470,61 @@ impl<'a> ParserImpl<'a> {
     /// on a single thread at one time.
     #[inline]
     pub fn new(
-        allocator: &'a Allocator,
+        ast: AstBuilder<'a>,
         source_text: &'a str,
         source_type: SourceType,
         options: ParseOptions,
         unique: UniquePromise,
     ) -> Self {
+        let allocator = ast.allocator;
+        let arena_bytes_before = if options.collect_stats { allocator.used_bytes() } else { 0 };
+        // Overlong source is replaced by the lexer, see `overlong_error`.
+        let (start, end, errors) = if options.skip_embedded_wrappers && source_text.len() <= MAX_LEN
+        {
+            find_embedded_wrappers(source_text)
+        } else {
+            (0, source_text.len(), vec![])
+        };
+        // The byte order mark is not part of the program, see `ParserReturn::had_bom`.
+        let start =
+            if start == 0 && source_text.len() <= MAX_LEN { bom_len(source_text) } else { start };
+        let mut lexer = Lexer::new(allocator, &source_text[..end], source_type, unique);
+        if start > 0 {
+            lexer.skip_prefix(start);
+        }
+        lexer.substitute_confusable_characters = options.recover_from_errors;
+        lexer.ascii_identifiers = options.identifier_validation == IdentifierValidation::Ascii;
         Self {
             options,
-            lexer: Lexer::new(allocator, source_text, source_type, unique),
+            lexer,
             source_type,
             source_text,
-            errors: vec![],
+            errors,
             fatal_error: None,
+            deferred_strict_mode_errors: vec![],
             token: Token::default(),
             prev_token_end: 0,
             state: ParserState::new(),
             ctx: Self::default_context(source_type, options),
-            ast: AstBuilder::new(allocator),
+            ast,
             module_record_builder: ModuleRecordBuilder::new(allocator),
             is_ts: source_type.is_typescript(),
+            nesting_depth: 0,
+            class_body_depth: 0,
+            contextual_keywords: vec![],
+            token_gaps: vec![],
+            recovered: false,
+            recovered_spans: vec![],
+            fixes: vec![],
+            arena_bytes_before,
+            node_count: Cell::new(0),
+            shape_counts: ShapeCounts::default(),
+            max_nesting_depth_reached: 0,
+            export_default_span: None,
+            unclosed_delimiters: vec![],
+            // `require` calls in ES modules are found by CommonJS detection, see `parse_program`.
+            commonjs_usage: (options.module_system_hints && source_type.is_module())
+                .then(CommonJsUsage::default),
         }
     }
 
@@ -428,8 +1533,24 @@ impl<'a> ParserImpl<'a> {
     /// Returns an empty `Program` on unrecoverable error,
     /// Recoverable errors are stored inside `errors`.
     #[inline]
-    pub fn parse(mut self) -> ParserReturn<'a> {
-        let mut program = self.parse_program();
+    pub fn parse(self) -> ParserReturn<'a> {
+        self.parse_with_commonjs_usage().0
+    }
+
+    pub fn parse_cjs_detect(mut self) -> CjsDetectReturn<'a> {
+        self.commonjs_usage = Some(CommonJsUsage::default());
+        let (parser_return, commonjs_usage) = self.parse_with_commonjs_usage();
+        CjsDetectReturn { parser_return, commonjs_usage: commonjs_usage.unwrap_or_default() }
+    }
+
+    #[inline]
+    fn parse_with_commonjs_usage(mut self) -> (ParserReturn<'a>, Option<CommonJsUsage>) {
+        let program = self.parse_program();
+        self.finish(program)
+    }
+
+    /// Handle errors which stopped parsing and build the [`ParserReturn`] for `program`.
+    fn finish(mut self, mut program: Program<'a>) -> (ParserReturn<'a>, Option<CommonJsUsage>) {
         let mut panicked = false;
 
         if let Some(fatal_error) = self.fatal_error.take() {
@@ -444,6 +1565,16 @@ impl<'a> ParserImpl<'a> {
             program = Program::dummy(self.ast.allocator);
             program.source_type = self.source_type;
             program.source_text = self.source_text;
+            self.contextual_keywords.clear();
+            self.token_gaps.clear();
+            self.recovered_spans.clear();
+            self.fixes.clear();
+            self.export_default_span = None;
+        }
+
+        // After the fatal error is handled, as a split token is often what caused it.
+        if self.options.report_irregular_whitespace_in_tokens {
+            self.report_irregular_whitespace_in_tokens();
         }
 
         self.check_unfinished_errors();
@@ -452,30 +1583,76 @@ impl<'a> ParserImpl<'a> {
             panicked = true;
             self.lexer.errors.clear();
             self.errors.clear();
+            self.fixes.clear();
             self.error(overlong_error);
         }
 
-        let mut is_flow_language = false;
-        let mut errors = vec![];
+        let whitespace_runs = if self.options.capture_token_trivia && !panicked {
+            self.whitespace_runs()
+        } else {
+            Box::default()
+        };
+
+        let todo_comments = if self.options.collect_todo_comments.is_empty() {
+            Box::default()
+        } else {
+            let tags = self.options.collect_todo_comments;
+            todo_comments::collect_todo_comments(self.source_text, &program.comments, tags)
+                .into_boxed_slice()
+        };
+
         // only check for `@flow` if the file failed to parse.
-        if (!self.lexer.errors.is_empty() || !self.errors.is_empty())
-            && let Some(error) = self.flow_error()
-        {
-            is_flow_language = true;
-            errors.push(error);
-        }
+        let flow_error = if !self.lexer.errors.is_empty() || !self.errors.is_empty() {
+            self.flow_error()
+        } else {
+            None
+        };
+        let is_flow_language = flow_error.is_some();
+        let module_syntax_evidence = self.module_record_builder.module_syntax_evidence();
         let (module_record, module_record_errors) = self.module_record_builder.build();
-        if errors.len() != 1 {
-            errors.reserve(self.lexer.errors.len() + self.errors.len());
-            errors.extend(self.lexer.errors);
-            errors.extend(self.errors);
-            // Skip checking for exports in TypeScript {
-            if !self.source_type.is_typescript() {
-                errors.extend(module_record_errors);
-            }
+        if module_record.has_module_syntax && !panicked {
+            self.errors.append(&mut self.deferred_strict_mode_errors);
         }
-        let irregular_whitespaces =
-            self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
+        let mut fixes = self.fixes;
+        let errors = match flow_error {
+            Some(error) if self.options.report_flow_errors_only => {
+                fixes.clear();
+                vec![error]
+            }
+            flow_error => {
+                // The parser's errors come after the lexer's and the `@flow` error.
+                let errors_offset = self.lexer.errors.len() + usize::from(flow_error.is_some());
+                for fix in &mut fixes {
+                    fix.error_index += errors_offset;
+                }
+                // Reuse the lexer's or parser's `Vec` rather than copying into a new one, as most
+                // files have errors from one source at most.
+                let mut errors = append_errors(self.lexer.errors, self.errors);
+                // Skip checking for exports in TypeScript {
+                if !self.source_type.is_typescript() {
+                    errors = append_errors(errors, module_record_errors);
+                }
+                if let Some(flow_error) = flow_error {
+                    errors.insert(0, flow_error);
+                }
+                errors
+            }
+        };
+        let irregular_whitespaces =
+            self.lexer.trivia_builder.irregular_whitespaces.into_boxed_slice();
+        let arena_bytes_allocated = if self.options.collect_stats {
+            self.ast.allocator.used_bytes().saturating_sub(self.arena_bytes_before)
+        } else {
+            0
+        };
+
+        // Nodes are recorded when they end, so enclosing nodes come after their children.
+        self.recovered_spans.sort_unstable_by_key(|span| span.start);
+
+        let mut unterminated_constructs = self.lexer.unterminated_constructs;
+        unterminated_constructs.extend(self.unclosed_delimiters);
+        unterminated_constructs.sort_unstable_by_key(|construct| construct.span.start);
+        unterminated_constructs.dedup();
 
         let source_type = program.source_type;
         if source_type.is_unambiguous() {
@@ -486,20 +1663,39 @@ impl<'a> ParserImpl<'a> {
             };
         }
 
-        ParserReturn {
+        let ret = ParserReturn {
             program,
             module_record,
             errors,
             irregular_whitespaces,
             panicked,
             is_flow_language,
-        }
+            contextual_keywords: self.contextual_keywords,
+            whitespace_runs,
+            todo_comments,
+            recovered_spans: self.recovered_spans.into_boxed_slice(),
+            arena_bytes_allocated,
+            node_count: self.node_count.get(),
+            jsx_elements_count: self.shape_counts.jsx_elements,
+            functions_count: self.shape_counts.functions,
+            classes_count: self.shape_counts.classes,
+            max_nesting_depth_reached: self.max_nesting_depth_reached,
+            export_default_span: self.export_default_span,
+            unterminated_constructs: unterminated_constructs.into_boxed_slice(),
+            module_syntax_evidence,
+            fixes: fixes.into_boxed_slice(),
+            had_bom: bom_len(self.source_text) > 0,
+        };
+        (ret, self.commonjs_usage)
     }
 
     pub fn parse_expression(mut self) -> Result<Expression<'a>, Vec<OxcDiagnostic>> {
         // initialize cur_token and prev_token by moving onto the first token
         self.bump_any();
-        let expr = self.parse_expr();
+        let expr = match self.options.expression_restriction {
+            Some(restriction) => self.parse_json_text(restriction),
+            None => self.parse_expr(),
+        };
         if let Some(FatalError { error, .. }) = self.fatal_error.take() {
             return Err(vec![error]);
         }
@@ -511,7 +1707,28 @@ impl<'a> ParserImpl<'a> {
         Ok(expr)
     }
 
-    #[expect(clippy::cast_possible_truncation)]
+    pub fn parse_directive_prologue_only(mut self) -> DirectivePrologueReturn<'a> {
+        // Initialize by moving onto the first token.
+        // Checks for hashbang comment.
+        self.token = self.lexer.first_token();
+
+        let hashbang = self.parse_hashbang();
+        let directives = self.parse_directive_prologue();
+
+        if let Some(fatal_error) = self.fatal_error.take() {
+            self.errors.truncate(fatal_error.errors_len);
+            self.error(fatal_error.error);
+        }
+        if let Some(overlong_error) = self.overlong_error() {
+            self.lexer.errors.clear();
+            self.errors.clear();
+            self.error(overlong_error);
+        }
+        let errors = self.lexer.errors.into_iter().chain(self.errors).collect::<Vec<_>>();
+
+        DirectivePrologueReturn { hashbang, directives, errors }
+    }
+
     fn parse_program(&mut self) -> Program<'a> {
         // Initialize by moving onto the first token.
         // Checks for hashbang comment.
@@ -520,8 +1737,32 @@ impl<'a> ParserImpl<'a> {
         let hashbang = self.parse_hashbang();
         let (directives, statements) =
             self.parse_directives_and_statements(/* is_top_level */ true);
+        self.finish_program(hashbang, directives, statements)
+    }
+
+    /// Run the checks over the whole source once the statements are parsed, and build the
+    /// [`Program`].
+    #[expect(clippy::cast_possible_truncation)]
+    fn finish_program(
+        &mut self,
+        hashbang: Option<Hashbang<'a>>,
+        directives: ArenaVec<'a, Directive<'a>>,
+        statements: ArenaVec<'a, Statement<'a>>,
+    ) -> Program<'a> {
+        if self.options.disallow_legacy_features {
+            self.report_html_comments();
+        }
+        if self.options.warn_suspicious_comments {
+            self.report_suspicious_comments();
+        }
+        if let Some(span) = self.commonjs_usage.and_then(|usage| usage.require)
+            && self.options.module_system_hints
+            && self.source_type.is_module()
+        {
+            self.error(diagnostics::require_in_module(span));
+        }
 
-        let span = Span::new(0, self.source_text.len() as u32);
+        let span = Span::new(bom_len(self.source_text) as u32, self.source_text.len() as u32);
         let comments = self.ast.vec_from_iter(self.lexer.trivia_builder.comments.iter().copied());
         self.ast.program(
             span,
@@ -534,12 +1775,132 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
+    /// Split the gaps between tokens around comments, see [`ParseOptions::capture_token_trivia`].
+    #[cold]
+    fn whitespace_runs(&mut self) -> Box<[Span]> {
+        let mut gaps = mem::take(&mut self.token_gaps);
+        // Whitespace at the end of the file, before `Kind::Eof`.
+        gaps.push(Span::new(self.prev_token_end, self.cur_token().start()));
+
+        let comments = &self.lexer.trivia_builder.comments;
+        let mut runs = Vec::with_capacity(gaps.len());
+        for gap in gaps {
+            let mut start = gap.start;
+            let first = comments.partition_point(|comment| comment.span.end <= gap.start);
+            for comment in comments[first..].iter().take_while(|c| c.span.start < gap.end) {
+                if start < comment.span.start {
+                    runs.push(Span::new(start, comment.span.start));
+                }
+                start = comment.span.end;
+            }
+            if start < gap.end {
+                runs.push(Span::new(start, gap.end));
+            }
+        }
+        runs.into_boxed_slice()
+    }
+
+    /// Report the `<!--` and `-->` starting HTML-like comments, which are only lexed as comments
+    /// in scripts.
+    #[cold]
+    fn report_html_comments(&mut self) {
+        let spans = self
+            .lexer
+            .trivia_builder
+            .comments
+            .iter()
+            .filter(|comment| comment.is_line())
+            .filter_map(|comment| {
+                let text = comment.span.source_text(self.source_text);
+                let len = if text.starts_with("<!--") {
+                    4
+                } else if text.starts_with("-->") {
+                    3
+                } else {
+                    return None;
+                };
+                Some(Span::sized(comment.span.start, len))
+            })
+            .collect::<Vec<_>>();
+        for span in spans {
+            self.error(diagnostics::html_comment(span));
+        }
+    }
+
+    /// Report long block comments which look like they contain code, see
+    /// [`ParseOptions::warn_suspicious_comments`].
+    #[cold]
+    fn report_suspicious_comments(&mut self) {
+        let max_lines = self.options.suspicious_comment_max_lines as usize;
+        let warnings = self
+            .lexer
+            .trivia_builder
+            .comments
+            .iter()
+            .filter(|comment| comment.is_block())
+            .filter_map(|comment| {
+                let text = comment.content_span().source_text(self.source_text);
+                let lines = text.lines().count();
+                let has_code = text.lines().any(|line| line.trim_end().ends_with([';', '{']));
+                (lines > max_lines && has_code)
+                    .then(|| diagnostics::suspicious_comment(lines, comment.span))
+            })
+            .collect::<Vec<_>>();
+        for warning in warnings {
+            self.error(warning);
+        }
+    }
+
+    /// Report irregular whitespace between two words which are not keywords, or between `.` and
+    /// a property name, with only whitespace on the same line around it, see [`ParseOptions::report_irregular_whitespace_in_tokens`].
+    #[cold]
+    fn report_irregular_whitespace_in_tokens(&mut self) {
+        // Whitespace is collected again when the lexer rewinds.
+        let mut spans = self.lexer.trivia_builder.irregular_whitespaces.clone();
+        spans.sort_unstable_by_key(|span| span.start);
+        spans.dedup();
+
+        let source_text = self.source_text;
+        for span in spans {
+            // Words on either side of the whitespace around `span`, e.g. `foo` and `bar` in
+            // `foo \u{a0}bar`.
+            let before =
+                source_text[..span.start as usize].trim_end_matches(is_white_space_single_line);
+            let after =
+                source_text[span.end as usize..].trim_start_matches(is_white_space_single_line);
+            let word_before = &before[before.trim_end_matches(is_identifier_part).len()..];
+            let word_after =
+                &after[..after.len() - after.trim_start_matches(is_identifier_part).len()];
+            if word_after.is_empty() {
+                continue;
+            }
+            if word_before.is_empty() {
+                if before.ends_with('.')
+                    && !before.ends_with("..")
+                    && !word_after.starts_with(|c: char| c.is_ascii_digit())
+                {
+                    self.error(diagnostics::irregular_whitespace_after_dot(span));
+                }
+            } else if !Kind::match_keyword(word_before).is_any_keyword()
+                && !Kind::match_keyword(word_after).is_any_keyword()
+            {
+                self.error(diagnostics::irregular_whitespace_in_token(span));
+            }
+        }
+    }
+
     fn default_context(source_type: SourceType, options: ParseOptions) -> Context {
         let mut ctx = Context::default().and_ambient(source_type.is_typescript_definition());
-        if source_type.module_kind() == ModuleKind::Module {
+        if source_type.module_kind() == ModuleKind::Module
+            || (source_type.module_kind() == ModuleKind::Script
+                && options.allow_top_level_await_in_script)
+        {
             // for [top-level-await](https://tc39.es/proposal-top-level-await/)
             ctx = ctx.and_await(true);
         }
+        if source_type.is_module() {
+            ctx = ctx.and_strict_mode(true);
+        }
         if options.allow_return_outside_function {
             ctx = ctx.and_return(true);
         }
@@ -548,13 +1909,12 @@ impl<'a> ParserImpl<'a> {
 
     /// Check for Flow declaration if the file cannot be parsed.
     /// The declaration must be [on the first line before any code](https://flow.org/en/docs/usage/#toc-prepare-your-code-for-flow)
-    fn flow_error(&mut self) -> Option<OxcDiagnostic> {
+    fn flow_error(&self) -> Option<OxcDiagnostic> {
         if !self.source_type.is_javascript() {
             return None;
         }
         let span = self.lexer.trivia_builder.comments.first()?.span;
         if span.source_text(self.source_text).contains("@flow") {
-            self.errors.clear();
             Some(diagnostics::flow(span))
         } else {
             None
@@ -568,6 +1928,17 @@ impl<'a> ParserImpl<'a> {
         for expr in self.state.cover_initialized_name.values() {
             self.errors.push(diagnostics::cover_initialized_name(expr.span()));
         }
+        for &(prev_span, span) in self.state.duplicate_proto.values().flatten() {
+            self.errors.push(diagnostics::redeclaration("__proto__", prev_span, span));
+        }
+        let mut elisions =
+            self.state.sparse_array_elisions.values().flatten().copied().collect::<Vec<_>>();
+        // Arrays may be parsed more than once when rewinding, e.g. for arrow functions.
+        elisions.sort_unstable_by_key(|span| span.start);
+        elisions.dedup();
+        for span in elisions {
+            self.errors.push(diagnostics::sparse_array_element(span));
+        }
     }
 
     /// Check if source length exceeds MAX_LEN, if the file cannot be parsed.
@@ -582,6 +1953,9 @@ impl<'a> ParserImpl<'a> {
 
     #[inline]
     fn alloc<T>(&self, value: T) -> ArenaBox<'a, T> {
+        if self.options.collect_stats {
+            self.node_count.set(self.node_count.get() + 1);
+        }
         self.ast.alloc(value)
     }
 }
@@ -590,7 +1964,10 @@ impl<'a> ParserImpl<'a> {
 mod test {
     use std::path::Path;
 
-    use oxc_ast::ast::{CommentKind, Expression, Statement};
+    use oxc_ast::ast::{
+        CommentKind, Declaration, Expression, JSXElementName, RegExpFlags, Statement,
+        TSModuleDeclarationBody,
+    };
     use oxc_span::GetSpan;
 
     use super::*;
@@ -637,6 +2014,33 @@ mod test {
         }
     }
 
+    #[test]
+    fn flow_errors_with_underlying_diagnostics() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "// @flow\nfunction foo(x: number): string {}";
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.is_flow_language);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Flow is not supported");
+
+        let opts = ParseOptions { report_flow_errors_only: false, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.is_flow_language);
+        assert!(ret.errors.len() > 1);
+        assert_eq!(ret.errors[0].to_string(), "Flow is not supported");
+
+        // `@flow` files without Flow-specific syntax are not Flow errors.
+        let source = "// @flow\nfunction foo(x) {}";
+        for report_flow_errors_only in [true, false] {
+            let opts = ParseOptions { report_flow_errors_only, ..ParseOptions::default() };
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.is_flow_language);
+            assert!(ret.errors.is_empty());
+        }
+    }
+
     #[test]
     fn ts_module_declaration() {
         let allocator = Allocator::default();
@@ -662,6 +2066,212 @@ mod test {
         }
     }
 
+    #[test]
+    fn directive_raw_text() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let sources = [
+            (r#""use \"strict\""; 'don\'t';"#, vec![r#"use \"strict\""#, r"don\'t"], 0),
+            (r"'\''; '\\';", vec![r"\'", r"\\"], 0),
+            ("'a'\n\"b\"\nfoo(); 'c';", vec!["a", "b"], 2),
+            ("('use strict'); 'a';", vec![], 2),
+            ("`use strict`; 'a';", vec![], 2),
+            ("`use ${strict}`; 'a';", vec![], 2),
+        ];
+        for (source, expected, body_length) in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            let directives =
+                ret.program.directives.iter().map(|d| d.directive.as_str()).collect::<Vec<_>>();
+            assert_eq!(directives, expected, "{source}");
+            assert_eq!(ret.program.body.len(), body_length, "{source}");
+        }
+
+        let source = r#"function f() { 'use strict'; "x\"y"; g(); 'z'; }"#;
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        let Statement::FunctionDeclaration(func) = &ret.program.body[0] else {
+            panic!("Expected FunctionDeclaration");
+        };
+        let body = func.body.as_ref().unwrap();
+        let directives = body.directives.iter().map(|d| d.directive.as_str()).collect::<Vec<_>>();
+        assert_eq!(directives, ["use strict", r#"x\"y"#]);
+        assert_eq!(body.statements.len(), 2);
+
+        // An escaped "use strict" is not a "use strict" directive.
+        let source = r"'use\x20strict'; with (a) {}";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+        assert!(ret.errors.is_empty());
+        let ret = Parser::new(&allocator, "'use strict'; with (a) {}", SourceType::cjs()).parse();
+        assert_eq!(ret.errors.len(), 1);
+    }
+
+    #[test]
+    fn directive_prologue_only() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = [
+            ("'use client';\nfoo();", vec!["use client"]),
+            (
+                "#!/usr/bin/env node\n'use strict'; 'use server'\nbar",
+                vec!["use strict", "use server"],
+            ),
+            ("'use strict' + 1; 'use client';", vec![]),
+            ("('use strict'); 'use client';", vec![]),
+            ("foo(); 'use client';", vec![]),
+            ("'use client'; this is not parsed", vec!["use client"]),
+        ];
+        for (source, expected) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse_directive_prologue_only();
+            assert!(ret.errors.is_empty(), "{source}");
+            let directives =
+                ret.directives.iter().map(|d| d.directive.as_str()).collect::<Vec<_>>();
+            assert_eq!(directives, expected, "{source}");
+        }
+
+        let opts = ParseOptions { preserve_parens: false, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, "('use strict');", source_type)
+            .with_options(opts)
+            .parse_directive_prologue_only();
+        assert!(ret.directives.is_empty());
+
+        let ret = Parser::new(&allocator, "#!/usr/bin/env node\n'use strict'", source_type)
+            .parse_directive_prologue_only();
+        assert_eq!(ret.hashbang.unwrap().value.as_str(), "/usr/bin/env node");
+
+        let ret = Parser::new(&allocator, "'use strict' 'oops'", source_type)
+            .parse_directive_prologue_only();
+        assert_eq!(ret.errors.len(), 1);
+
+        let builder = ParseOptions::builder().emit_placeholder_nodes(true);
+        let ret = Parser::new(&allocator, "'use strict';", source_type)
+            .with_options_builder(builder)
+            .parse_directive_prologue_only();
+        assert_eq!(ret.directives.len(), 1);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].severity, oxc_diagnostics::Severity::Warning);
+    }
+
+    #[test]
+    fn iter_top_level() {
+        let allocator = Allocator::default();
+        let source =
+            (0..10).map(|i| format!("import a{i} from 'm{i}';")).collect::<Vec<_>>().join("\n");
+        let mut iter = Parser::new(&allocator, &source, SourceType::mjs()).iter_top_level();
+        let statements = iter.by_ref().take(3).collect::<Vec<_>>();
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[2].span().source_text(&source), "import a2 from 'm2';");
+        assert_eq!(iter.errors().count(), 0);
+        let ret = iter.finish();
+        assert!(ret.errors.is_empty());
+        assert!(ret.program.body.is_empty());
+        assert_eq!(ret.module_record.import_entries.len(), 3);
+        assert_eq!(ret.module_record.requested_modules.len(), 3);
+
+        // Directives are not yielded, and errors are available while iterating.
+        let source = "'use strict';\nlet a = 1;\nlet b c;\nlet d = 3;\n/* end */";
+        let mut iter = Parser::new(&allocator, source, SourceType::cjs()).iter_top_level();
+        assert_eq!(iter.next().unwrap().span().source_text(source), "let a = 1;");
+        assert!(iter.next().is_none());
+        assert_eq!(iter.errors().count(), 1);
+        let ret = iter.finish();
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+
+        let mut iter = Parser::new(&allocator, source, SourceType::mjs()).iter_top_level();
+        assert!(iter.next().is_some());
+        drop(iter);
+
+        let source = "'use strict';\nlet a = 1;\nlet b = 3;\n/* end */";
+        let mut iter = Parser::new(&allocator, source, SourceType::cjs()).iter_top_level();
+        assert_eq!(iter.by_ref().count(), 2);
+        let ret = iter.finish();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.directives.len(), 1);
+        assert_eq!(ret.program.comments.len(), 1);
+    }
+
+    #[test]
+    fn with_ast_builder() {
+        fn atoms<'a>(ret: &ParserReturn<'a>) -> (&'a str, &'a str) {
+            let Statement::VariableDeclaration(decl) = &ret.program.body[0] else {
+                panic!("Expected VariableDeclaration");
+            };
+            let declarator = &decl.declarations[0];
+            let name = declarator.id.get_binding_identifier().unwrap().name;
+            let Some(Expression::StringLiteral(value)) = &declarator.init else {
+                panic!("Expected StringLiteral");
+            };
+            (name.as_str(), value.value.as_str())
+        }
+
+        let sources = ["let foo = 'a\\nb';", "let f\\u006fo = 'a\\nb';"];
+        let source_type = SourceType::mjs();
+
+        // Parses sharing a builder get the same strings for equal names and values.
+        let shared = Allocator::default();
+        let builder = AstBuilder::with_atom_cache(&shared);
+        let [first, second] = sources.map(|source| {
+            let ret = Parser::new(&shared, source, source_type).with_ast_builder(builder).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            atoms(&ret)
+        });
+        assert_eq!(first, ("foo", "a\nb"));
+        assert!(std::ptr::eq(first.0, second.0));
+        assert!(std::ptr::eq(first.1, second.1));
+
+        // Independent builders each keep their own strings.
+        let independent = Allocator::default();
+        let [first, second] = sources.map(|source| {
+            let builder = AstBuilder::with_atom_cache(&independent);
+            let parser = Parser::new(&independent, source, source_type);
+            atoms(&parser.with_ast_builder(builder).parse())
+        });
+        assert_eq!(first, second);
+        assert!(!std::ptr::eq(first.0, second.0));
+        assert!(!std::ptr::eq(first.1, second.1));
+        assert!(shared.used_bytes() < independent.used_bytes());
+    }
+
+    #[test]
+    #[should_panic(expected = "`AstBuilder` must use the parser's allocator")]
+    fn with_ast_builder_other_allocator() {
+        let allocator = Allocator::default();
+        let other = Allocator::default();
+        let builder = AstBuilder::with_atom_cache(&other);
+        let _ = Parser::new(&allocator, "", SourceType::mjs()).with_ast_builder(builder);
+    }
+
+    #[test]
+    fn preserve_empty_statements() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let source =
+            ";\n{ ; }\nfunction f() { ; }\nswitch (a) { case 1: ; }\nnamespace N { ; }\nif (a);";
+        let opts = ParseOptions { preserve_empty_statements: false, ..ParseOptions::default() };
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let body = &ret.program.body;
+        assert_eq!(body.len(), 5);
+        let Statement::BlockStatement(block) = &body[0] else { panic!() };
+        assert!(block.body.is_empty());
+        let Statement::FunctionDeclaration(func) = &body[1] else { panic!() };
+        assert!(func.body.as_ref().unwrap().statements.is_empty());
+        let Statement::SwitchStatement(switch) = &body[2] else { panic!() };
+        assert!(switch.cases[0].consequent.is_empty());
+        let Statement::TSModuleDeclaration(module) = &body[3] else { panic!() };
+        let Some(TSModuleDeclarationBody::TSModuleBlock(module_block)) = &module.body else {
+            panic!()
+        };
+        assert!(module_block.body.is_empty());
+        let Statement::IfStatement(if_stmt) = &body[4] else { panic!() };
+        assert!(matches!(if_stmt.consequent, Statement::EmptyStatement(_)));
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.program.body.len(), 6);
+        assert!(matches!(ret.program.body[0], Statement::EmptyStatement(_)));
+    }
+
     #[test]
     fn v8_intrinsics() {
         let allocator = Allocator::default();
@@ -691,6 +2301,45 @@ mod test {
                 ret.errors[0].to_string(),
                 "V8 runtime calls cannot have spread elements as arguments"
             );
+            let Some(Statement::ExpressionStatement(expr_stmt)) = ret.program.body.first() else {
+                panic!("Expected ExpressionStatement");
+            };
+            let Expression::V8IntrinsicExpression(expr) = &expr_stmt.expression else {
+                panic!("Expected V8IntrinsicExpression");
+            };
+            assert_eq!(expr.arguments.len(), 1);
+            assert_eq!(expr.arguments[0].span().source_text(source), "...illegalSpread");
+        }
+        {
+            let opts = ParseOptions { allow_v8_intrinsics: true, ..ParseOptions::default() };
+            for source in [
+                "const x = %GetOptimizationStatus(f);",
+                "foo(%GetOptimizationStatus(f), %DebugPrint(x));",
+                "`${%DebugPrint(x)}`;",
+                "const x = %DebugPrint(...a), y = %DebugPrint(b);",
+                "f(`${%DebugPrint(...a)}`);",
+            ] {
+                let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+                assert!(!ret.panicked, "{source}");
+                assert!(ret.errors.len() <= 1, "{source}");
+                assert_eq!(ret.errors.is_empty(), !source.contains("..."), "{source}");
+                assert_eq!(ret.program.body.len(), 1, "{source}");
+            }
+        }
+        {
+            for source in [
+                "%DebugPrint(x);",
+                "const x = %GetOptimizationStatus(f);",
+                "foo(%DebugPrint(x));",
+                "`${%DebugPrint(x)}`;",
+            ] {
+                let ret = Parser::new(&allocator, source, source_type).parse();
+                assert!(ret.panicked, "{source}");
+                assert_eq!(ret.errors.len(), 1, "{source}");
+                assert_eq!(ret.errors[0].to_string(), "Unexpected token", "{source}");
+                let label = ret.errors[0].labels.as_ref().unwrap()[0].offset();
+                assert_eq!(&source[label..=label], "%", "{source}");
+            }
         }
         {
             let source = "%DebugPrint('~~')";
@@ -712,113 +2361,1582 @@ mod test {
     }
 
     #[test]
-    fn comments() {
+    fn placeholder_nodes() {
         let allocator = Allocator::default();
-        let source_type = SourceType::default().with_typescript(true);
-        let sources = [
-            ("// line comment", CommentKind::Line),
-            ("/* line comment */", CommentKind::SingleLineBlock),
-            (
-                "type Foo = ( /* Require properties which are not generated automatically. */ 'bar')",
-                CommentKind::SingleLineBlock,
-            ),
-        ];
-        for (source, kind) in sources {
+        let source_type = SourceType::default();
+        let recover = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let no_placeholders = ParseOptions { emit_placeholder_nodes: false, ..recover };
+        // A stray `}` is skipped, instead of being parsed as a statement forever.
+        let sources = ["a + ;", "(a * );", "[a, b = ];", "x = ;\ny = 1;", "a; }\nfoo();"];
+        for source in sources {
             let ret = Parser::new(&allocator, source, source_type).parse();
-            let comments = &ret.program.comments;
-            assert_eq!(comments.len(), 1, "{source}");
-            assert_eq!(comments.first().unwrap().kind, kind, "{source}");
+            assert!(ret.panicked, "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type).with_options(no_placeholders);
+            assert!(ret.parse().panicked, "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type).with_options(recover).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), "Expression expected.", "{source}");
         }
+
+        let source = "x = ;\ny = 1;";
+        let ret = Parser::new(&allocator, source, source_type).with_options(recover).parse();
+        assert_eq!(ret.program.body.len(), 2);
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else {
+            panic!("Expected ExpressionStatement");
+        };
+        let Expression::AssignmentExpression(assign) = &stmt.expression else {
+            panic!("Expected AssignmentExpression");
+        };
+        let Expression::Identifier(ident) = &assign.right else {
+            panic!("Expected placeholder");
+        };
+        assert!(ident.name.is_empty());
+        assert!(ident.span.is_empty());
     }
 
     #[test]
-    fn hashbang() {
+    fn class_declaration_missing_name() {
         let allocator = Allocator::default();
-        let source_type = SourceType::default();
-        let source = "#!/usr/bin/node\n;";
-        let ret = Parser::new(&allocator, source, source_type).parse();
-        assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
+        let opts = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let message = "Class declaration requires a name";
+
+        for source in ["class extends B { m() {} }\nnext();", "export class { m() {} }\nnext();"] {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].message, message);
+            let class = match &ret.program.body[0] {
+                Statement::ClassDeclaration(class) => class,
+                Statement::ExportNamedDeclaration(decl) => {
+                    let Some(Declaration::ClassDeclaration(class)) = &decl.declaration else {
+                        panic!("Expected ClassDeclaration");
+                    };
+                    class
+                }
+                _ => panic!("Expected ClassDeclaration"),
+            };
+            let id = class.id.as_ref().unwrap();
+            assert!(id.name.is_empty() && id.span.is_empty());
+            assert_eq!(class.body.body.len(), 1);
+            assert_eq!(ret.program.body[1].span().source_text(source), "next();");
+        }
+
+        // The name is optional in default exports and class expressions.
+        for source in
+            ["export default class {}", "export default abstract class {}", "x = class {}"]
+        {
+            let ret = Parser::new(&allocator, source, SourceType::ts()).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+        }
+
+        // Without recovery, the missing name is left to semantic analysis.
+        let ret = Parser::new(&allocator, "class {}", SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+        let Statement::ClassDeclaration(class) = &ret.program.body[0] else {
+            panic!("Expected ClassDeclaration");
+        };
+        assert!(class.id.is_none());
     }
 
     #[test]
-    fn unambiguous() {
+    fn suggested_fixes() {
         let allocator = Allocator::default();
-        let source_type = SourceType::unambiguous();
-        assert!(source_type.is_unambiguous());
-        let sources = ["import x from 'foo';", "export {x} from 'foo';", "import.meta"];
-        for source in sources {
-            let ret = Parser::new(&allocator, source, source_type).parse();
-            assert!(ret.program.source_type.is_module());
+        let opts = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let sources = [
+            ("const o = { a: 1; b: 2; };", SourceType::mjs()),
+            ("class A extends B, {}", SourceType::mjs()),
+            ("f(a[i);", SourceType::mjs()),
+            ("arr[i).push(1);", SourceType::mjs()),
+            ("(a + b]);", SourceType::mjs()),
+            ("const f = (x) => ({...x )};", SourceType::mjs()),
+            ("foo()));\nbar();", SourceType::mjs()),
+            ("const f = async (a, => await a;", SourceType::mjs()),
+            ("enum E { A = , B = 2 }", SourceType::ts()),
+            ("function f() extends B {}", SourceType::mjs()),
+            ("const h = (x: number): { a: number } { return { a: x }; };", SourceType::ts()),
+        ];
+        for (source, source_type) in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(!ret.errors.is_empty(), "{source}");
+            assert_eq!(ret.fixes.len(), ret.errors.len(), "{source}");
+            // Fixes at the same position are applied in order.
+            let mut fixed = source.to_string();
+            for fix in ret.fixes.iter().rev() {
+                fixed.replace_range(
+                    fix.replace_span.start as usize..fix.replace_span.end as usize,
+                    &fix.replacement,
+                );
+            }
+            let ret = Parser::new(&allocator, &fixed, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source} -> {fixed}: {:?}", ret.errors);
         }
 
-        let sources = ["", "import('foo')"];
-        for source in sources {
-            let ret = Parser::new(&allocator, source, source_type).parse();
-            assert!(ret.program.source_type.is_script());
-        }
+        // Indexes are into all errors, including the lexer's.
+        let source = "let s = '\\u{110000}'; ({ a: 1; b: 2 });";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 2);
+        assert_eq!(ret.fixes.len(), 1);
+        let fix = &ret.fixes[0];
+        assert_eq!(ret.errors[fix.error_index].message, "Unexpected `;` in object literal");
+        assert_eq!((fix.replace_span.source_text(source), fix.replacement.as_str()), (";", ","));
+
+        // Errors without an unambiguous fix have none.
+        let ret =
+            Parser::new(&allocator, "let x = a +;", SourceType::mjs()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert!(ret.fixes.is_empty());
     }
 
     #[test]
-    fn memory_leak() {
+    fn explicit_resource_management() {
         let allocator = Allocator::default();
-        let source_type = SourceType::default();
-        let sources = ["2n", ";'1234567890123456789012345678901234567890'"];
-        for source in sources {
+        let source_type = SourceType::mjs();
+        let opts =
+            ParseOptions { report_explicit_resource_management: true, ..ParseOptions::default() };
+        let sources = [
+            ("using a = b;", vec!["using"]),
+            ("async function f() { await using a = b, c = d; }", vec!["await using"]),
+            ("for (using a of b);", vec!["using"]),
+            ("for (await using a of b);", vec!["await using"]),
+            ("using a = b; { using c = d; }", vec!["using", "using"]),
+            ("let using = 1; using[0];", vec![]),
+        ];
+        for (source, labels) in sources {
             let ret = Parser::new(&allocator, source, source_type).parse();
-            assert!(!ret.program.body.is_empty());
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), labels.len(), "{source}");
+            for (error, label) in ret.errors.iter().zip(labels) {
+                assert_eq!(error.severity, oxc_diagnostics::Severity::Warning, "{source}");
+                let span = &error.labels.as_ref().unwrap()[0];
+                assert_eq!(&source[span.offset()..span.offset() + span.len()], label, "{source}");
+            }
+            assert!(!ret.program.body.is_empty(), "{source}");
         }
     }
 
-    // Source with length MAX_LEN + 1 fails to parse.
-    // Skip this test on 32-bit systems as impossible to allocate a string longer than `isize::MAX`.
-    // Also skip running under Miri since it takes so long.
-    #[cfg(target_pointer_width = "64")]
-    #[cfg(not(miri))]
     #[test]
-    fn overlong_source() {
-        // Build string in 16 KiB chunks for speed
-        let mut source = String::with_capacity(MAX_LEN + 1);
-        let line = "var x = 123456;\n";
-        let chunk = line.repeat(1024);
-        while source.len() < MAX_LEN + 1 - chunk.len() {
-            source.push_str(&chunk);
-        }
-        while source.len() < MAX_LEN + 1 - line.len() {
-            source.push_str(line);
-        }
-        while source.len() < MAX_LEN + 1 {
-            source.push('\n');
-        }
-        assert_eq!(source.len(), MAX_LEN + 1);
-
+    fn module_system_hints() {
         let allocator = Allocator::default();
-        let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
-        assert!(ret.program.is_empty());
-        assert!(ret.panicked);
-        assert_eq!(ret.errors.len(), 1);
-        assert_eq!(ret.errors.first().unwrap().to_string(), "Source length exceeds 4 GiB limit");
+        let opts = ParseOptions { module_system_hints: true, ..ParseOptions::default() };
+        let warnings = |source: &str, path: &str, options: ParseOptions| {
+            let source_type = SourceType::from_path(path).unwrap();
+            let ret = Parser::new(&allocator, source, source_type).with_options(options).parse();
+            assert!(!ret.panicked, "{source}");
+            assert!(
+                ret.errors.iter().all(|e| e.severity == oxc_diagnostics::Severity::Warning),
+                "{source}: {:?}",
+                ret.errors
+            );
+            ret.errors.iter().map(ToString::to_string).collect::<Vec<_>>()
+        };
+
+        let errors = warnings("import x from 'y';", "a.cts", opts);
+        assert_eq!(errors, ["This file is CommonJS (`.cts`), but uses an ES module import"]);
+        assert!(
+            warnings("import type x from 'y'; import x = require('y');", "a.cts", opts).is_empty()
+        );
+        assert!(warnings("import x from 'y';", "a.d.cts", opts).is_empty());
+        assert!(warnings("import x from 'y';", "a.mts", opts).is_empty());
+
+        let errors = warnings("export = x;", "a.mts", opts);
+        assert_eq!(errors, ["`export =` cannot be used in an ES module"]);
+        assert!(warnings("export = x;", "a.cts", opts).is_empty());
+        assert!(warnings("declare module 'm' { export = x; }", "a.mts", opts).is_empty());
+
+        let errors = warnings("const fs = require('fs'); require('path');", "a.mjs", opts);
+        assert_eq!(errors, ["`require` is not defined in ES modules"]);
+        assert!(warnings("const fs = require('fs');", "a.cjs", opts).is_empty());
+
+        // Disabled by default.
+        let default = ParseOptions::default();
+        assert!(warnings("import x from 'y';", "a.cts", default).is_empty());
+        assert!(warnings("export = x;", "a.mts", default).is_empty());
+        assert!(warnings("require('fs');", "a.mjs", default).is_empty());
     }
 
-    // Source with length MAX_LEN parses OK.
-    // This test takes over 1 minute on an M1 Macbook Pro unless compiled in release mode.
-    // `not(debug_assertions)` is a proxy for detecting release mode.
-    // Also skip running under Miri since it takes so long.
-    #[cfg(not(debug_assertions))]
-    #[cfg(not(miri))]
     #[test]
-    fn legal_length_source() {
-        // Build a string MAX_LEN bytes long which doesn't take too long to parse
-        let head = "const x = 1;\n/*";
-        let foot = "*/\nconst y = 2;\n";
-        let mut source = "x".repeat(MAX_LEN);
-        source.replace_range(..head.len(), head);
-        source.replace_range(MAX_LEN - foot.len().., foot);
-        assert_eq!(source.len(), MAX_LEN);
-
+    fn warn_var_declarations() {
         let allocator = Allocator::default();
-        let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
+        let opts = ParseOptions { warn_var_declarations: true, ..ParseOptions::default() };
+        let source = "var a = 1;\nexport var b = 2;\nfor (var i = 0;;) {}\nlet c; const d = 3;\nfunction f() { var e; }\ndeclare var g: number;\ndeclare namespace N { var h: number; }\ndeclare global { var j: number; }";
+        let ret = Parser::new(&allocator, source, SourceType::ts()).with_options(opts).parse();
         assert!(!ret.panicked);
+        assert!(ret.errors.iter().all(|e| e.severity == oxc_diagnostics::Severity::Warning));
+        assert!(ret.errors.iter().all(|e| e.message == "Unexpected `var`"));
+        let offsets = ret
+            .errors
+            .iter()
+            .map(|e| e.labels.as_ref().unwrap()[0].offset())
+            .map(|offset| &source[offset..offset + 7])
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, ["var a =", "var b =", "var i =", "var e; "]);
+
+        let ret = Parser::new(&allocator, "var a;", SourceType::d_ts()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+
+        // Disabled by default.
+        let ret = Parser::new(&allocator, "var a;", SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn skip_embedded_wrappers() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let opts = ParseOptions { skip_embedded_wrappers: true, ..ParseOptions::default() };
+        let fence = "Skipped a markdown code fence around the source";
+        let script = "Skipped a `<script>` tag around the source";
+
+        for (source, message, wrappers) in [
+            ("```js\nlet a = 1;\nfoo();\n```\n", fence, ["```js", "```"]),
+            ("```\nfoo();\r\n```", fence, ["```", "```"]),
+            (
+                "<script type=\"module\">\nfoo();\n</script>\n\n",
+                script,
+                ["<script type=\"module\">", "</script>"],
+            ),
+        ] {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 2, "{source}");
+            assert!(ret.errors.iter().all(|e| e.message == message));
+            assert!(ret.errors.iter().all(|e| e.severity == oxc_diagnostics::Severity::Warning));
+            let labels = ret
+                .errors
+                .iter()
+                .map(|e| {
+                    let label = &e.labels.as_ref().unwrap()[0];
+                    &source[label.offset()..label.offset() + label.len()]
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(labels, wrappers);
+            assert_eq!(ret.program.span.end as usize, source.len());
+            let last = ret.program.body.last().unwrap();
+            assert_eq!(last.span().source_text(source), "foo();");
+        }
+
+        // Only whole lines at the very start and end are skipped.
+        for source in ["let a = 1;\nfoo(a);\n", "// ```\nfoo();\n", "foo('```');\n"] {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert_eq!(ret.program.body.len(), 1 + usize::from(source.starts_with("let")));
+        }
+        let source = "foo();\n```\nbar();\n";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.panicked);
+
+        // Disabled by default. The backticks of fences happen to form template literals.
+        let ret = Parser::new(&allocator, "```js\nfoo();\n```\n", source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(matches!(
+            &ret.program.body[0],
+            Statement::ExpressionStatement(stmt) if matches!(stmt.expression, Expression::TaggedTemplateExpression(_))
+        ));
+        let ret = Parser::new(&allocator, "<script>\nfoo();\n</script>\n", source_type).parse();
+        assert!(ret.panicked);
+    }
+
+    #[test]
+    fn suspicious_comments() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let opts = ParseOptions { warn_suspicious_comments: true, ..ParseOptions::default() };
+        let filler = "\n".repeat(30);
+
+        // The first comment is missing its `*/`, so it swallows the three statements up to the
+        // end of the second comment.
+        let source = format!(
+            "/* setup{filler}\nconst a = 1;\nif (a) {{\n  run(a);\n}}\n/* second comment */\nfoo();"
+        );
+        let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        let error = &ret.errors[0];
+        assert_eq!(error.severity, oxc_diagnostics::Severity::Warning);
+        assert_eq!(error.message, "This block comment spans 36 lines and appears to contain code");
+        let label = &error.labels.as_ref().unwrap()[0];
+        assert_eq!(label.offset(), 0);
+        assert!(source[label.offset() + label.len()..].starts_with("\nfoo();"));
+        assert_eq!(ret.program.body.len(), 1);
+
+        let ret = Parser::new(&allocator, &source, source_type).parse();
+        assert!(ret.errors.is_empty());
+
+        let short = ParseOptions { suspicious_comment_max_lines: 40, ..opts };
+        let ret = Parser::new(&allocator, &source, source_type).with_options(short).parse();
+        assert!(ret.errors.is_empty());
+
+        let license = format!(
+            "/**\n{}\n */\nfoo();",
+            " * Permission is hereby granted, free of charge, to any person\n".repeat(40)
+        );
+        let ret = Parser::new(&allocator, &license, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn sparse_array_warnings() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { report_sparse_array_warnings: true, ..ParseOptions::default() };
+        let sources = [
+            ("[1, , 3]", vec![", ,"]),
+            ("[, 1]", vec!["[,"]),
+            ("[1, , , 2]", vec![", ,", ", ,"]),
+            ("[1, ,]", vec![", ,"]),
+            ("[[, 1], ,]", vec!["[,", ", ,"]),
+            ("[1, 2,]", vec![]),
+            ("[]", vec![]),
+            ("[, a] = b", vec![]),
+            ("[[, a], , b] = c", vec![]),
+            ("let [, a] = b", vec![]),
+        ];
+        for (source, labels) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), labels.len(), "{source}");
+            for (error, label) in ret.errors.iter().zip(labels) {
+                assert_eq!(error.severity, oxc_diagnostics::Severity::Warning, "{source}");
+                let span = &error.labels.as_ref().unwrap()[0];
+                assert_eq!(&source[span.offset()..span.offset() + span.len()], label, "{source}");
+            }
+        }
+    }
+
+    #[test]
+    fn recovered_spans() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions {
+            recover_from_errors: true,
+            track_recovered_nodes: true,
+            ..ParseOptions::default()
+        };
+        let recovered = |source: &'static str| -> Vec<&str> {
+            let ret = Parser::new(&allocator, source, SourceType::ts()).with_options(opts).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+            ret.recovered_spans.iter().map(|span| span.source_text(source)).collect()
+        };
+
+        let source = "let a = 1;\nlet b = a + ;\nfunction f() { return a; }\nthrow;\nclass C {}\n";
+        assert_eq!(recovered(source), ["let b = a + ;", "throw;"]);
+
+        // A statement with several recoveries is included once.
+        assert_eq!(recovered("let a = (1 + ] + ;\nb;"), ["let a = (1 + ] + ;"]);
+
+        // Only the innermost statement or member is included.
+        let source = "function f() {\n  a;\n  b = c + ;\n}\nfunction g() { (x: T) => { y + ; }; }";
+        assert_eq!(recovered(source), ["b = c + ;", "y + ;"]);
+        let source = "class C {\n  a = 1;\n  [a, b] = foo();\n  m() {}\n}";
+        assert_eq!(recovered(source), ["[a, b] = foo();"]);
+        assert_eq!(recovered("x = { a: 1, b: 2 + , c };"), ["b: 2 +"]);
+        // Recoveries in the statement around a nested statement.
+        assert_eq!(recovered("if (a + ) { b; }"), ["if (a + ) { b; }"]);
+        let source = "if (a) {} else if (b + ) {} else if (c) {} else { d + ; }";
+        assert_eq!(recovered(source), ["if (b + ) {} else if (c) {} else { d + ; }", "d + ;"]);
+
+        // Tokens skipped between statements.
+        assert_eq!(recovered("a; }\nfoo();"), ["}"]);
+        assert_eq!(recovered("if (x) { a + ; }\n}\nfoo();"), ["a + ;", "}"]);
+
+        // Errors which do not need recovery.
+        assert!(recovered("a = () => { 'use strict'; with (b) {} };").is_empty());
+
+        // Not collected by default, or without recovery.
+        for opts in [
+            ParseOptions { track_recovered_nodes: false, ..opts },
+            ParseOptions { recover_from_errors: false, ..opts },
+        ] {
+            let ret = Parser::new(&allocator, "a + ;", SourceType::ts()).with_options(opts).parse();
+            assert!(!ret.errors.is_empty());
+            assert!(ret.recovered_spans.is_empty());
+        }
+    }
+
+    #[test]
+    fn unterminated_constructs() {
+        use UnterminatedConstructKind::{
+            Brace, Bracket, Comment, Paren, RegExp, String as Str, Template,
+        };
+        type Expected = &'static [(UnterminatedConstructKind, u32, &'static str)];
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+        let recover = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let cases: [(&str, Expected); 10] = [
+            ("function f() { if (x) {} }", &[]),
+            (
+                "function f() { if (x) { foo(",
+                &[(Brace, 13, "{"), (Brace, 22, "{"), (Paren, 27, "(")],
+            ),
+            ("foo(a, [1, {", &[(Paren, 3, "("), (Bracket, 7, "["), (Brace, 11, "{")]),
+            ("{ (a }", &[]),
+            ("let s = 'abc", &[(Str, 8, "'abc")]),
+            ("{ let s = 'abc", &[(Brace, 0, "{"), (Str, 10, "'abc")]),
+            ("let s = 'abc\nfoo;", &[(Str, 8, "'abc\n")]),
+            ("let t = `abc${x}", &[(Template, 15, "}")]),
+            ("let r = /abc", &[(RegExp, 8, "/abc")]),
+            ("foo(/* comment", &[(Paren, 3, "("), (Comment, 4, "/* comment")]),
+        ];
+        for (source, expected) in cases {
+            for opts in [ParseOptions::default(), recover] {
+                let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+                let constructs = ret
+                    .unterminated_constructs
+                    .iter()
+                    .map(|construct| {
+                        (construct.kind, construct.span.start, construct.span.source_text(source))
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(constructs, expected, "{source}");
+            }
+        }
+    }
+
+    #[test]
+    fn render_errors() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, "function f() {\n  foo();\n", source_type).parse();
+        let output = ret.render_errors("test.js");
+        for expected in [
+            "Expected `}` but found `EOF`",
+            "test.js",
+            "function f() {",
+            "foo();",
+            "Opened here, but never closed",
+        ] {
+            assert!(output.contains(expected), "{expected:?} not in:\n{output}");
+        }
+
+        let ret = Parser::new(&allocator, "let a = 1;", source_type).parse();
+        assert_eq!(ret.render_errors("test.js"), "");
+    }
+
+    #[test]
+    fn allow_private_names_outside_class() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts =
+            ParseOptions { allow_private_names_outside_class: true, ..ParseOptions::default() };
+
+        let source = "this.#x = 1;\nif (#x in obj) obj.#x();";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.errors.len(), 3);
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert_eq!(ret.program.body.len(), 2);
+
+        // Class elements outside of a class are still reported.
+        let opts = ParseOptions { recover_from_errors: true, ..opts };
+        let ret = Parser::new(&allocator, "#field = 1;", source_type).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].to_string(), "Private names are only allowed in class bodies");
+
+        let source = "class C { #x; #y; m(o) { #x in #y in o; } }";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.panicked);
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(
+            ret.errors[0].to_string(),
+            "Unexpected right-hand side of private-in expression"
+        );
+    }
+
+    #[test]
+    fn report_irregular_whitespace_in_tokens() {
+        let allocator = Allocator::default();
+        let opts =
+            ParseOptions { report_irregular_whitespace_in_tokens: true, ..ParseOptions::default() };
+        let messages = |source: &str, opts: ParseOptions| -> Vec<(String, Option<usize>)> {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+            ret.errors
+                .iter()
+                .filter(|error| error.message.contains("Irregular whitespace"))
+                .map(|error| {
+                    let offset = error.labels.as_ref().and_then(|labels| labels.first());
+                    (error.message.to_string(), offset.map(oxc_diagnostics::LabeledSpan::offset))
+                })
+                .collect()
+        };
+        let in_token = |offset| ("Irregular whitespace inside a token".to_string(), Some(offset));
+
+        // Reported alongside the error caused by the split token, with or without recovery.
+        for recover_from_errors in [false, true] {
+            let opts = ParseOptions { recover_from_errors, ..opts };
+            assert_eq!(messages("foo\u{a0}bar;", opts), [in_token(3)]);
+            assert_eq!(messages("let x = 1\u{feff}000;", opts), [in_token(9)]);
+            assert_eq!(messages("foo\t \u{200b}bar;", opts), [in_token(5)]);
+        }
+
+        let source = "a.\u{a0}b;";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(
+            messages(source, opts),
+            [("Irregular whitespace between `.` and a property name".to_string(), Some(2))]
+        );
+
+        // Irregular whitespace between tokens.
+        let sources = [
+            "let\u{a0}x = typeof\u{a0}y;",
+            "function f() { return\u{a0}x; }",
+            "[...\u{a0}a];",
+            "a\u{a0}= b\u{a0}+\u{a0}c;",
+            "a\u{a0}\nb;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            assert!(!ret.irregular_whitespaces.is_empty(), "{source}");
+        }
+
+        // Not reported by default.
+        assert!(messages("foo\u{a0}bar;", ParseOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn disallow_legacy_features() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { disallow_legacy_features: true, ..ParseOptions::default() };
+        // (source, message, span of the error)
+        let sources = [
+            ("x = 010;", "Legacy octal literals are not allowed", Span::new(4, 7)),
+            ("x = 'a\\101b';", "Octal escape sequences are not allowed", Span::new(6, 10)),
+            ("x = '\\08';", "Octal escape sequences are not allowed", Span::new(5, 7)),
+            ("x = 1; <!-- comment", "HTML-like comments are not allowed", Span::new(7, 11)),
+            ("x = 1;\n--> comment", "HTML-like comments are not allowed", Span::new(7, 10)),
+            ("with (a) b;", "'with' statements are not allowed", Span::new(0, 4)),
+        ];
+        for (source, message, span) in sources {
+            let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}: {:?}", ret.errors);
+            assert_eq!(ret.errors[0].to_string(), message, "{source}");
+            let label = &ret.errors[0].labels.as_ref().unwrap()[0];
+            assert_eq!(label.offset(), span.start as usize, "{source}");
+            assert_eq!(label.len(), span.size() as usize, "{source}");
+            assert_eq!(ret.program.body.len(), 1, "{source}");
+        }
+
+        // Not legacy syntax.
+        let source = "x = 0o10 + 0 + 0.5 + 08 + 1e010; y = '\\0 \\x41 \\\\101'; a-- > 0;";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(opts).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+
+        // Modules report the same errors once. `<!--` and `-->` are not comments in modules.
+        let sources = ["x = 010;", "x = '\\101';", "with (a) b;", "x = 1; <!-- comment"];
+        for source in sources {
+            let default_errors =
+                Parser::new(&allocator, source, SourceType::mjs()).parse().errors.len();
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+            assert_eq!(ret.errors.len(), default_errors.max(1), "{source}: {:?}", ret.errors);
+            assert!(
+                ret.errors
+                    .iter()
+                    .all(|error| error.to_string() != "HTML-like comments are not allowed"),
+                "{source}"
+            );
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn parse_to_json_ast() {
+        let allocator = Allocator::default();
+        let source = "// a\nlet x: T = 010;";
+        let opts = ParseOptions { disallow_legacy_features: true, ..ParseOptions::default() };
+        let parser = || Parser::new(&allocator, source, SourceType::ts()).with_options(opts);
+
+        let json = parser().parse_to_json_ast(true, true);
+        assert!(json.starts_with(
+            r#"{"program":{"type":"Program","body":[{"type":"VariableDeclaration","kind":"let","#
+        ));
+        assert!(json.contains(r#""typeAnnotation":{"type":"TSTypeAnnotation""#));
+        assert!(json.contains(r#"{"type":"Literal","value":8,"raw":"010","start":16,"end":19}"#));
+        assert!(json.contains(r#""comments":[{"type":"Line","value":" a","start":0,"end":4}]"#));
+        assert!(json.ends_with(
+            r#""errors":[{"severity":"Error","message":"Legacy octal literals are not allowed","labels":[{"message":null,"start":16,"end":19}],"helpMessage":"Use the `0o` prefix instead"}]}"#
+        ));
+
+        let json = parser().parse_to_json_ast(false, false);
+        assert!(!json.contains(r#""comments""#));
+        assert!(!json.contains(r#""errors""#));
+        assert!(json.ends_with(r#""declare":false,"start":5,"end":20}],"sourceType":"module","hashbang":null,"start":5,"end":20}}"#));
+
+        // No TypeScript fields in JavaScript.
+        let source = "let x = /a/g; // b";
+        let json = Parser::new(&allocator, source, SourceType::mjs()).parse_to_json_ast(true, true);
+        assert!(!json.contains("typeAnnotation"));
+        assert!(json.contains(r#""type":"Literal","value":null,"raw":"/a/g","#));
+        assert!(json.ends_with(
+            r#""comments":[{"type":"Line","value":" b","start":14,"end":18}],"errors":[]}"#
+        ));
+    }
+
+    #[test]
+    fn jsx_namespace_support() {
+        let allocator = Allocator::default();
+        let source = "<svg:rect xlink:href='#a'>{x}</svg:rect>;";
+        let opts = ParseOptions { jsx_namespace_support: false, ..ParseOptions::default() };
+
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).parse();
+        assert!(ret.errors.is_empty());
+
+        let ret = Parser::new(&allocator, source, SourceType::jsx()).with_options(opts).parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 2, "{:?}", ret.errors);
+        for (error, expected) in ret.errors.iter().zip([(1, 8), (10, 10)]) {
+            assert_eq!(error.to_string(), "JSX namespaced names are not supported");
+            let label = &error.labels.as_ref().unwrap()[0];
+            assert_eq!((label.offset(), label.len()), expected);
+        }
+        let Statement::ExpressionStatement(stmt) = &ret.program.body[0] else {
+            panic!("Expected ExpressionStatement");
+        };
+        let Expression::JSXElement(element) = &stmt.expression else {
+            panic!("Expected JSXElement");
+        };
+        assert!(matches!(element.opening_element.name, JSXElementName::NamespacedName(_)));
+    }
+
+    #[test]
+    fn options_builder() {
+        let options = ParseOptions {
+            allow_return_outside_function: true,
+            allow_top_level_await_in_script: true,
+            allow_private_names_outside_class: true,
+            preserve_parens: false,
+            preserve_empty_statements: false,
+            allow_v8_intrinsics: true,
+            jsx_namespace_support: false,
+            disallow_legacy_features: true,
+            report_irregular_whitespace_in_tokens: true,
+            recover_from_errors: true,
+            emit_placeholder_nodes: false,
+            track_recovered_nodes: true,
+            report_flow_errors_only: false,
+            max_nesting_depth: Some(64),
+            max_parameters: Some(8),
+            report_sparse_array_warnings: true,
+            report_explicit_resource_management: true,
+            warn_suspicious_comments: true,
+            suspicious_comment_max_lines: 10,
+            module_system_hints: true,
+            warn_var_declarations: true,
+            skip_embedded_wrappers: true,
+            collect_contextual_keywords: true,
+            capture_token_trivia: true,
+            validate_ambient_declarations: true,
+            expression_restriction: Some(ExpressionRestriction::Json5),
+            normalize_regex_flags: true,
+            identifier_validation: IdentifierValidation::Ascii,
+            collect_todo_comments: DEFAULT_TODO_COMMENT_TAGS,
+            collect_stats: true,
+            ..ParseOptions::default()
+        };
+        let (built, diagnostics) = ParseOptions::builder()
+            .allow_return_outside_function(true)
+            .allow_top_level_await_in_script(true)
+            .allow_private_names_outside_class(true)
+            .preserve_parens(false)
+            .preserve_empty_statements(false)
+            .allow_v8_intrinsics(true)
+            .jsx_namespace_support(false)
+            .disallow_legacy_features(true)
+            .report_irregular_whitespace_in_tokens(true)
+            .recover_from_errors(true)
+            .emit_placeholder_nodes(false)
+            .track_recovered_nodes(true)
+            .report_flow_errors_only(false)
+            .max_nesting_depth(Some(64))
+            .max_parameters(Some(8))
+            .report_sparse_array_warnings(true)
+            .report_explicit_resource_management(true)
+            .warn_suspicious_comments(true)
+            .suspicious_comment_max_lines(10)
+            .module_system_hints(true)
+            .warn_var_declarations(true)
+            .skip_embedded_wrappers(true)
+            .collect_contextual_keywords(true)
+            .capture_token_trivia(true)
+            .validate_ambient_declarations(true)
+            .expression_restriction(Some(ExpressionRestriction::Json5))
+            .normalize_regex_flags(true)
+            .identifier_validation(IdentifierValidation::Ascii)
+            .collect_todo_comments(DEFAULT_TODO_COMMENT_TAGS)
+            .collect_stats(true)
+            .build();
+        assert_eq!(built, options);
+        assert!(diagnostics.is_empty());
+
+        let (built, diagnostics) = ParseOptions::builder().build();
+        assert_eq!(built, ParseOptions::default());
+        assert!(diagnostics.is_empty());
+
+        let allocator = Allocator::default();
+        let builder = ParseOptions::builder().emit_placeholder_nodes(true);
+        let ret = Parser::new(&allocator, "a", SourceType::default())
+            .with_options_builder(builder)
+            .parse();
+        assert!(!ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].severity, oxc_diagnostics::Severity::Warning);
+    }
+
+    #[cfg(feature = "regular_expression")]
+    #[test]
+    fn options_builder_regular_expression() {
+        let (options, diagnostics) = ParseOptions::builder().parse_regular_expression(true).build();
+        assert!(options.parse_regular_expression);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[cfg(not(feature = "regular_expression"))]
+    #[test]
+    fn options_builder_regular_expression_feature_disabled() {
+        let (options, diagnostics) = ParseOptions::builder().parse_regular_expression(true).build();
+        assert_eq!(options, ParseOptions::default());
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    /// Adversarially nested sources, 100,000 levels deep.
+    fn deeply_nested_sources() -> Vec<(String, SourceType)> {
+        let n = 100_000;
+        let js = SourceType::default();
+        let ts = SourceType::ts();
+        let jsx = SourceType::jsx();
+        vec![
+            (format!("{}a{}", "(".repeat(n), ")".repeat(n)), js),
+            (format!("{}{}", "[".repeat(n), "]".repeat(n)), js),
+            (format!("{}a{}", "a ? ".repeat(n), " : a".repeat(n)), js),
+            (format!("{}a", "!".repeat(n)), js),
+            (format!("async () => {}a", "await ".repeat(n)), js),
+            (format!("{}A", "new ".repeat(n)), js),
+            (format!("let {}a{} = b", "[".repeat(n), "]".repeat(n)), js),
+            (format!("{}{}", "{".repeat(n), "}".repeat(n)), js),
+            (format!("type T = {}A{}", "A & (".repeat(n), ")".repeat(n)), ts),
+            (format!("type T = {}A", "keyof ".repeat(n)), ts),
+            (format!("{}{}", "<a>".repeat(n), "</a>".repeat(n)), jsx),
+        ]
+    }
+
+    fn assert_nesting_too_deep(max_nesting_depth: u32) {
+        let options =
+            ParseOptions { max_nesting_depth: Some(max_nesting_depth), ..ParseOptions::default() };
+        for (source, source_type) in deeply_nested_sources() {
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, &source, source_type).with_options(options).parse();
+            assert!(ret.panicked);
+            assert_eq!(ret.errors.len(), 1);
+            let message = format!("Maximum nesting depth of {max_nesting_depth} exceeded");
+            assert_eq!(ret.errors[0].to_string(), message);
+        }
+    }
+
+    #[test]
+    fn max_nesting_depth() {
+        // Use a large stack so that the depth guard is hit before the stack overflows,
+        // even in debug builds.
+        std::thread::Builder::new()
+            .stack_size(256 * 1024 * 1024)
+            .spawn(|| assert_nesting_too_deep(1024))
+            .unwrap()
+            .join()
+            .unwrap();
+
+        let allocator = Allocator::default();
+        let source = "((((a))))";
+        let ret = Parser::new(&allocator, source, SourceType::default()).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        let options = ParseOptions { max_nesting_depth: Some(3), ..ParseOptions::default() };
+        let ret =
+            Parser::new(&allocator, source, SourceType::default()).with_options(options).parse();
+        assert!(ret.panicked);
+        assert_eq!(ret.errors[0].to_string(), "Maximum nesting depth of 3 exceeded");
+    }
+
+    #[test]
+    fn max_nesting_depth_default_stack() {
+        // A spawned thread has a 2 MiB stack by default.
+        std::thread::spawn(|| {
+            assert_nesting_too_deep(256);
+
+            // Valid code is not limited by default, and long `else if` chains don't recurse.
+            let allocator = Allocator::default();
+            let source = format!("if (a) {{}}{}", " else if (a) {}".repeat(5000));
+            let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
+            assert!(!ret.panicked);
+            assert!(ret.errors.is_empty());
+            let Statement::IfStatement(stmt) = &ret.program.body[0] else {
+                panic!("Expected IfStatement");
+            };
+            assert_eq!(stmt.span.end as usize, source.len());
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn max_parameters() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let params = (0..2000).map(|i| format!("p{i}")).collect::<Vec<_>>().join(", ");
+        let sources = [
+            format!("function f({params}) {{}}"),
+            format!("({params}) => {{}};"),
+            format!("class C {{ m({params}) {{}} }}"),
+        ];
+        for source in &sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty());
+        }
+
+        let opts = ParseOptions { max_parameters: Some(3), ..ParseOptions::default() };
+        let sources = [
+            ("function f(a, b, c) {}", None),
+            ("function f(a, b, c, d, e) {}", Some("d")),
+            ("(a, b, c, ...d) => {};", Some("...d")),
+            ("function f(this: T, a, b, c) {}", None),
+            ("class C { constructor(a, b, c, private d) {} }", Some("private d")),
+        ];
+        for (source, span_text) in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            let Some(span_text) = span_text else {
+                assert!(ret.errors.is_empty(), "{source}");
+                continue;
+            };
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].to_string(), "Maximum number of 3 parameters exceeded");
+            let label = ret.errors[0].labels.as_ref().unwrap()[0].clone();
+            let span = Span::sized(
+                u32::try_from(label.offset()).unwrap(),
+                u32::try_from(label.len()).unwrap(),
+            );
+            assert_eq!(span.source_text(source), span_text, "{source}");
+        }
+
+        // The parameters are not truncated.
+        let source = "function f(a, b, c, d, e) {}";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        let Some(Statement::FunctionDeclaration(func)) = ret.program.body.first() else {
+            panic!("Expected FunctionDeclaration");
+        };
+        assert_eq!(func.params.items.len(), 5);
+    }
+
+    #[test]
+    fn comments() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let sources = [
+            ("// line comment", CommentKind::Line),
+            ("/* line comment */", CommentKind::SingleLineBlock),
+            (
+                "type Foo = ( /* Require properties which are not generated automatically. */ 'bar')",
+                CommentKind::SingleLineBlock,
+            ),
+        ];
+        for (source, kind) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            let comments = &ret.program.comments;
+            assert_eq!(comments.len(), 1, "{source}");
+            assert_eq!(comments.first().unwrap().kind, kind, "{source}");
+        }
+    }
+
+    #[test]
+    fn hashbang() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let source = "#!/usr/bin/node\n;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert_eq!(ret.program.hashbang.unwrap().value.as_str(), "/usr/bin/node");
+    }
+
+    #[test]
+    fn byte_order_mark() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::mjs();
+
+        let source = "\u{feff}#!/usr/bin/env node\nfoo();";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.had_bom);
+        assert_eq!(ret.program.span, Span::new(3, 29));
+        let hashbang = ret.program.hashbang.unwrap();
+        assert_eq!(hashbang.span, Span::new(3, 22));
+        assert_eq!(hashbang.value.as_str(), "/usr/bin/env node");
+        assert_eq!(ret.program.body[0].span().source_text(source), "foo();");
+
+        let source = "\u{feff}let x = 1;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        assert!(ret.had_bom);
+        assert!(ret.irregular_whitespaces.is_empty());
+        assert_eq!(ret.program.span, Span::new(3, 13));
+        assert_eq!(ret.program.body[0].span(), Span::new(3, 13));
+
+        let source = "let x = 1;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.had_bom);
+        assert_eq!(ret.program.span, Span::new(0, 10));
+        assert_eq!(ret.program.body[0].span(), Span::new(0, 10));
+
+        // Only a byte order mark at the very start is skipped.
+        let source = "let x = 1;\u{feff}";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.had_bom);
+        assert_eq!(ret.irregular_whitespaces.len(), 1);
+    }
+
+    #[test]
+    fn unambiguous() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::unambiguous();
+        assert!(source_type.is_unambiguous());
+        let sources = ["import x from 'foo';", "export {x} from 'foo';", "import.meta"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.program.source_type.is_module());
+        }
+
+        let sources = ["", "import('foo')"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.program.source_type.is_script());
+        }
+
+        // Strict mode errors are reported once module syntax is found.
+        let sources = [
+            ("with (a) {}", 0),
+            ("with (a) {} export {}", 1),
+            ("x = () => { with (a) {} }; import 'b';", 1),
+        ];
+        for (source, len) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.errors.len(), len, "{source}");
+        }
+    }
+
+    #[test]
+    fn identifier_validation() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions {
+            identifier_validation: IdentifierValidation::Ascii,
+            ..ParseOptions::default()
+        };
+        let source = "let café = 1; class C { #é; ü() { return this.#é; } } x\\u0061é + 1;";
+
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty());
+
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert!(!ret.panicked);
+        let errors = ret
+            .errors
+            .iter()
+            .map(|error| (error.to_string(), error.labels.as_ref().unwrap()[0].offset()))
+            .collect::<Vec<_>>();
+        // The label is on the first non-ASCII character of each identifier.
+        let expected =
+            [("é", "é = 1"), ("é", "é; ü"), ("ü", "ü()"), ("é", "é; } }"), ("é", "é + 1")].map(
+                |(c, at)| {
+                    (format!("Non-ASCII character `{c}` in identifier"), source.find(at).unwrap())
+                },
+            );
+        assert_eq!(errors, expected);
+
+        // Unicode escapes, strings and comments are fine.
+        let source = "let caf\\u00e9 = 'é'; // ü";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn import_meta_in_script() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { recover_from_errors: true, ..ParseOptions::default() };
+        let source = "const url = import.meta.url;";
+
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].message, "import.meta is only allowed in modules");
+        let Statement::VariableDeclaration(decl) = &ret.program.body[0] else {
+            panic!("Expected VariableDeclaration");
+        };
+        let Some(Expression::StaticMemberExpression(member)) = &decl.declarations[0].init else {
+            panic!("Expected StaticMemberExpression");
+        };
+        let Expression::MetaProperty(meta) = &member.object else {
+            panic!("Expected MetaProperty");
+        };
+        assert_eq!(meta.span.source_text(source), "import.meta");
+
+        // Modules and unambiguous sources, which become modules, are fine.
+        for source_type in [SourceType::mjs(), SourceType::unambiguous()] {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty());
+        }
+
+        // Without recovery, it is left to semantic analysis.
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn module_syntax_evidence() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::unambiguous();
+        let sources = [
+            ("foo();\nimport x from 'foo';", "import x from 'foo';"),
+            ("let a;\nexport { a };\nexport default a;", "export { a };"),
+            ("export function f() { import.meta }", "export function f() { import.meta }"),
+            ("if (import.meta.url) {}", "import.meta"),
+        ];
+        for (source, evidence) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.program.source_type.is_module(), "{source}");
+            let span = ret.module_syntax_evidence.unwrap();
+            assert_eq!(span.source_text(source), evidence, "{source}");
+        }
+
+        for source in ["", "import('foo')", "function f() { export = 1; }"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.program.source_type.is_script(), "{source}");
+            assert_eq!(ret.module_syntax_evidence, None, "{source}");
+        }
+
+        let source = "export = foo;";
+        let ret = Parser::new(&allocator, source, SourceType::ts()).parse();
+        assert_eq!(ret.module_syntax_evidence, Some(Span::new(0, 13)));
+    }
+
+    #[test]
+    fn parse_cjs_detect() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::cjs();
+        let source = "const path = require('path');
+const dir = path.join(__dirname, 'x');
+function f() { return require(`y`); }
+exports.a = { __filename };
+module.exports = f;
+exports.b = 1;";
+        let ret = Parser::new(&allocator, source, source_type).parse_cjs_detect();
+        assert!(ret.parser_return.errors.is_empty());
+        assert_eq!(ret.parser_return.program.body.len(), 6);
+        let usage = ret.commonjs_usage;
+        assert!(usage.is_commonjs());
+        let text = |span: Option<Span>| span.unwrap().source_text(source);
+        assert_eq!(text(usage.require), "require('path')");
+        assert_eq!(text(usage.dirname), "__dirname");
+        assert_eq!(text(usage.filename), "__filename");
+        assert_eq!(text(usage.exports), "exports.a");
+        assert_eq!(text(usage.module_exports), "module.exports");
+
+        let sources = [
+            "import fs from 'fs'; export default fs;",
+            "foo.require('x'); module.id; exports['a'] = 1;",
+            "const require = 1; a.__dirname;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, SourceType::mjs()).parse_cjs_detect();
+            assert!(!ret.commonjs_usage.is_commonjs(), "{source}");
+        }
+
+        // Arrow function parameters are parsed speculatively first.
+        let source = "const f = (a = require('a')) => module.exports;";
+        let usage = Parser::new(&allocator, source, source_type).parse_cjs_detect().commonjs_usage;
+        assert_eq!(usage.require.unwrap().source_text(source), "require('a')");
+        assert_eq!(usage.module_exports.unwrap().source_text(source), "module.exports");
+    }
+
+    #[test]
+    fn memory_leak() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let sources = ["2n", ";'1234567890123456789012345678901234567890'"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.program.body.is_empty());
+        }
+    }
+
+    // Source with length MAX_LEN + 1 fails to parse.
+    // Skip this test on 32-bit systems as impossible to allocate a string longer than `isize::MAX`.
+    // Also skip running under Miri since it takes so long.
+    #[cfg(target_pointer_width = "64")]
+    #[cfg(not(miri))]
+    #[test]
+    fn overlong_source() {
+        // Build string in 16 KiB chunks for speed
+        let mut source = String::with_capacity(MAX_LEN + 1);
+        let line = "var x = 123456;\n";
+        let chunk = line.repeat(1024);
+        while source.len() < MAX_LEN + 1 - chunk.len() {
+            source.push_str(&chunk);
+        }
+        while source.len() < MAX_LEN + 1 - line.len() {
+            source.push_str(line);
+        }
+        while source.len() < MAX_LEN + 1 {
+            source.push('\n');
+        }
+        assert_eq!(source.len(), MAX_LEN + 1);
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
+        assert!(ret.program.is_empty());
+        assert!(ret.panicked);
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors.first().unwrap().to_string(), "Source length exceeds 4 GiB limit");
+    }
+
+    // Source with length MAX_LEN parses OK.
+    // This test takes over 1 minute on an M1 Macbook Pro unless compiled in release mode.
+    // `not(debug_assertions)` is a proxy for detecting release mode.
+    // Also skip running under Miri since it takes so long.
+    #[cfg(not(debug_assertions))]
+    #[cfg(not(miri))]
+    #[test]
+    fn legal_length_source() {
+        // Build a string MAX_LEN bytes long which doesn't take too long to parse
+        let head = "const x = 1;\n/*";
+        let foot = "*/\nconst y = 2;\n";
+        let mut source = "x".repeat(MAX_LEN);
+        source.replace_range(..head.len(), head);
+        source.replace_range(MAX_LEN - foot.len().., foot);
+        assert_eq!(source.len(), MAX_LEN);
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &source, SourceType::default()).parse();
+        assert!(!ret.panicked);
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.program.body.len(), 2);
+    }
+
+    #[test]
+    fn whitespace_runs() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions { capture_token_trivia: true, ..ParseOptions::default() };
+        let runs = |source: &'static str, source_type: SourceType| {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+            ret.whitespace_runs.iter().map(|span| span.source_text(source)).collect::<Vec<_>>()
+        };
+
+        let source_type = SourceType::mjs();
+        assert_eq!(
+            runs("let  a = /* c */ 'x y' ;\n// d\nfoo()  \n", source_type),
+            ["  ", " ", " ", " ", " ", "\n", "\n", "  \n"]
+        );
+        assert_eq!(
+            runs(" f = (a,\tb) => `${ a } b`;", source_type),
+            [" ", " ", " ", "\t", " ", " ", " ", " "]
+        );
+        assert_eq!(runs("<div  a='1'> x </div>", SourceType::jsx()), ["  "]);
+        assert!(runs("a;b", source_type).is_empty());
+
+        let source = "let  a = 1;";
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.whitespace_runs.is_empty());
+        let ret = Parser::new(&allocator, "let  a = ;", source_type).with_options(opts).parse();
+        assert!(ret.panicked);
+        assert!(ret.whitespace_runs.is_empty());
+    }
+
+    #[test]
+    fn todo_comments() {
+        let allocator = Allocator::default();
+        let opts = ParseOptions {
+            collect_todo_comments: DEFAULT_TODO_COMMENT_TAGS,
+            ..ParseOptions::default()
+        };
+        let source = "// TODO: fix this\nlet a = 1; /* FIXME(alice) handle null */\n/**\n * Docs.\n * HACK\n */\n// TODOS are not TODO\n//XXX:no space";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        let todo_comments = ret
+            .todo_comments
+            .iter()
+            .map(|todo| (todo.span.source_text(source), todo.tag, todo.message))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            todo_comments,
+            [
+                ("TODO: fix this", "TODO", "fix this"),
+                ("FIXME(alice) handle null", "FIXME", "(alice) handle null"),
+                ("HACK", "HACK", ""),
+                ("XXX:no space", "XXX", "no space"),
+            ]
+        );
+
+        // The tags are configurable.
+        let opts = ParseOptions { collect_todo_comments: &["NOTE"], ..ParseOptions::default() };
+        let source = "// NOTE: a\n// TODO: b";
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).with_options(opts).parse();
+        assert_eq!(ret.todo_comments.len(), 1);
+        assert_eq!(ret.todo_comments[0].tag, "NOTE");
+
+        let ret = Parser::new(&allocator, source, SourceType::mjs()).parse();
+        assert!(ret.todo_comments.is_empty());
+    }
+
+    #[test]
+    fn contextual_keywords() {
+        use ContextualKeyword::{As, Await, From, Let, Of, Using, Yield};
+
+        let allocator = Allocator::default();
+        let opts = ParseOptions { collect_contextual_keywords: true, ..ParseOptions::default() };
+        let source = "import a, { b as c } from 'x';
+let of = 1, as = 2;
+for (const x of of) {}
+async function f() { for await (const y of z) {} await of; }
+function* g() { yield as; }
+{ using u = h(); }
+let v = w as any;";
+        let ret = Parser::new(&allocator, source, SourceType::ts()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let keywords =
+            ret.contextual_keywords.iter().map(|(_, keyword)| *keyword).collect::<Vec<_>>();
+        assert_eq!(keywords, [As, From, Let, Of, Await, Of, Await, Yield, Using, Let, As]);
+        for (span, keyword) in ret.contextual_keywords {
+            let text = span.source_text(source);
+            assert!(text.eq_ignore_ascii_case(&format!("{keyword:?}")), "{text}");
+        }
+
+        // Identifiers only, including ones looked ahead at and rewound.
+        let source = "let = 1; let + 1; var await, yield, of, as, from, using; for (of of of) {}";
+        let ret = Parser::new(&allocator, source, SourceType::cjs()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        let keywords =
+            ret.contextual_keywords.iter().map(|(_, keyword)| *keyword).collect::<Vec<_>>();
+        assert_eq!(keywords, [Of]);
+
+        let ret = Parser::new(&allocator, "for (x of y) {}", SourceType::default()).parse();
+        assert!(ret.contextual_keywords.is_empty());
+    }
+
+    #[test]
+    fn ambient_declarations() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::d_ts();
+        let opts = ParseOptions { validate_ambient_declarations: true, ..ParseOptions::default() };
+
+        let valid = "declare function f(a: number): void;
+export function g(): void;
+declare class C { m(): void; constructor(); get x(): number; }
+declare namespace N { function h(): void; let y: string; }
+declare const a = 1, b = -1n, c = 'c', d = `d`, e = true, f2 = E.A, g2 = N.E['B'];
+export declare let h2: number;
+declare enum E { A = 1 }
+export = N;";
+        for opts in [ParseOptions::default(), opts] {
+            let ret = Parser::new(&allocator, valid, source_type).with_options(opts).parse();
+            assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+            assert_eq!(ret.program.body.len(), 8);
+            assert!(matches!(ret.program.body[7], Statement::TSExportAssignment(_)));
+            assert!(ret.module_record.has_module_syntax);
+        }
+
+        // Each statement has one violation, and parsing continues after it.
+        let sources = [
+            ("export function f() { function g() {} }", "TS(1183)"),
+            ("declare class C { m() {} }", "TS(1183)"),
+            ("declare namespace N { function f() {} }", "TS(1183)"),
+            ("declare let x = 1;", "TS(1039)"),
+            ("declare const x: number = 1;", "TS(1039)"),
+            ("export declare var x = 1;", "TS(1039)"),
+            ("declare const x = a + 1;", "TS(1254)"),
+            ("declare const x = f();", "TS(1254)"),
+        ];
+        for (source, code) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(ret.errors.is_empty(), "{source}");
+
+            let source = format!("{source}\nexport = N;");
+            let ret = Parser::new(&allocator, &source, source_type).with_options(opts).parse();
+            assert!(!ret.panicked, "{source}");
+            assert_eq!(ret.errors.len(), 1, "{source}");
+            assert_eq!(ret.errors[0].code.to_string(), code, "{source}");
+            assert_eq!(ret.program.body.len(), 2, "{source}");
+        }
+
+        // `declare` in a `.ts` file is an ambient context too.
+        let source = "declare let x = 1;\nlet y = 1;\nfunction f() {}";
+        let ret = Parser::new(&allocator, source, SourceType::ts()).with_options(opts).parse();
+        assert_eq!(ret.errors.len(), 1);
+        assert_eq!(ret.errors[0].code.to_string(), "TS(1039)");
+    }
+
+    #[test]
+    fn expression_restriction() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let parse = |source: &'static str, restriction: ExpressionRestriction| {
+            let opts = ParseOptions {
+                expression_restriction: Some(restriction),
+                ..ParseOptions::default()
+            };
+            Parser::new(&allocator, source, source_type).with_options(opts).parse_expression()
+        };
+
+        // (source, accepted in JSON, accepted in JSON5)
+        let cases = [
+            (r#"{ "a": [1, -2.5, 1e3, "b", true, false, null], "c": {} }"#, true, true),
+            ("0", true, true),
+            ("-0.5E-3", true, true),
+            ("[]", true, true),
+            ("[1, 2,]", false, true),
+            (r#"{ "a": 1, }"#, false, true),
+            ("'a'", false, true),
+            ("{ a: 1 }", false, true),
+            ("{ null: 1 }", false, true),
+            ("// comment\n1 /* comment */", false, true),
+            ("0xFF", false, true),
+            (".5", false, true),
+            ("5.", false, true),
+            ("+1", false, true),
+            ("Infinity", false, true),
+            ("-Infinity", false, true),
+            ("[NaN]", false, true),
+            ("a", false, false),
+            ("undefined", false, false),
+            ("{ \"a\": b }", false, false),
+            ("{ 1: 2 }", false, false),
+            ("{ [a]: 1 }", false, false),
+            ("1_000", false, false),
+            ("01", false, false),
+            ("0b1", false, false),
+            ("0o1", false, false),
+            ("1n", false, false),
+            ("- -1", false, false),
+            ("-'a'", false, false),
+            ("-a", false, false),
+            ("`a`", false, false),
+            ("function f() {}", false, false),
+            ("() => 1", false, false),
+            ("f()", false, false),
+            ("a.b", false, false),
+            ("[1, , 2]", false, false),
+            ("1 + 2", false, false),
+            ("1, 2", false, false),
+            ("{ \"a\": 1 } x", false, false),
+        ];
+        for (source, json, json5) in cases {
+            let ret = parse(source, ExpressionRestriction::Json);
+            assert_eq!(ret.is_ok(), json, "JSON: {source}: {ret:?}");
+            let ret = parse(source, ExpressionRestriction::Json5);
+            assert_eq!(ret.is_ok(), json5, "JSON5: {source}: {ret:?}");
+        }
+
+        // Errors are targeted, and parsing continues after them.
+        let errors = parse("[a, 'b', f(), 1,] // c", ExpressionRestriction::Json).unwrap_err();
+        let messages = errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                "Identifiers are not allowed in JSON",
+                "Strings must use double quotes in JSON",
+                "Only strings, numbers, booleans, `null`, arrays and objects are allowed in JSON",
+                "Trailing commas are not allowed in JSON",
+                "Comments are not allowed in JSON",
+            ]
+        );
+        let errors = parse("{ \"a\": foo }", ExpressionRestriction::Json5).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "Identifiers are not allowed in JSON5");
+        assert_eq!(errors[0].labels.as_ref().unwrap()[0].offset(), 7);
+
+        let expr = parse("-Infinity", ExpressionRestriction::Json5).unwrap();
+        let Expression::UnaryExpression(expr) = expr else { panic!() };
+        assert!(
+            matches!(&expr.argument, Expression::Identifier(ident) if ident.name == "Infinity")
+        );
+
+        // `Parser::parse` is not restricted.
+        let opts = ParseOptions {
+            expression_restriction: Some(ExpressionRestriction::Json),
+            ..ParseOptions::default()
+        };
+        let ret = Parser::new(&allocator, "f(a)", source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+    }
+
+    #[test]
+    fn invalid_regex_flags() {
+        use oxc_diagnostics::Severity;
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let normalize = ParseOptions { normalize_regex_flags: true, ..ParseOptions::default() };
+
+        // (source, message, offset of the flag)
+        let sources = [
+            ("/a/gg;\nfoo();", "Flag g is mentioned twice in regular expression literal", 4),
+            ("x = /a/ix;\nfoo();", "Unexpected flag x in regular expression literal", 8),
+            ("f(/a/$, 1);\nfoo();", "Unexpected flag $ in regular expression literal", 5),
+        ];
+        for (source, message, offset) in sources {
+            for opts in [ParseOptions::default(), normalize] {
+                let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+                assert!(!ret.panicked, "{source}");
+                assert_eq!(ret.errors.len(), 1, "{source}");
+                assert_eq!(ret.errors[0].to_string(), message, "{source}");
+                let label = &ret.errors[0].labels.as_ref().unwrap()[0];
+                assert_eq!((label.offset(), label.len()), (offset, 1), "{source}");
+                assert_eq!(ret.program.body.len(), 2, "{source}");
+            }
+        }
+
+        let ret = Parser::new(&allocator, "/a/gxgi", source_type).parse();
+        let severities = ret.errors.iter().map(|error| error.severity).collect::<Vec<_>>();
+        assert_eq!(severities, [Severity::Error, Severity::Error]);
+        let ret = Parser::new(&allocator, "/a/gxgi", source_type).with_options(normalize).parse();
+        let severities = ret.errors.iter().map(|error| error.severity).collect::<Vec<_>>();
+        assert_eq!(severities, [Severity::Warning, Severity::Error]);
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("Expected ExpressionStatement");
+        };
+        let Expression::RegExpLiteral(lit) = &stmt.expression else {
+            panic!("Expected RegExpLiteral");
+        };
+        assert_eq!(lit.regex.flags, RegExpFlags::G | RegExpFlags::I);
+        assert_eq!(lit.raw.unwrap(), "/a/gxgi");
+    }
+
+    #[test]
+    fn collect_stats() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let opts = ParseOptions { collect_stats: true, ..ParseOptions::default() };
+        let small = "let a = [1, 'a'];";
+        let large = small.repeat(100);
+
+        let ret = Parser::new(&allocator, small, source_type).parse();
+        assert_eq!(ret.arena_bytes_allocated, 0);
+        assert_eq!(ret.node_count, 0);
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, small, source_type).with_options(opts).parse();
+        let small_bytes = ret.arena_bytes_allocated;
+        let small_nodes = ret.node_count;
+        assert!(small_bytes > 0);
+        assert!(small_nodes > 0);
+        assert!(small_bytes <= allocator.used_bytes());
+
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, &large, source_type).with_options(opts).parse();
+        let large_bytes = ret.arena_bytes_allocated;
+        assert!(large_bytes > small_bytes * 10);
+        assert!(ret.node_count >= small_nodes * 50);
+
+        // A shared allocator reports the usage of each parse, not the total.
+        let allocator = Allocator::default();
+        let first = Parser::new(&allocator, &large, source_type).with_options(opts).parse();
+        let first_bytes = first.arena_bytes_allocated;
+        let second = Parser::new(&allocator, &large, source_type).with_options(opts).parse();
+        assert!(second.arena_bytes_allocated > 0);
+        assert!(second.arena_bytes_allocated < first_bytes + first_bytes / 2);
+        assert_eq!(second.node_count, first.node_count);
+        assert!(allocator.used_bytes() >= first_bytes + second.arena_bytes_allocated);
+
+        let source = "class A { m() { return <div><span /><></></div>; } get a() {} }\n\
+                      const f = (a) => <A />;\n\
+                      function g() { return class {}; }";
+        let ret = Parser::new(&allocator, source, SourceType::tsx()).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+        assert_eq!(ret.jsx_elements_count, 3);
+        assert_eq!(ret.functions_count, 4);
+        assert_eq!(ret.classes_count, 2);
+        let depth = ret.max_nesting_depth_reached;
+        assert!(depth > 3);
+        let ret = Parser::new(&allocator, "a;", source_type).with_options(opts).parse();
+        assert!(ret.max_nesting_depth_reached < depth);
+        let ret = Parser::new(&allocator, source, SourceType::tsx()).parse();
+        assert_eq!(ret.jsx_elements_count + ret.functions_count + ret.classes_count, 0);
+        assert_eq!(ret.max_nesting_depth_reached, 0);
+    }
+
+    #[test]
+    fn export_default_span() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::ts();
+        let sources = [
+            ("export default foo();", (0, 14), (15, 20)),
+            ("let x;\nexport   default class {}", (7, 23), (24, 32)),
+            ("@dec export default class C {}", (5, 19), (20, 30)),
+            ("export default function f() {}\nexport default 1;", (0, 14), (15, 30)),
+        ];
+        for (source, keyword, value) in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            let expected = ExportDefaultSpan {
+                keyword: Span::new(keyword.0, keyword.1),
+                value: Span::new(value.0, value.1),
+            };
+            assert_eq!(ret.export_default_span, Some(expected), "{source}");
+        }
+
+        let sources = [
+            "export const x = 1;",
+            "declare module 'm' { export default x; }",
+            "export default (;",
+        ];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert_eq!(ret.export_default_span, None, "{source}");
+        }
+    }
+
+    #[test]
+    fn top_level_await_in_script() {
+        let allocator = Allocator::default();
+        let source_type = SourceType::cjs();
+        let opts =
+            ParseOptions { allow_top_level_await_in_script: true, ..ParseOptions::default() };
+        let source = "const res = await fetch(url);\nfor await (const x of xs) {}";
+
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(!ret.errors.is_empty());
+
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+        assert!(ret.program.source_type.is_script());
+        let Some(Statement::VariableDeclaration(decl)) = ret.program.body.first() else {
+            panic!("Expected VariableDeclaration");
+        };
+        let init = decl.declarations[0].init.as_ref().unwrap();
+        assert!(matches!(init, Expression::AwaitExpression(_)));
+
+        // Still an error inside functions which are not `async`.
+        let sources = ["function f() { await g(); }", "const f = () => { await g(); };"];
+        for source in sources {
+            let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+            assert!(!ret.errors.is_empty(), "{source}");
+        }
+        let source = "async function f() { await g(); }";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(ret.errors.is_empty());
+
+        // Also allows top-level `return` when combined with `allow_return_outside_function`.
+        let source = "return await f();";
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
+        assert!(!ret.errors.is_empty());
+        let opts = ParseOptions { allow_return_outside_function: true, ..opts };
+        let ret = Parser::new(&allocator, source, source_type).with_options(opts).parse();
         assert!(ret.errors.is_empty());
-        assert_eq!(ret.program.body.len(), 2);
     }
 }
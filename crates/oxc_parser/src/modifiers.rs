@@ -440,6 +440,44 @@ impl<'a> ParserImpl<'a> {
         self.at(kind) && self.try_parse(Self::next_token_can_follow_modifier).is_some()
     }
 
+    /// Eat `get` or `set` if it starts an accessor.
+    ///
+    /// When placeholders are allowed, also eats it if it is followed by a token which can neither
+    /// start the accessor name nor continue a member named `get` or `set`, e.g. `{` in `get {}`.
+    /// The caller is then expected to recover the missing name.
+    pub(crate) fn parse_accessor_keyword(&mut self, kind: Kind) -> bool {
+        debug_assert!(matches!(kind, Kind::Get | Kind::Set));
+        if self.parse_contextual_modifier(kind) {
+            return true;
+        }
+        if self.at(kind)
+            && self.can_insert_placeholder()
+            && self.lookahead(Self::next_token_is_missing_accessor_name)
+        {
+            self.bump_any();
+            return true;
+        }
+        false
+    }
+
+    fn next_token_is_missing_accessor_name(&mut self) -> bool {
+        self.bump_any();
+        !self.can_follow_get_or_set_keyword()
+            && !matches!(
+                self.cur_kind(),
+                Kind::LParen
+                    | Kind::LAngle
+                    | Kind::Colon
+                    | Kind::Comma
+                    | Kind::RCurly
+                    | Kind::Eq
+                    | Kind::Semicolon
+                    | Kind::Question
+                    | Kind::Bang
+                    | Kind::Eof
+            )
+    }
+
     fn parse_any_contextual_modifier(&mut self) -> bool {
         self.cur_kind().is_modifier_kind()
             && self.try_parse(Self::next_token_can_follow_modifier).is_some()
@@ -487,7 +525,7 @@ impl<'a> ParserImpl<'a> {
         }
     }
 
-    fn can_follow_get_or_set_keyword(&self) -> bool {
+    pub(crate) fn can_follow_get_or_set_keyword(&self) -> bool {
         let kind = self.cur_kind();
         kind == Kind::LBrack || kind == Kind::PrivateIdentifier || kind.is_literal_property_name()
     }
@@ -12,6 +12,7 @@ pub struct ModuleRecordBuilder<'a> {
     module_record: ModuleRecord<'a>,
     export_entries: Vec<'a, ExportEntry<'a>>,
     exported_bindings_duplicated: Vec<'a, NameSpan<'a>>,
+    module_syntax_evidence: Option<Span>,
 }
 
 impl<'a> ModuleRecordBuilder<'a> {
@@ -21,9 +22,15 @@ impl<'a> ModuleRecordBuilder<'a> {
             module_record: ModuleRecord::new(allocator),
             export_entries: Vec::new_in(allocator),
             exported_bindings_duplicated: Vec::new_in(allocator),
+            module_syntax_evidence: None,
         }
     }
 
+    /// The first module syntax found, see [`crate::ParserReturn::module_syntax_evidence`].
+    pub fn module_syntax_evidence(&self) -> Option<Span> {
+        self.module_syntax_evidence
+    }
+
     pub fn build(mut self) -> (ModuleRecord<'a>, std::vec::Vec<OxcDiagnostic>) {
         // The `ParseModule` algorithm requires `importedBoundNames` (import entries) to be
         // resolved before resolving export entries.
@@ -64,6 +71,14 @@ impl<'a> ModuleRecordBuilder<'a> {
         errors
     }
 
+    fn found_module_syntax(&mut self, span: Span) {
+        self.module_record.has_module_syntax = true;
+        // `import.meta` is visited before the declaration which contains it.
+        if self.module_syntax_evidence.is_none_or(|evidence| span.start < evidence.start) {
+            self.module_syntax_evidence = Some(span);
+        }
+    }
+
     fn add_module_request(&mut self, name: Atom<'a>, requested_module: RequestedModule) {
         self.module_record
             .requested_modules
@@ -182,7 +197,7 @@ impl<'a> ModuleRecordBuilder<'a> {
     }
 
     pub fn visit_import_meta(&mut self, span: Span) {
-        self.module_record.has_module_syntax = true;
+        self.found_module_syntax(span);
         self.module_record.import_metas.push(span);
     }
 
@@ -229,7 +244,7 @@ impl<'a> ModuleRecordBuilder<'a> {
                 is_import: true,
             },
         );
-        self.module_record.has_module_syntax = true;
+        self.found_module_syntax(decl.span);
     }
 
     pub fn visit_export_all_declaration(&mut self, decl: &ExportAllDeclaration<'a>) {
@@ -261,7 +276,7 @@ impl<'a> ModuleRecordBuilder<'a> {
                 is_import: false,
             },
         );
-        self.module_record.has_module_syntax = true;
+        self.found_module_syntax(decl.span);
     }
 
     pub fn visit_export_default_declaration(
@@ -298,7 +313,7 @@ impl<'a> ModuleRecordBuilder<'a> {
             is_type: decl.is_typescript_syntax(),
         };
         self.add_export_entry(export_entry);
-        self.module_record.has_module_syntax = true;
+        self.found_module_syntax(decl.span);
     }
 
     pub fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
@@ -363,14 +378,19 @@ impl<'a> ModuleRecordBuilder<'a> {
                 is_type: specifier.export_kind.is_type() || decl.export_kind.is_type(),
             };
             self.add_export_entry(export_entry);
+            self.module_record.export_specifiers.push(ExportSpecifierSpans {
+                span: specifier.span,
+                local_name_span: specifier.local.span(),
+                export_name_span: specifier.exported.span(),
+            });
             self.add_export_binding(specifier.exported.name(), specifier.exported.span());
         }
 
-        self.module_record.has_module_syntax = true;
+        self.found_module_syntax(decl.span);
     }
 
-    pub fn found_ts_export(&mut self) {
-        self.module_record.has_module_syntax = true;
+    pub fn found_ts_export(&mut self, span: Span) {
+        self.found_module_syntax(span);
     }
 }
 
@@ -674,7 +694,7 @@ mod module_record_tests {
                 import_name: ExportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
                 export_name: ExportExportName::Name(NameSpan::new("x".into(), Span::new(33, 34))),
                 local_name: ExportLocalName::Null,
-                is_type: false
+                is_type: false,
             }
         );
         assert_eq!(
@@ -686,11 +706,74 @@ mod module_record_tests {
                 import_name: ExportImportName::All,
                 export_name: ExportExportName::Name(NameSpan::new("ns".into(), Span::new(49, 51))),
                 local_name: ExportLocalName::Null,
-                is_type: false
+                is_type: false,
             }
         );
     }
 
+    #[test]
+    fn export_specifier_spans() {
+        let allocator = Allocator::default();
+        // (source, local name span, exported name span, exported name)
+        let cases = [
+            ("export { x }; var x", Span::new(9, 10), Span::new(9, 10), "x"),
+            ("export { x as v }; var x", Span::new(9, 10), Span::new(14, 15), "v"),
+            ("export { x as default }; var x", Span::new(9, 10), Span::new(14, 21), "default"),
+            (
+                "export { x as 'some name' }; var x",
+                Span::new(9, 10),
+                Span::new(14, 25),
+                "some name",
+            ),
+            ("export { x as 'a\\u0062' } from 'mod'", Span::new(9, 10), Span::new(14, 23), "ab"),
+            (
+                "import { x } from 'mod'; export { x as y }",
+                Span::new(34, 35),
+                Span::new(39, 40),
+                "y",
+            ),
+        ];
+        for (source, local_name_span, export_name_span, export_name) in cases {
+            let module_record = build(&allocator, source);
+            let entries = module_record
+                .local_export_entries
+                .iter()
+                .chain(module_record.indirect_export_entries.iter())
+                .collect::<Vec<_>>();
+            assert_eq!(entries.len(), 1, "{source}");
+            let entry = entries[0];
+            let specifier = module_record.export_specifier(entry).unwrap();
+            assert_eq!(specifier.span, entry.span, "{source}");
+            assert_eq!(specifier.local_name_span, local_name_span, "{source}");
+            assert_eq!(specifier.export_name_span, export_name_span, "{source}");
+            let ExportExportName::Name(name) = &entry.export_name else {
+                panic!("Expected named export: {source}");
+            };
+            assert_eq!(name.name, export_name, "{source}");
+            assert_eq!(name.span, export_name_span, "{source}");
+        }
+
+        // `export default` and `export * as ns` are not specifiers.
+        let module_record = build(&allocator, "export default x; export * as ns from 'mod'; var x");
+        let default_entry = &module_record.local_export_entries[0];
+        assert!(default_entry.export_name.is_default());
+        assert!(module_record.export_specifier(default_entry).is_none());
+        let namespace_entry = &module_record.indirect_export_entries[0];
+        assert!(module_record.export_specifier(namespace_entry).is_none());
+        assert_eq!(namespace_entry.export_name.span(), Some(Span::new(30, 32)));
+        assert!(module_record.export_specifiers.is_empty());
+
+        // Neither are exported declarations, even when mixed with specifiers.
+        let module_record =
+            build(&allocator, "export var y; export { x }; export function f() {} var x");
+        let entries = &module_record.local_export_entries;
+        assert_eq!(entries.len(), 3);
+        assert!(module_record.export_specifier(&entries[0]).is_none());
+        assert!(module_record.export_specifier(&entries[1]).is_some());
+        assert!(module_record.export_specifier(&entries[2]).is_none());
+        assert_eq!(module_record.export_specifiers.len(), 1);
+    }
+
     #[test]
     fn import_meta() {
         let allocator = Allocator::default();
@@ -0,0 +1,152 @@
+//! Serialization of parser results to ESTree JSON.
+
+use oxc_ast::ast::Comment;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+use oxc_estree::{
+    CompactJSSerializer, CompactTSSerializer, ESTree, JsonSafeString, SequenceSerializer,
+    Serializer, StructSerializer,
+};
+
+use crate::{Parser, ParserReturn};
+
+/// Estimate of the ratio of JSON size to source text size, as for `Program::to_estree_ts_json`.
+const JSON_CAPACITY_RATIO: usize = 16;
+
+impl Parser<'_> {
+    /// Parse, and serialize the result to JSON with an [ESTree]-compatible AST.
+    ///
+    /// The JSON is an object with these fields:
+    ///
+    /// * `program`: The `Program`, in the same shape as `Program::to_estree_ts_json` for
+    ///   TypeScript source types, and `Program::to_estree_js_json` otherwise.
+    /// * `comments`: The comments, as ESTree `Line` and `Block` comments.
+    ///   Only included if `include_comments` is `true`.
+    /// * `errors`: The errors, as objects with `severity`, `message`, `labels` and `helpMessage`.
+    ///   Only included if `include_errors` is `true`.
+    ///
+    /// All nodes, comments and labels have `start` and `end` fields, which are UTF-8 byte offsets
+    /// into the source text. The `value` of `BigInt` and `RegExp` literals is `null`, as they
+    /// cannot be represented in JSON.
+    ///
+    /// [ESTree]: https://github.com/estree/estree
+    pub fn parse_to_json_ast(self, include_comments: bool, include_errors: bool) -> String {
+        let ret = self.parse();
+        let json = JsonAst { ret: &ret, include_comments, include_errors };
+        let capacity = ret.program.source_text.len() * JSON_CAPACITY_RATIO;
+        if ret.program.source_type.is_typescript() {
+            let mut serializer = CompactTSSerializer::with_capacity(capacity, false);
+            json.serialize(&mut serializer);
+            serializer.into_string()
+        } else {
+            let mut serializer = CompactJSSerializer::with_capacity(capacity, false);
+            json.serialize(&mut serializer);
+            serializer.into_string()
+        }
+    }
+}
+
+struct JsonAst<'r, 'a> {
+    ret: &'r ParserReturn<'a>,
+    include_comments: bool,
+    include_errors: bool,
+}
+
+impl ESTree for JsonAst<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("program", &self.ret.program);
+        if self.include_comments {
+            let source_text = self.ret.program.source_text;
+            state.serialize_field(
+                "comments",
+                &JsonComments(&self.ret.program.comments, source_text),
+            );
+        }
+        if self.include_errors {
+            state.serialize_field("errors", &JsonErrors(&self.ret.errors));
+        }
+        state.end();
+    }
+}
+
+struct JsonComments<'r>(&'r [Comment], &'r str);
+
+impl ESTree for JsonComments<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut seq = serializer.serialize_sequence();
+        for comment in self.0 {
+            seq.serialize_element(&JsonComment(comment, self.1));
+        }
+        seq.end();
+    }
+}
+
+/// `Comment` serializes its `value` only for raw transfer, as it needs the source text.
+struct JsonComment<'r>(&'r Comment, &'r str);
+
+impl ESTree for JsonComment<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let Self(comment, source_text) = self;
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("type", &comment.kind);
+        state.serialize_field("value", comment.content_span().source_text(source_text));
+        state.serialize_span(comment.span);
+        state.end();
+    }
+}
+
+struct JsonErrors<'r>(&'r [OxcDiagnostic]);
+
+impl ESTree for JsonErrors<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut seq = serializer.serialize_sequence();
+        for error in self.0 {
+            seq.serialize_element(&JsonError(error));
+        }
+        seq.end();
+    }
+}
+
+struct JsonError<'r>(&'r OxcDiagnostic);
+
+impl ESTree for JsonError<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let error = self.0;
+        let severity = match error.severity {
+            Severity::Advice => "Advice",
+            Severity::Warning => "Warning",
+            Severity::Error => "Error",
+        };
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("severity", &JsonSafeString(severity));
+        state.serialize_field("message", error.message.as_ref());
+        state.serialize_field("labels", &JsonLabels(error.labels.as_deref().unwrap_or_default()));
+        state.serialize_field("helpMessage", &error.help.as_deref());
+        state.end();
+    }
+}
+
+struct JsonLabels<'r>(&'r [LabeledSpan]);
+
+impl ESTree for JsonLabels<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let mut seq = serializer.serialize_sequence();
+        for label in self.0 {
+            seq.serialize_element(&JsonLabel(label));
+        }
+        seq.end();
+    }
+}
+
+struct JsonLabel<'r>(&'r LabeledSpan);
+
+impl ESTree for JsonLabel<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) {
+        let label = self.0;
+        let mut state = serializer.serialize_struct();
+        state.serialize_field("message", &label.label());
+        state.serialize_field("start", &label.offset());
+        state.serialize_field("end", &(label.offset() + label.len()));
+        state.end();
+    }
+}
@@ -3,6 +3,8 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use oxc_ast::ast::AssignmentExpression;
 use oxc_span::Span;
 
+use crate::lexer::Kind;
+
 pub struct ParserState<'a> {
     pub not_parenthesized_arrow: FxHashSet<u32>,
 
@@ -15,6 +17,34 @@ pub struct ParserState<'a> {
     /// Keyed by start span of `ArrayExpression` / `ObjectExpression`.
     /// Valued by position of the trailing_comma.
     pub trailing_commas: FxHashMap<u32, Span>,
+
+    /// Duplicate `__proto__` properties in `ObjectExpression`s `({ __proto__: a, __proto__: b })`.
+    /// Not an error if the object is reinterpreted as an assignment pattern.
+    /// Keyed by start span of `ObjectExpression`.
+    /// Valued by spans of the previous and the duplicate `__proto__` keys.
+    pub duplicate_proto: FxHashMap<u32, Vec<(Span, Span)>>,
+
+    /// Elisions in `ArrayExpression`s `[1, , 3]`, when `report_sparse_array_warnings` is enabled.
+    /// Not reported if the array is reinterpreted as an assignment pattern.
+    /// Keyed by start span of `ArrayExpression`.
+    /// Valued by spans of the commas (or `[` and comma) around each elision.
+    pub sparse_array_elisions: FxHashMap<u32, Vec<Span>>,
+
+    /// Closing delimiters of the enclosing delimited constructs, innermost last.
+    /// Only tracked when `recover_from_errors` is enabled.
+    pub open_delimiters: Vec<Kind>,
+
+    /// Start of a closing delimiter which was transposed with the one before it, e.g. the `}`
+    /// in `({ a )}`. It is accepted as the closing delimiter of the enclosing construct.
+    pub transposed_delimiter: Option<u32>,
+
+    /// Start of a `;` which closed parenthesized lists implicitly, e.g. in `f(g(a;`, and the index
+    /// of the error reported for it.
+    pub unclosed_lists: Option<(u32, usize)>,
+
+    /// Length of `open_delimiters` within the innermost template substitution `${ ... }`, so
+    /// that it is the innermost delimited construct when they have the same length.
+    pub template_substitution: Option<usize>,
 }
 
 impl ParserState<'_> {
@@ -23,6 +53,12 @@ impl ParserState<'_> {
             not_parenthesized_arrow: FxHashSet::default(),
             cover_initialized_name: FxHashMap::default(),
             trailing_commas: FxHashMap::default(),
+            duplicate_proto: FxHashMap::default(),
+            sparse_array_elisions: FxHashMap::default(),
+            open_delimiters: vec![],
+            transposed_delimiter: None,
+            unclosed_lists: None,
+            template_substitution: None,
         }
     }
 }
@@ -0,0 +1,68 @@
+//! Collection of comments such as `// TODO: ...`, see [`crate::ParseOptions::collect_todo_comments`].
+
+use oxc_ast::ast::Comment;
+use oxc_span::Span;
+
+/// The tags linters commonly scan comments for, for [`ParseOptions::collect_todo_comments`].
+///
+/// [`ParseOptions::collect_todo_comments`]: crate::ParseOptions::collect_todo_comments
+pub const DEFAULT_TODO_COMMENT_TAGS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+/// A line of a comment which starts with a tag such as `TODO`, collected when
+/// [`ParseOptions::collect_todo_comments`] is enabled.
+///
+/// [`ParseOptions::collect_todo_comments`]: crate::ParseOptions::collect_todo_comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TodoComment<'a> {
+    /// Span from the tag to the end of the message, e.g. `TODO: fix this` in
+    /// `// TODO: fix this`.
+    pub span: Span,
+
+    /// The tag, e.g. `TODO`.
+    pub tag: &'a str,
+
+    /// The text after the tag and an optional `:`, trimmed, e.g. `fix this`.
+    /// Empty if the tag is all there is.
+    pub message: &'a str,
+}
+
+/// Find the lines of `comments` starting with one of `tags`, in source order.
+///
+/// Each line of a block comment is matched separately, after any leading whitespace, `*` and
+/// `/`, so JSDoc comments are matched line by line, and `/// TODO` matches too. The tag must be followed by a character which
+/// cannot continue a word, so `TODOS` does not match `TODO`.
+pub fn collect_todo_comments<'a>(
+    source_text: &'a str,
+    comments: &[Comment],
+    tags: &[&str],
+) -> Vec<TodoComment<'a>> {
+    let mut todo_comments = vec![];
+    for comment in comments {
+        let content_span = comment.content_span();
+        let mut line_start = content_span.start;
+        for line in content_span.source_text(source_text).split('\n') {
+            if let Some(todo_comment) = match_line(line, line_start, tags) {
+                todo_comments.push(todo_comment);
+            }
+            #[expect(clippy::cast_possible_truncation)]
+            let line_len = line.len() as u32;
+            line_start += line_len + 1;
+        }
+    }
+    todo_comments
+}
+
+#[expect(clippy::cast_possible_truncation)]
+fn match_line<'a>(line: &'a str, line_start: u32, tags: &[&str]) -> Option<TodoComment<'a>> {
+    let text = line.trim_start().trim_start_matches(['*', '/']).trim_start();
+    let tag = tags.iter().find(|tag| {
+        text.strip_prefix(**tag)
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    })?;
+    let (tag, rest) = text.split_at(tag.len());
+    let rest = rest.trim_start();
+    let message = rest.strip_prefix(':').unwrap_or(rest).trim();
+    let start = line_start + (line.len() - text.len()) as u32;
+    let end = line_start + line.trim_end().len() as u32;
+    Some(TodoComment { span: Span::new(start, end), tag, message })
+}
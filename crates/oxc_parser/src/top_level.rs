@@ -0,0 +1,89 @@
+//! Lazy parsing of top-level statements, see [`Parser::iter_top_level`].
+//!
+//! [`Parser::iter_top_level`]: crate::Parser::iter_top_level
+
+use oxc_allocator::Vec as ArenaVec;
+use oxc_ast::ast::{Directive, Hashbang, Statement};
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{ParserImpl, ParserReturn, context::StatementContext};
+
+/// Iterator over the top-level statements of a file, parsing one statement per call to
+/// [`next`](Iterator::next). Returned by [`Parser::iter_top_level`].
+///
+/// Statements are allocated in the arena as with [`Parser::parse`]. Iteration ends at the end of
+/// the file, or at an error which stops parsing. The iterator can be dropped at any point.
+///
+/// [`Parser::iter_top_level`]: crate::Parser::iter_top_level
+/// [`Parser::parse`]: crate::Parser::parse
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TopLevelIter<'a> {
+    parser: ParserImpl<'a>,
+    hashbang: Option<Hashbang<'a>>,
+    directives: ArenaVec<'a, Directive<'a>>,
+    expecting_directives: bool,
+    options_diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl<'a> TopLevelIter<'a> {
+    pub(crate) fn new(mut parser: ParserImpl<'a>, options_diagnostics: Vec<OxcDiagnostic>) -> Self {
+        // Initialize by moving onto the first token.
+        // Checks for hashbang comment.
+        parser.token = parser.lexer.first_token();
+        let hashbang = parser.parse_hashbang();
+        let directives = parser.ast.vec();
+        Self { parser, hashbang, directives, expecting_directives: true, options_diagnostics }
+    }
+
+    /// Errors found so far, including the error which stopped parsing, if any.
+    ///
+    /// Errors which [`finish`](Self::finish) reports about the file as a whole, such as those
+    /// from the module record, are not included.
+    pub fn errors(&self) -> impl Iterator<Item = &OxcDiagnostic> {
+        let (errors, fatal_error) = match &self.parser.fatal_error {
+            Some(fatal_error) => {
+                (&self.parser.errors[..fatal_error.errors_len], Some(&fatal_error.error))
+            }
+            None => (&self.parser.errors[..], None),
+        };
+        self.parser
+            .lexer
+            .errors
+            .iter()
+            .chain(errors)
+            .chain(fatal_error)
+            .chain(&self.options_diagnostics)
+    }
+
+    /// Stop parsing, and return the rest of what [`Parser::parse`] would for the statements
+    /// parsed so far.
+    ///
+    /// The [`Program`] has the hashbang, the directives and the comments up to where parsing
+    /// stopped, but no statements, as they were returned by the iterator. The module record
+    /// only has the imports and exports of those statements.
+    ///
+    /// [`Parser::parse`]: crate::Parser::parse
+    /// [`Program`]: oxc_ast::ast::Program
+    pub fn finish(self) -> ParserReturn<'a> {
+        let Self { mut parser, hashbang, directives, options_diagnostics, .. } = self;
+        let statements = parser.ast.vec();
+        let program = parser.finish_program(hashbang, directives, statements);
+        let mut ret = parser.finish(program).0;
+        ret.errors.extend(options_diagnostics);
+        ret
+    }
+}
+
+impl<'a> Iterator for TopLevelIter<'a> {
+    type Item = Statement<'a>;
+
+    fn next(&mut self) -> Option<Statement<'a>> {
+        let stmt = self.parser.parse_next_statement_list_item(
+            StatementContext::TopLevelStatementList,
+            &mut self.directives,
+            &mut self.expecting_directives,
+        )?;
+        // The statement is incomplete if parsing stopped inside it.
+        self.parser.fatal_error.is_none().then_some(stmt)
+    }
+}
@@ -1,6 +1,6 @@
 use oxc_allocator::{Box, Vec};
 use oxc_ast::ast::*;
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 
 use crate::{
     ParserImpl, diagnostics,
@@ -59,8 +59,19 @@ impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_enum_member(&mut self) -> TSEnumMember<'a> {
         let span = self.start_span();
         let id = self.parse_ts_enum_member_name();
+        let eq_span = self.cur_token().span();
         let initializer = if self.eat(Kind::Eq) {
-            Some(self.parse_assignment_expression_or_higher())
+            if self.options.recover_from_errors
+                && matches!(self.cur_kind(), Kind::Comma | Kind::RCurly)
+            {
+                // `enum E { A = , B = 2 }`
+                self.record_recovery();
+                self.error(diagnostics::enum_member_initializer_expected(eq_span));
+                self.suggest_fix(eq_span, "");
+                None
+            } else {
+                Some(self.parse_assignment_expression_or_higher())
+            }
         } else {
             None
         };
@@ -181,15 +192,13 @@ impl<'a> ParserImpl<'a> {
         let id = self.parse_binding_identifier();
         let type_parameters = self.parse_ts_type_parameters();
         let (extends, implements) = self.parse_heritage_clause();
-        let body = self.parse_ts_interface_body();
-        let extends = extends.map_or_else(
-            || self.ast.vec(),
-            |e| {
-                self.ast.vec_from_iter(e.into_iter().map(|(expression, type_parameters, span)| {
-                    TSInterfaceHeritage { span, expression, type_arguments: type_parameters }
-                }))
-            },
-        );
+        let body = if self.is_missing_body() {
+            self.record_recovery();
+            self.error(diagnostics::body_expected("interface", Span::empty(self.prev_token_end)));
+            self.ast.alloc_ts_interface_body(Span::empty(self.prev_token_end), self.ast.vec())
+        } else {
+            self.parse_ts_interface_body()
+        };
         self.verify_modifiers(
             modifiers,
             ModifierFlags::DECLARE,
@@ -199,19 +208,23 @@ impl<'a> ParserImpl<'a> {
         if let Some((implements_kw_span, _)) = implements {
             self.error(diagnostics::interface_implements(implements_kw_span));
         }
-        for extend in &extends {
-            if self.fatal_error.is_some() {
-                break;
-            }
-            if !extend.expression.is_entity_name_expression() {
-                self.error(diagnostics::interface_extend(extend.span));
+        let mut heritages = self.ast.vec();
+        for (expression, type_arguments, span) in extends.into_iter().flatten() {
+            if self.fatal_error.is_none() && !expression.is_entity_name_expression() {
+                self.error(diagnostics::interface_extend(span));
+                // `interface A extends foo(), B {}`: keep the other heritages.
+                if self.options.recover_from_errors {
+                    self.record_recovery();
+                    continue;
+                }
             }
+            heritages.push(TSInterfaceHeritage { span, expression, type_arguments });
         }
         self.ast.declaration_ts_interface(
             self.end_span(span),
             id,
             type_parameters,
-            extends,
+            heritages,
             body,
             modifiers.contains_declare(),
         )
@@ -370,6 +383,16 @@ impl<'a> ParserImpl<'a> {
             let decl = self.parse_module_or_namespace_declaration(span, kind, &Modifiers::empty());
             TSModuleDeclarationBody::TSModuleDeclaration(decl)
         } else {
+            // `namespace A => { }`, `module A = { }`
+            if self.options.recover_from_errors
+                && matches!(self.cur_kind(), Kind::Arrow | Kind::Eq)
+                && self.lookahead(|p| {
+                    p.bump_any();
+                    p.at(Kind::LCurly)
+                })
+            {
+                self.recover_namespace_body_token();
+            }
             let block = self.parse_ts_module_block();
             TSModuleDeclarationBody::TSModuleBlock(block)
         };
@@ -443,6 +466,10 @@ impl<'a> ParserImpl<'a> {
         match kind {
             Kind::Var | Kind::Let | Kind::Const => {
                 let kind = self.get_variable_declaration_kind();
+                if kind == VariableDeclarationKind::Var {
+                    let span = self.cur_token().span();
+                    self.check_var_declaration(span, modifiers.contains_declare());
+                }
                 self.bump_any();
                 self.verify_modifiers(
                     modifiers,
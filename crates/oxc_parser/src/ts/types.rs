@@ -1,6 +1,6 @@
 use oxc_allocator::{Box, Vec};
 use oxc_ast::{NONE, ast::*};
-use oxc_span::GetSpan;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 
 use crate::{
@@ -13,6 +13,10 @@ use super::{super::js::FunctionKind, statement::CallOrConstructorSignature};
 
 impl<'a> ParserImpl<'a> {
     pub(crate) fn parse_ts_type(&mut self) -> TSType<'a> {
+        self.with_nesting_guard(Self::parse_ts_type_guarded)
+    }
+
+    fn parse_ts_type_guarded(&mut self) -> TSType<'a> {
         if self.is_start_of_function_type_or_constructor_type() {
             return self.parse_function_or_constructor_type();
         }
@@ -177,7 +181,7 @@ impl<'a> ParserImpl<'a> {
         self.expect(Kind::Implements);
         let first = self.parse_ts_implement_name();
         let mut implements = self.ast.vec1(first);
-        while self.eat(Kind::Comma) {
+        while self.eat_heritage_clause_comma() {
             implements.push(self.parse_ts_implement_name());
         }
         implements
@@ -267,7 +271,12 @@ impl<'a> ParserImpl<'a> {
         let span = self.start_span();
         self.bump_any(); // bump operator
         let operator_span = self.end_span(span);
-        let ty = self.parse_type_operator_or_higher();
+        // `keyof ;`
+        if self.can_insert_placeholder() && !self.is_start_of_type(false) {
+            let ty = self.recover_missing_type_operand(operator.to_str());
+            return self.ast.ts_type_type_operator_type(self.end_span(span), operator, ty);
+        }
+        let ty = self.with_nesting_guard(Self::parse_type_operator_or_higher);
         if operator == TSTypeOperatorOperator::Readonly
             && !matches!(ty, TSType::TSArrayType(_))
             && !matches!(ty, TSType::TSTupleType(_))
@@ -434,6 +443,8 @@ impl<'a> ParserImpl<'a> {
                 if self.lookahead(|parser| {
                     parser.bump(Kind::Asserts);
                     parser.is_token_identifier_or_keyword_on_same_line()
+                        || (parser.can_insert_placeholder()
+                            && parser.is_token_invalid_asserts_parameter_name())
                 }) {
                     let asserts_start_span = self.start_span();
                     self.bump_any(); // bump `asserts`
@@ -451,6 +462,14 @@ impl<'a> ParserImpl<'a> {
         self.cur_kind().is_identifier_name() && !self.cur_token().is_on_new_line()
     }
 
+    /// A literal or private name after `asserts`, e.g. `asserts 1`, which is neither a type nor a
+    /// parameter name.
+    fn is_token_invalid_asserts_parameter_name(&self) -> bool {
+        let kind = self.cur_kind();
+        (matches!(kind, Kind::Str | Kind::PrivateIdentifier) || kind.is_number())
+            && !self.cur_token().is_on_new_line()
+    }
+
     fn parse_keyword_and_no_dot(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let ty = match self.cur_kind() {
@@ -558,6 +577,12 @@ impl<'a> ParserImpl<'a> {
         let kind = self.cur_kind();
         if kind == Kind::Plus || kind == Kind::Minus {
             self.bump_any();
+            // `+-readonly`, see `parse_mapped_type_modifier_operator`
+            if self.options.recover_from_errors
+                && matches!(self.cur_kind(), Kind::Plus | Kind::Minus)
+            {
+                self.bump_any();
+            }
             return self.at(Kind::Readonly);
         }
 
@@ -590,14 +615,16 @@ impl<'a> ParserImpl<'a> {
     fn parse_mapped_type(&mut self) -> TSType<'a> {
         let span = self.start_span();
         self.expect(Kind::LCurly);
-        let mut readonly = None;
-        if self.eat(Kind::Readonly) {
-            readonly = Some(TSMappedTypeModifierOperator::True);
-        } else if self.eat(Kind::Plus) && self.eat(Kind::Readonly) {
-            readonly = Some(TSMappedTypeModifierOperator::Plus);
-        } else if self.eat(Kind::Minus) && self.eat(Kind::Readonly) {
-            readonly = Some(TSMappedTypeModifierOperator::Minus);
-        }
+        let readonly = match self.cur_kind() {
+            Kind::Readonly => {
+                self.bump_any();
+                Some(TSMappedTypeModifierOperator::True)
+            }
+            Kind::Plus | Kind::Minus => {
+                Some(self.parse_mapped_type_modifier_operator(Kind::Readonly))
+            }
+            _ => None,
+        };
 
         self.expect(Kind::LBrack);
         let type_parameter_span = self.start_span();
@@ -606,7 +633,14 @@ impl<'a> ParserImpl<'a> {
         }
         let name = self.parse_binding_identifier();
         self.expect(Kind::In);
-        let constraint = self.parse_ts_type();
+        // `{ [K in]: V }`
+        let constraint = if self.can_insert_placeholder()
+            && matches!(self.cur_kind(), Kind::RBrack | Kind::As)
+        {
+            self.recover_missing_type()
+        } else {
+            self.parse_ts_type()
+        };
         let type_parameter = self.alloc(self.ast.ts_type_parameter(
             self.end_span(type_parameter_span),
             name,
@@ -625,21 +659,29 @@ impl<'a> ParserImpl<'a> {
                 self.bump_any();
                 Some(TSMappedTypeModifierOperator::True)
             }
-            Kind::Minus => {
-                self.bump_any();
-                self.expect(Kind::Question);
-                Some(TSMappedTypeModifierOperator::Minus)
-            }
-            Kind::Plus => {
-                self.bump_any();
-                self.expect(Kind::Question);
-                Some(TSMappedTypeModifierOperator::Plus)
+            Kind::Plus | Kind::Minus => {
+                Some(self.parse_mapped_type_modifier_operator(Kind::Question))
             }
             _ => None,
         };
 
-        let type_annotation = self.eat(Kind::Colon).then(|| self.parse_ts_type());
+        let type_annotation = if self.eat(Kind::Colon) {
+            // `{ [K in U]?: }`
+            let ty = if self.can_insert_placeholder()
+                && matches!(self.cur_kind(), Kind::Semicolon | Kind::RCurly)
+            {
+                self.recover_missing_type()
+            } else {
+                self.parse_ts_type()
+            };
+            Some(ty)
+        } else {
+            None
+        };
         self.bump(Kind::Semicolon);
+        if self.options.recover_from_errors && !self.at(Kind::RCurly) && !self.has_fatal_error() {
+            self.skip_mapped_type_trailing_tokens();
+        }
         self.expect(Kind::RCurly);
 
         self.ast.ts_type_mapped_type(
@@ -652,6 +694,60 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
+    /// Parse `+` or `-` followed by `modifier` (`readonly` or `?`) in a mapped type.
+    ///
+    /// When recovering, a second sign (`+-?`) is reported and skipped, keeping the first one.
+    fn parse_mapped_type_modifier_operator(
+        &mut self,
+        modifier: Kind,
+    ) -> TSMappedTypeModifierOperator {
+        let sign_span = self.cur_token().span();
+        let operator = if self.at(Kind::Plus) {
+            TSMappedTypeModifierOperator::Plus
+        } else {
+            TSMappedTypeModifierOperator::Minus
+        };
+        self.bump_any();
+        if self.options.recover_from_errors && matches!(self.cur_kind(), Kind::Plus | Kind::Minus) {
+            self.record_recovery();
+            let span = Span::new(sign_span.start, self.cur_token().end());
+            self.error(diagnostics::mapped_type_modifier_conflicting_signs(
+                modifier.to_str(),
+                span,
+            ));
+            self.bump_any();
+        }
+        self.expect(modifier);
+        operator
+    }
+
+    /// Report and skip unexpected tokens before the `}` closing a mapped type,
+    /// e.g. `extra` in `{ [K in U]: V extra }`.
+    ///
+    /// Like a list of type members, skipping stops at the `}` closing the mapped type,
+    /// so nested braces, brackets and parentheses are skipped as a whole.
+    #[cold]
+    fn skip_mapped_type_trailing_tokens(&mut self) {
+        self.record_recovery();
+        self.error(diagnostics::expect_token(
+            Kind::RCurly.to_str(),
+            self.cur_kind().to_str(),
+            self.cur_token().span(),
+            None,
+        ));
+        let mut depth = 0u32;
+        loop {
+            match self.cur_kind() {
+                Kind::Eof | Kind::Undetermined => break,
+                Kind::RCurly if depth == 0 => break,
+                Kind::LCurly | Kind::LBrack | Kind::LParen => depth += 1,
+                Kind::RCurly | Kind::RBrack | Kind::RParen => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+            self.bump_any();
+        }
+    }
+
     fn parse_type_literal(&mut self) -> TSType<'a> {
         let span = self.start_span();
         let member_list =
@@ -665,6 +761,10 @@ impl<'a> ParserImpl<'a> {
         let (entity_name, type_arguments) = if self.at(Kind::Import) {
             let entity_name = TSTypeQueryExprName::TSImportType(self.parse_ts_import_type());
             (entity_name, None)
+        } else if self.can_insert_placeholder() && !self.cur_kind().is_identifier_name() {
+            // `typeof ;`
+            let name = self.recover_missing_type_query_name();
+            (TSTypeQueryExprName::IdentifierReference(self.alloc(name)), None)
         } else {
             let entity_name = self.parse_ts_type_name(); // TODO: parseEntityName
             let entity_name = TSTypeQueryExprName::from(entity_name);
@@ -680,7 +780,7 @@ impl<'a> ParserImpl<'a> {
 
     fn parse_this_type_predicate(&mut self, span: u32, this_ty: TSThisType) -> TSType<'a> {
         self.bump_any(); // bump `is`
-        let ty = self.parse_ts_type();
+        let ty = self.parse_type_predicate_type();
         let type_annotation = Some(self.ast.ts_type_annotation(ty.span(), ty));
         self.ast.ts_type_type_predicate(
             self.end_span(span),
@@ -701,6 +801,12 @@ impl<'a> ParserImpl<'a> {
             return None;
         }
         self.bump_any();
+        // `<T extends>`
+        if self.can_insert_placeholder()
+            && matches!(self.cur_kind(), Kind::RAngle | Kind::Comma | Kind::Eq)
+        {
+            return Some(self.recover_missing_type());
+        }
         Some(self.parse_ts_type())
     }
 
@@ -754,14 +860,22 @@ impl<'a> ParserImpl<'a> {
         let parameter_name = if self.at(Kind::This) {
             TSTypePredicateName::This(self.parse_this_type_node())
         } else {
-            let ident_name = self.parse_identifier_name();
+            let ident_name = if self.can_insert_placeholder()
+                && self.is_token_invalid_asserts_parameter_name()
+            {
+                self.recover_invalid_asserts_parameter_name()
+            } else {
+                self.parse_identifier_name()
+            };
             TSTypePredicateName::Identifier(self.alloc(ident_name))
         };
         let mut type_annotation = None;
         if self.eat(Kind::Is) {
             let type_span = self.start_span();
-            let ty = self.parse_ts_type();
-            type_annotation = Some(self.ast.ts_type_annotation(self.end_span(type_span), ty));
+            let ty = self.parse_type_predicate_type();
+            // A placeholder for a missing type ends before `type_span` starts.
+            let span = if ty.span().is_empty() { ty.span() } else { self.end_span(type_span) };
+            type_annotation = Some(self.ast.ts_type_annotation(span, ty));
         }
         self.ast.ts_type_type_predicate(
             self.end_span(asserts_start_span),
@@ -921,7 +1035,14 @@ impl<'a> ParserImpl<'a> {
             self.expect(Kind::Colon);
             let type_span_start = self.start_span();
             let rest_after_tuple_member_name = self.eat(Kind::Dot3);
-            let ty = self.parse_ts_type();
+            // `[a: , b: number]`
+            let ty = if self.can_insert_placeholder()
+                && matches!(self.cur_kind(), Kind::Comma | Kind::RBrack)
+            {
+                self.recover_missing_tuple_element_type()
+            } else {
+                self.parse_ts_type()
+            };
             let optional_after_tuple_member_name = matches!(ty, TSType::JSDocNullableType(_));
             let tuple_element = self.convert_type_to_tuple_element(ty);
             let member_span = self.end_span(member_span_start);
@@ -1094,7 +1215,11 @@ impl<'a> ParserImpl<'a> {
             None
         };
 
-        let ty = self.parse_ts_type();
+        let ty = if type_predicate_variable.is_some() {
+            self.parse_type_predicate_type()
+        } else {
+            self.parse_ts_type()
+        };
         if let Some(parameter_name) = type_predicate_variable {
             let type_annotation = Some(self.ast.ts_type_annotation(ty.span(), ty));
             return self.ast.ts_type_type_predicate(
@@ -1107,6 +1232,29 @@ impl<'a> ParserImpl<'a> {
         ty
     }
 
+    /// Parse the type after `is` in a type predicate.
+    fn parse_type_predicate_type(&mut self) -> TSType<'a> {
+        if !self.can_insert_placeholder() {
+            return self.parse_ts_type();
+        }
+        // `x is ;`
+        if !self.is_start_of_type(false) {
+            return self.recover_missing_type_predicate_type();
+        }
+        // `x is { return true }`, where the `{` starts the function body rather than a type
+        if self.at(Kind::LCurly) {
+            let checkpoint = self.checkpoint_with_error_recovery();
+            let errors_count = self.errors_count();
+            let ty = self.parse_ts_type();
+            if self.fatal_error.is_none() && self.errors_count() == errors_count {
+                return ty;
+            }
+            self.rewind(checkpoint);
+            return self.recover_missing_type_predicate_type();
+        }
+        self.parse_ts_type()
+    }
+
     fn parse_type_predicate_prefix(&mut self) -> TSTypePredicateName<'a> {
         let parameter_name = if self.at(Kind::This) {
             TSTypePredicateName::This(self.parse_this_type_node())
@@ -1170,12 +1318,17 @@ impl<'a> ParserImpl<'a> {
             self.parse_formal_parameters(FunctionKind::Declaration, FormalParameterKind::Signature);
         let return_type = self.parse_ts_return_type_annotation();
         self.parse_type_member_semicolon();
-        if kind == TSMethodSignatureKind::Set
-            && let Some(return_type) = return_type.as_ref()
-        {
-            self.error(diagnostics::a_set_accessor_cannot_have_a_return_type_annotation(
-                return_type.span,
-            ));
+        match kind {
+            TSMethodSignatureKind::Get => self.check_getter(&params),
+            TSMethodSignatureKind::Set => {
+                self.check_setter(&params);
+                if let Some(return_type) = return_type.as_ref() {
+                    self.error(diagnostics::a_set_accessor_cannot_have_a_return_type_annotation(
+                        return_type.span,
+                    ));
+                }
+            }
+            TSMethodSignatureKind::Method => {}
         }
         self.ast.ts_signature_method_signature(
             self.end_span(span),
@@ -1225,6 +1378,9 @@ impl<'a> ParserImpl<'a> {
             )
         } else {
             let type_annotation = self.parse_ts_type_annotation();
+            if self.at(Kind::Eq) {
+                self.parse_property_signature_initializer();
+            }
             self.parse_type_member_semicolon();
             self.ast.ts_signature_property_signature(
                 self.end_span(span),
@@ -1274,6 +1430,24 @@ impl<'a> ParserImpl<'a> {
         )
     }
 
+    /// Report and skip the initializer of a property signature, e.g. `= 3` in
+    /// `interface Config { retries = 3; }`.
+    ///
+    /// The initializer is parsed as an expression, so that the next member starts after it.
+    #[cold]
+    fn parse_property_signature_initializer(&mut self) {
+        let span = self.start_span();
+        self.bump_any(); // bump `=`
+        self.context_add(Context::In, Self::parse_assignment_expression_or_higher);
+        let error = diagnostics::property_signature_initializer(self.end_span(span));
+        if self.options.recover_from_errors {
+            self.record_recovery();
+            self.error(error);
+        } else {
+            self.set_fatal_error(error);
+        }
+    }
+
     fn parse_type_member_semicolon(&mut self) {
         // We allow type members to be separated by commas or (possibly ASI) semicolons.
         // First check if it was a comma.  If so, we're done with the member.
@@ -0,0 +1,7 @@
+abstract const x = 1;
+abstract function f() {}
+abstract class C {}
+foo();
+abstract async function g() {}
+export default abstract function h() {}
+bar();
@@ -0,0 +1,2 @@
+export default abstract interface I {}
+foo();
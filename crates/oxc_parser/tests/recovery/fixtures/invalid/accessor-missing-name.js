@@ -0,0 +1,5 @@
+({ get {}, a: 1 });
+({ set { x = 1 }, b });
+class C { get {} foo() {} }
+class D { static set {} bar = 1 }
+foo();
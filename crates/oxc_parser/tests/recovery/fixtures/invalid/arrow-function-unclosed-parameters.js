@@ -0,0 +1,4 @@
+const f = async (a, => await a;
+const g = (a, b, => { return a; };
+const h = (a, => a;
+next();
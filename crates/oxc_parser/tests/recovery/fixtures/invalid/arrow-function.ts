@@ -0,0 +1,3 @@
+const f = (x) => ({...x )};
+const g = (x: number): { return x; };
+const h = (x: number): { a: number } { return { a: x }; };
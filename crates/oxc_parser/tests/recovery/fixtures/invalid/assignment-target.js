@@ -0,0 +1,7 @@
+let a, b;
+1 = a;
+a + b = 2;
+foo() = 1;
+a = 1 = b;
+(a + b) += c;
+a = 1;
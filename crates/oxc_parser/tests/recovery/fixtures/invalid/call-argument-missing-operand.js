@@ -0,0 +1,4 @@
+foo(a, b +);
+foo(a, b + , c);
+foo(a + , c);
+bar();
@@ -0,0 +1,10 @@
+class C {
+    public bar() {
+        var v = foo();
+    }
+    [a, b] = foo();
+    [
+    public blaz() {}
+    public baz() {}
+}
+new C();
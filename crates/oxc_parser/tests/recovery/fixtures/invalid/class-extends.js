@@ -0,0 +1,13 @@
+class Service extends createBase(options config) {
+  start() {
+    return this.run();
+  }
+}
+
+class Widget extends Base + Mixin {
+  render() {}
+}
+
+class Store extends getBase(, config) {
+  m() {}
+}
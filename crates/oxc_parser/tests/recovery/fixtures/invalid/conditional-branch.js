@@ -0,0 +1,7 @@
+const x = cond ? : b;
+const y = cond ? a :;
+const z = c1 ? (c2 ? : b) : d;
+foo();
+const w = c1 ? c2 ? a : : d;
+const v = c1 ? c2 : (c3 ? a :);
+bar();
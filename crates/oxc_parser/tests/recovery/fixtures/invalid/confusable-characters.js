@@ -0,0 +1,3 @@
+const s = “hello world”;
+x = a — b;
+foo（a）；bar();
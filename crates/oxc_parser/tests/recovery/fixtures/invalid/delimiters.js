@@ -0,0 +1,10 @@
+const x = (a + b]);
+arr[i).push(1);
+f(a[i);
+function g() {
+  h(a }
+foo();
+g([1, 2);
+x = { a: 1 ];
+x = `${a(b}`;
+foo();
@@ -0,0 +1,5 @@
+do {
+  foo();
+} bar();
+function f() { do {} }
+do g(); h();
@@ -0,0 +1,8 @@
+enum Status {
+  Active = "active",
+  Pending = ,
+  Archived = 3,
+  Deleted,
+}
+enum E { A = }
+enum F { A = 'a', B = , C = 3, D }
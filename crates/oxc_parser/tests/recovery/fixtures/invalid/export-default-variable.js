@@ -0,0 +1,4 @@
+export default const x = 1;
+export default let y;
+export default var z = 2;
+export { x, y, z };
@@ -0,0 +1,10 @@
+const items = [1, 2, 3];
+for (const item items) {
+  console.log(item);
+}
+for (const [a, b] pairs) {
+  use(a, b);
+}
+for (a b c) {
+  console.log(a);
+}
@@ -0,0 +1,10 @@
+function createStore(reducer) extends EventEmitter {
+  return { reducer };
+}
+
+const store = createStore(reduce);
+
+class A {
+  m() extends B {}
+}
+const g = function () implements I {};
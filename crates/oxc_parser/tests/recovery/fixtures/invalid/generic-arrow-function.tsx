@@ -0,0 +1,3 @@
+<T>(x: T) => x;
+const id = <T>(value: T): T => value;
+const el = <T>(text)</T>;
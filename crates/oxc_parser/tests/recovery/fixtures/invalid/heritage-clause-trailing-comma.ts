@@ -0,0 +1,6 @@
+class A extends B, {}
+class C implements I, { x = 1 }
+class D extends B implements I, J, {}
+class E extends B, implements I {}
+(class extends B, {});
+foo();
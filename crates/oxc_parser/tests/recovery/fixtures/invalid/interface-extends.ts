@@ -0,0 +1,5 @@
+interface A extends foo(), B {
+  x: number;
+}
+
+interface C extends D, a[0], E.F<G> {}
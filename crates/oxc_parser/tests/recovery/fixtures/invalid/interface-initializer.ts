@@ -0,0 +1,7 @@
+interface Config {
+  retries = 3;
+  name: string = 'x';
+  verbose: boolean;
+}
+
+type Options = { retries = 3, name: string = f(() => { return 'x'; }), verbose: boolean };
@@ -0,0 +1,5 @@
+lbl: let x = 1;
+lbl: let [a] = y;
+function f() { lbl: let x = 1; return x; }
+function g() { a: lbl: const x = 1; }
+foo();
@@ -0,0 +1,6 @@
+lbl: let { x } = y;
+lbl: const z = 1;
+lbl: class C {}
+{ lbl: using w = f(); }
+lbl: await using v = f();
+foo();
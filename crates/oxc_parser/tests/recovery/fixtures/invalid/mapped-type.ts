@@ -0,0 +1,9 @@
+type A = { [K in keyof U]?: } ;
+type B = { readonly [K in U]-?: V extra };
+type C = { [K in U]+-?: V };
+type D = { -+readonly [K in U]: V };
+type E = { [K in]: V };
+type F = { [K in keyof U]: ; };
+type G = { [K in as `get${K}`]: V };
+type H = { [K in U]: V extra (a, { b }) };
+type I = { -+readonly [K in U]+-?: V };
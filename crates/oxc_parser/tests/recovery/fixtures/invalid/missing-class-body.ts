@@ -0,0 +1,5 @@
+class Foo extends Bar
+const x = 1;
+interface Baz extends Bar
+const y = 1;
+class Qux extends Bar
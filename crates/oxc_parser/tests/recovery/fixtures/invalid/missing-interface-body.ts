@@ -0,0 +1 @@
+interface Foo extends Bar
@@ -0,0 +1,5 @@
+let a = 1;
+a + ;
+[a, b = ];
+let c = a;
+const v = cond ? a;
@@ -0,0 +1,7 @@
+namespace Utils => {
+  export const x = 1;
+}
+
+module Api = {
+  export function get() {}
+}
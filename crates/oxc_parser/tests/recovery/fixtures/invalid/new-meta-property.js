@@ -0,0 +1,4 @@
+function f() {
+  return new.foo;
+}
+foo();
@@ -0,0 +1,7 @@
+{ a } = obj;
+{ b, c: { d }, e = 1 } = obj;
+foo();
+{}
+= obj
+function f() { { a } = obj; }
+bar();
@@ -0,0 +1,4 @@
+function f() {
+  return { status; body: 1 };
+}
+const g = () => ({ a: 1; b: 2; });
@@ -0,0 +1,5 @@
+x = { a: , b: 2 };
+y = { "c": , [d]: };
+next();
+x = { a: };
+f({ a: , ...b });
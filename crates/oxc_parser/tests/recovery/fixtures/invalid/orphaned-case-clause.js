@@ -0,0 +1,11 @@
+function handle(action) {
+  log(action);
+  case "reset":
+    return initial;
+  default:
+    return action;
+}
+
+foo();
+case 1: bar(); break;
+default: baz();
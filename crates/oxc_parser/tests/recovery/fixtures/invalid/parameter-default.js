@@ -0,0 +1,3 @@
+function f(a = , b) {}
+const g = (x = ;) => x;
+foo();
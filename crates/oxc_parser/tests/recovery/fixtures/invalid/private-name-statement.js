@@ -0,0 +1,4 @@
+#field = 1;
+#other = { a: 1 }
+this.#secret;
+foo();
@@ -0,0 +1,3 @@
+const a = /a/gg;
+const b = /b/iq;
+foo(a, b);
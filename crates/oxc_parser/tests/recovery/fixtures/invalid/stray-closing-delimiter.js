@@ -0,0 +1,7 @@
+function f(items) {
+  doThing());
+  items]
+  return items;
+}
+foo(a)));
+bar();
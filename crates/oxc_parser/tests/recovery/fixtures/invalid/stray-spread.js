@@ -0,0 +1,5 @@
+function f() {
+  ...rest = getThings();
+  const ...x = y;
+  return ...values;
+}
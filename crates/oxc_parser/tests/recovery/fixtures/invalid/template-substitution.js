@@ -0,0 +1,4 @@
+const greeting = `Hello ${user.`;
+const path = `${dir.}/${file}`;
+console.log(greeting, path);
+const sum = `${a.` + `b`;
@@ -0,0 +1 @@
+foo(); throw
\ No newline at end of file
@@ -0,0 +1,7 @@
+function f() {
+  throw;
+}
+function g() { throw }
+f();
+throw
+new Error('x');
@@ -0,0 +1,5 @@
+type T = [a: , b: number];
+type U = [...rest: ];
+const x: T = [1, 2];
+type V = [a: number, b?: ];
+type W = [a: , b: ];
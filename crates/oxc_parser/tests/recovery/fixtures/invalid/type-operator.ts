@@ -0,0 +1,6 @@
+let a: typeof ;
+type B = keyof ;
+type C = unique ;
+type D = readonly ;
+type E = readonly string;
+function f(a: keyof , b: readonly ) {}
@@ -0,0 +1,8 @@
+function f<T extends, U extends = string>(a: T, b: U) {}
+type A<T extends> = T;
+function g<T extends>() {}
+class C<T extends> {}
+interface I<T extends> {}
+class D {
+  m<T extends>() {}
+}
@@ -0,0 +1,5 @@
+function isFoo(x): x is { return x.foo; }
+function assert(c): asserts c is ;
+function assertNumber(c): asserts 1 { return; }
+declare function isBar(x): x is ;
+type Guard = (x: unknown) => x is ;
@@ -0,0 +1,3 @@
+outer(inner(a, b;, c);
+first(second(third(x, y;
+next();
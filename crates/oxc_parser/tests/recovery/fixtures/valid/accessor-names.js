@@ -0,0 +1,9 @@
+({ get() {}, set(v) {} });
+({ get: 1, set: 2 });
+({ get, set });
+({ get = 1, set } = {});
+({ get 'a'() {}, set [b](v) {} });
+class A { get; set; }
+class B { get() {} set(v) {} }
+class C { get = 1; static set }
+class D { get }
@@ -0,0 +1,5 @@
+(x): {a: number} => x;
+(x: number): {a: number} => x;
+switch (y) { case (x): { break; } }
+c ? (x) : { a };
+class A { m(): asserts this is T { return; } }
@@ -0,0 +1,6 @@
+let a, b, c;
+a = b = c;
+[a, b] = [b, a];
+({ a, b: [c] } = { a: 1, b: [2] });
+a += 1;
+a ??= (b, c);
@@ -0,0 +1,13 @@
+class A {
+  #x = 1;
+  [a] = 1;
+  [b]() {}
+  m(o) {
+    function f() { return o.#x; }
+    return this.#x + (#x in o);
+  }
+}
+export default class {}
+x = class {};
+abstract
+class B {}
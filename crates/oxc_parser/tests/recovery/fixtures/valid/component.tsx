@@ -0,0 +1,10 @@
+export function App({ items }: { items: string[] }) {
+  return (
+    <ul className="list">
+      {items.map((item) => (
+        <li key={item}>{item}</li>
+      ))}
+      <>fragment</>
+    </ul>
+  );
+}
@@ -0,0 +1,3 @@
+declare let public: number;
+export default interface I {}
+export = foo;
@@ -0,0 +1,10 @@
+const f = (x, y = 1, ...rest) => x + y + rest.length;
+const g = async function* () {
+  yield await f(1, 2);
+};
+const o = { __proto__: null, [`key${f(1)}`]: 1, get x() { return 1; }, set x(v) {} };
+const t = typeof o === "object" ? o?.x : void 0;
+label: for (const k in o) {
+  if (!k) continue label;
+  throw new Error(k);
+}
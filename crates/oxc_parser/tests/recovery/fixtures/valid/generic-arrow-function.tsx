@@ -0,0 +1,3 @@
+<T,>(x: T) => x;
+<T extends unknown>(x: T) => x;
+<T>(text)</T>;
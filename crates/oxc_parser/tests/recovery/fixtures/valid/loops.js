@@ -0,0 +1,4 @@
+for (let i = 0; i < n; i++) {}
+for (const x of xs) {}
+for (k in o) {}
+do {} while (x) bar();
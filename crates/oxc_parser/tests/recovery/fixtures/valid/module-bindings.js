@@ -0,0 +1,11 @@
+public = package + 1;
+const url = import.meta.url;
+({ __proto__: 1, ['__proto__']: 2 });
+({ __proto__: 1, __proto__ });
+({ __proto__: 1, __proto__() {} });
+({ __proto__: 1, get __proto__() {} });
+({ __proto__: a, __proto__: b } = x);
+for ({ __proto__: a, __proto__: b } of x);
+[{ __proto__: a, __proto__: b }] = x;
+switch (x) { case 1: default: a(); }
+switch (x) { default: switch (y) { default: } }
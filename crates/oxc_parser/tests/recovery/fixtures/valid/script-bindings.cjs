@@ -0,0 +1,19 @@
+with (a) {}
+function f() { 'use strict'; }
+foo(); 'use strict'; with (a) {}
+let public = 1;
+const package = require('x');
+var static, interface;
+function g(implements, { a: private }) {}
+const yield = 5;
+var await = 1; function await() {}
+function h(yield, await) { let { yield: a } = x; }
+function* await() { const await = 1; }
+async function yield() { const yield = 1; }
+var let = 1; var [let] = x;
+lbl: let
+x = 1;
+lbl: function i() {}
+a: b: function j() {}
+lbl: using
+x = 1;
@@ -0,0 +1,7 @@
+f(...a, [...b], { ...c });
+const [...d] = e;
+function g(...h) { return [...h]; }
+async(a, b) => a;
+async(a, b);
+{ a }
+(b);
@@ -0,0 +1,13 @@
+type Pair = [first: string, second?: number];
+type Rest = [...items: string[]];
+declare function overload(a: string): string;
+declare function overload(a: number): number;
+function overload(a: any) {
+  return a;
+}
+interface Point<T extends object = {}> {
+  readonly x: number;
+  get y(): number;
+  set y(v: number);
+}
+const p = <T,>(x: T) => x as unknown as T satisfies T;
@@ -0,0 +1,268 @@
+//! Regression tests for [`ParseOptions::recover_from_errors`].
+//!
+//! Every file in the corpus is parsed twice, with recovery disabled and enabled, and must satisfy:
+//!
+//! * Files which parse without errors produce the same AST in both modes,
+//!   compared by the kind and span of every node.
+//! * Files which parse with errors still report errors when recovery is enabled.
+//! * Files expected to be valid parse without errors in both modes.
+//! * Files expected to be invalid report errors in both modes.
+//!
+//! The corpus is the sample in `tests/recovery/fixtures`, plus the directories listed in the
+//! `OXC_PARSER_RECOVERY_CORPUS` environment variable, e.g. local Test262 or TypeScript checkouts:
+//!
+//! ```sh
+//! OXC_PARSER_RECOVERY_CORPUS=../test262/test:../TypeScript/tests/cases \
+//!     cargo test -p oxc_parser --test recovery
+//! ```
+//!
+//! A file is expected to be valid or invalid if it is inside a `valid` or `invalid` directory,
+//! or by its Test262 frontmatter (`negative` with `phase: parse` is invalid).
+//! Other files are only checked for consistency between the two modes.
+//!
+//! The errors and AST of every file in `tests/recovery/fixtures/invalid`, parsed with recovery
+//! enabled, are also kept as snapshots in `tests/recovery/snapshots`.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstKind, AstType, ast::Program};
+use oxc_ast_visit::Visit;
+use oxc_parser::{ParseOptions, Parser, ParserReturn};
+use oxc_span::{GetSpan, SourceType, Span};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expectation {
+    Valid,
+    Invalid,
+    Unknown,
+}
+
+struct Case {
+    path: PathBuf,
+    source_text: String,
+    source_type: SourceType,
+    expectation: Expectation,
+}
+
+impl Case {
+    fn new(root: &Path, path: PathBuf) -> Option<Self> {
+        let mut source_type = SourceType::from_path(&path).ok()?;
+        let source_text = fs::read_to_string(&path).ok()?;
+        let mut expectation = Expectation::Unknown;
+
+        if let Some(frontmatter) = test262_frontmatter(&source_text) {
+            let is_module = frontmatter
+                .lines()
+                .any(|line| line.trim_start().starts_with("flags:") && line.contains("module"));
+            source_type = source_type.with_module(is_module);
+            // Negative tests for later phases (e.g. `phase: runtime`) are syntactically valid.
+            expectation =
+                if frontmatter.contains("negative:") && frontmatter.contains("phase: parse") {
+                    Expectation::Invalid
+                } else {
+                    Expectation::Valid
+                };
+        } else {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            for component in relative.components() {
+                match component.as_os_str().to_str() {
+                    Some("valid") => expectation = Expectation::Valid,
+                    Some("invalid") => expectation = Expectation::Invalid,
+                    _ => {}
+                }
+            }
+        }
+
+        Some(Self { path, source_text, source_type, expectation })
+    }
+
+    fn parse<'a>(&'a self, allocator: &'a Allocator, recover: bool) -> ParserReturn<'a> {
+        let options = ParseOptions { recover_from_errors: recover, ..ParseOptions::default() };
+        Parser::new(allocator, &self.source_text, self.source_type).with_options(options).parse()
+    }
+
+    /// Describe every failed check, or return an empty list if all checks pass.
+    fn check(&self) -> Vec<String> {
+        let allocator = Allocator::default();
+        let default = self.parse(&allocator, false);
+        let recovered = self.parse(&allocator, true);
+        let mut failures = vec![];
+
+        if default.errors.is_empty() {
+            if let Some(error) = recovered.errors.first() {
+                failures.push(format!("reports errors only with recovery enabled: {error}"));
+            }
+            if default.program.body.len() != recovered.program.body.len() {
+                failures.push(format!(
+                    "statement count differs: {} without recovery, {} with recovery",
+                    default.program.body.len(),
+                    recovered.program.body.len()
+                ));
+            }
+            if let Some(diff) = self.first_divergent_node(&default.program, &recovered.program) {
+                failures.push(diff);
+            }
+        } else if recovered.errors.is_empty() {
+            failures.push(format!(
+                "parses without errors only with recovery enabled, without recovery: {}",
+                default.errors[0]
+            ));
+        }
+
+        for (ret, mode) in [(&default, "without recovery"), (&recovered, "with recovery")] {
+            match self.expectation {
+                Expectation::Valid if !ret.errors.is_empty() => {
+                    failures.push(format!("expected valid, {mode}: {}", ret.errors[0]));
+                }
+                Expectation::Invalid if ret.errors.is_empty() => {
+                    failures.push(format!("expected invalid, but no errors {mode}"));
+                }
+                _ => {}
+            }
+        }
+
+        failures
+    }
+
+    fn first_divergent_node(&self, default: &Program, recovered: &Program) -> Option<String> {
+        let default = Nodes::collect(default);
+        let recovered = Nodes::collect(recovered);
+        let describe = |node: Option<&(AstType, Span)>| {
+            let Some(&(ty, span)) = node else { return "nothing".to_string() };
+            let text = span.source_text(&self.source_text).chars().take(40).collect::<String>();
+            format!("{ty:?} at {}..{} {text:?}", span.start, span.end)
+        };
+        let index = (0..default.len().max(recovered.len()))
+            .find(|&i| default.get(i) != recovered.get(i))?;
+        Some(format!(
+            "AST differs at node {index}: {} without recovery, {} with recovery",
+            describe(default.get(index)),
+            describe(recovered.get(index))
+        ))
+    }
+}
+
+/// Kinds and spans of all nodes in a [`Program`], in visitation order.
+#[derive(Default)]
+struct Nodes(Vec<(AstType, Span)>);
+
+impl Nodes {
+    fn collect(program: &Program) -> Vec<(AstType, Span)> {
+        let mut nodes = Self::default();
+        nodes.visit_program(program);
+        nodes.0
+    }
+}
+
+impl<'a> Visit<'a> for Nodes {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        self.0.push((kind.ty(), kind.span()));
+    }
+}
+
+/// Kinds, spans and source text of all nodes in a [`Program`], indented by depth.
+struct Outline<'s> {
+    source_text: &'s str,
+    depth: usize,
+    output: String,
+}
+
+impl<'s> Outline<'s> {
+    fn print(program: &Program<'s>) -> String {
+        let mut outline =
+            Self { source_text: program.source_text, depth: 0, output: String::new() };
+        outline.visit_program(program);
+        outline.output
+    }
+}
+
+impl<'a> Visit<'a> for Outline<'_> {
+    fn enter_node(&mut self, kind: AstKind<'a>) {
+        let span = kind.span();
+        let text = span.source_text(self.source_text);
+        let text = text.lines().next().unwrap_or_default().chars().take(40).collect::<String>();
+        let indent = "  ".repeat(self.depth);
+        writeln!(self.output, "{indent}{:?} {}..{} {text:?}", kind.ty(), span.start, span.end)
+            .unwrap();
+        self.depth += 1;
+    }
+
+    fn leave_node(&mut self, _kind: AstKind<'a>) {
+        self.depth -= 1;
+    }
+}
+
+/// Text between `/*---` and `---*/` in a Test262 test.
+fn test262_frontmatter(source_text: &str) -> Option<&str> {
+    let start = source_text.find("/*---")? + "/*---".len();
+    let end = start + source_text[start..].find("---*/")?;
+    Some(&source_text[start..end])
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn recovery() {
+    let mut roots = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/recovery/fixtures")];
+    if let Some(paths) = env::var_os("OXC_PARSER_RECOVERY_CORPUS") {
+        roots.extend(env::split_paths(&paths));
+    }
+
+    let mut count = 0;
+    let mut failed = 0;
+    let mut report = String::new();
+    for root in &roots {
+        let mut files = vec![];
+        collect_files(root, &mut files);
+        files.sort_unstable();
+        for case in files.into_iter().filter_map(|path| Case::new(root, path)) {
+            count += 1;
+            let failures = case.check();
+            if failures.is_empty() {
+                continue;
+            }
+            failed += 1;
+            writeln!(report, "{}", case.path.display()).unwrap();
+            for failure in failures {
+                writeln!(report, "  {failure}").unwrap();
+            }
+        }
+    }
+
+    assert!(count > 0, "No files found in {roots:?}");
+    assert!(report.is_empty(), "{failed} of {count} files failed:\n{report}");
+}
+
+#[test]
+fn snapshots() {
+    insta::glob!("fixtures/invalid/*", |path| {
+        let case = Case::new(path.parent().unwrap(), path.to_path_buf()).unwrap();
+        let allocator = Allocator::default();
+        let ret = case.parse(&allocator, true);
+        let name = path.file_name().unwrap().to_str().unwrap();
+        let snapshot = format!(
+            "==================== Errors ====================\n{}\n==================== AST ====================\n{}",
+            ret.render_errors(name),
+            Outline::print(&ret.program)
+        );
+        insta::with_settings!({ prepend_module_to_snapshot => false, snapshot_suffix => "", omit_expression => true }, {
+            insta::assert_snapshot!(name, snapshot);
+        });
+    });
+}
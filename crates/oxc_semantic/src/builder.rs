@@ -100,6 +100,15 @@ pub struct SemanticBuilder<'a> {
     /// See: [`crate::checker::check`]
     check_syntax_error: bool,
 
+    /// See [`SemanticBuilder::with_allow_break_outside_loop`]
+    pub(crate) allow_break_outside_loop: bool,
+
+    /// See [`SemanticBuilder::with_allow_continue_outside_loop`]
+    pub(crate) allow_continue_outside_loop: bool,
+
+    /// See [`SemanticBuilder::with_allow_super_outside_method`]
+    pub(crate) allow_super_outside_method: bool,
+
     #[cfg(feature = "cfg")]
     pub(crate) cfg: Option<ControlFlowGraphBuilder<'a>>,
     #[cfg(not(feature = "cfg"))]
@@ -149,6 +158,9 @@ impl<'a> SemanticBuilder<'a> {
             stats: None,
             excess_capacity: 0.0,
             check_syntax_error: false,
+            allow_break_outside_loop: false,
+            allow_continue_outside_loop: false,
+            allow_super_outside_method: false,
             #[cfg(feature = "cfg")]
             cfg: None,
             #[cfg(not(feature = "cfg"))]
@@ -172,6 +184,50 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
+    /// Allow `break` statements at the top level which have no enclosing loop or `switch`,
+    /// or whose label is not defined.
+    ///
+    /// This is useful for checking a fragment of code which will be spliced into a loop,
+    /// like [`ParseOptions::allow_return_outside_function`] for a function body.
+    /// `break` inside a function in the fragment is still checked.
+    ///
+    /// Only has an effect with [`SemanticBuilder::with_check_syntax_error`].
+    ///
+    /// By default, this is `false`.
+    ///
+    /// [`ParseOptions::allow_return_outside_function`]: https://docs.rs/oxc_parser/latest/oxc_parser/struct.ParseOptions.html#structfield.allow_return_outside_function
+    #[must_use]
+    pub fn with_allow_break_outside_loop(mut self, yes: bool) -> Self {
+        self.allow_break_outside_loop = yes;
+        self
+    }
+
+    /// Allow `continue` statements at the top level which have no enclosing loop,
+    /// or whose label is not defined.
+    ///
+    /// See [`SemanticBuilder::with_allow_break_outside_loop`].
+    ///
+    /// By default, this is `false`.
+    #[must_use]
+    pub fn with_allow_continue_outside_loop(mut self, yes: bool) -> Self {
+        self.allow_continue_outside_loop = yes;
+        self
+    }
+
+    /// Allow `super.foo` and `super()` outside of methods and constructors, as long as they are
+    /// not inside a function (other than an arrow function) which is not a method.
+    ///
+    /// This is useful for checking a fragment of code which will be spliced into a method body.
+    ///
+    /// Only has an effect with [`SemanticBuilder::with_check_syntax_error`].
+    ///
+    /// By default, this is `false`.
+    #[must_use]
+    pub fn with_allow_super_outside_method(mut self, yes: bool) -> Self {
+        self.allow_super_outside_method = yes;
+        self
+    }
+
     /// Enable or disable building a [`ControlFlowGraph`].
     ///
     /// [`ControlFlowGraph`]: oxc_cfg::ControlFlowGraph
@@ -443,6 +499,10 @@ impl<'a> SemanticBuilder<'a> {
         excludes: SymbolFlags,
         report_error: bool,
     ) -> Option<SymbolId> {
+        // Placeholder bindings from parser error recovery have empty names, and are distinct.
+        if name.is_empty() {
+            return None;
+        }
         let symbol_id = self.scoping.get_binding(scope_id, name).or_else(|| {
             self.hoisting_variables.get(&scope_id).and_then(|symbols| symbols.get(name).copied())
         })?;
@@ -121,7 +121,12 @@ pub fn check_identifier(
     }
 
     // It is a Syntax Error if this phrase is contained in strict mode code and the StringValue of IdentifierName is: "implements", "interface", "let", "package", "private", "protected", "public", "static", or "yield".
-    if ctx.strict_mode() && STRICT_MODE_NAMES.contains(name) {
+    // The parser reports binding names other than `let` and `yield`.
+    if ctx.strict_mode()
+        && STRICT_MODE_NAMES.contains(name)
+        && (matches!(name, "let" | "yield")
+            || !matches!(ctx.nodes.kind(ctx.current_node_id), AstKind::BindingIdentifier(_)))
+    {
         ctx.error(reserved_keyword(name, span));
     }
 }
@@ -148,13 +153,6 @@ fn unexpected_identifier_assign(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Cannot assign to '{x0}' in strict mode")).with_label(span1)
 }
 
-fn invalid_let_declaration(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!(
-        "`let` cannot be declared as a variable name inside of a `{x0}` declaration"
-    ))
-    .with_label(span1)
-}
-
 pub fn check_binding_identifier(ident: &BindingIdentifier, ctx: &SemanticBuilder<'_>) {
     if ctx.strict_mode() {
         // In strict mode, `eval` and `arguments` are banned as identifiers.
@@ -205,24 +203,8 @@ pub fn check_binding_identifier(ident: &BindingIdentifier, ctx: &SemanticBuilder
                 ctx.error(unexpected_identifier_assign(&ident.name, ident.span));
             }
         }
-    } else {
-        // LexicalDeclaration : LetOrConst BindingList ;
-        // * It is a Syntax Error if the BoundNames of BindingList contains "let".
-        if ident.name == "let" {
-            for node_kind in ctx.nodes.ancestor_kinds(ctx.current_node_id) {
-                match node_kind {
-                    AstKind::VariableDeclarator(decl) => {
-                        if decl.kind.is_lexical() {
-                            ctx.error(invalid_let_declaration(decl.kind.as_str(), ident.span));
-                        }
-                        break;
-                    }
-                    AstKind::Function(_) => break,
-                    _ => {}
-                }
-            }
-        }
     }
+    // `let` as a name in a lexical declaration is reported by the parser.
 }
 
 fn unexpected_arguments(x0: &str, span1: Span) -> OxcDiagnostic {
@@ -292,20 +274,6 @@ pub fn check_identifier_reference(ident: &IdentifierReference, ctx: &SemanticBui
     }
 }
 
-fn private_not_in_class(x0: &str, span1: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(format!("Private identifier '#{x0}' is not allowed outside class bodies"))
-        .with_label(span1)
-}
-
-pub fn check_private_identifier_outside_class(
-    ident: &PrivateIdentifier,
-    ctx: &SemanticBuilder<'_>,
-) {
-    if ctx.class_table_builder.current_class_id.is_none() {
-        ctx.error(private_not_in_class(&ident.name, ident.span));
-    }
-}
-
 fn private_field_undeclared(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::error(format!("Private field '{x0}' must be declared in an enclosing class"))
         .with_label(span1)
@@ -684,29 +652,6 @@ pub fn check_class_redeclaration(class: &Class, ctx: &SemanticBuilder<'_>) {
     }
 }
 
-fn with_statement(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("'with' statements are not allowed").with_label(span)
-}
-
-pub fn check_with_statement(stmt: &WithStatement, ctx: &SemanticBuilder<'_>) {
-    if ctx.strict_mode() || ctx.source_type.is_typescript() {
-        ctx.error(with_statement(Span::sized(stmt.span.start, 4)));
-    }
-}
-
-pub fn check_switch_statement<'a>(stmt: &SwitchStatement<'a>, ctx: &SemanticBuilder<'a>) {
-    let mut previous_default: Option<Span> = None;
-    for case in &stmt.cases {
-        if case.test.is_none() {
-            if let Some(previous_span) = previous_default {
-                ctx.error(redeclaration("default", previous_span, case.span));
-                break;
-            }
-            previous_default.replace(case.span);
-        }
-    }
-}
-
 fn invalid_label_jump_target(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Jump target cannot cross function boundary.").with_label(span)
 }
@@ -734,6 +679,9 @@ pub fn check_break_statement(stmt: &BreakStatement, ctx: &SemanticBuilder<'_>) {
     for node_kind in ctx.nodes.ancestor_kinds(ctx.current_node_id) {
         match node_kind {
             AstKind::Program(_) => {
+                if ctx.allow_break_outside_loop {
+                    return;
+                }
                 return stmt.label.as_ref().map_or_else(
                     || ctx.error(invalid_break(stmt.span)),
                     |label| ctx.error(invalid_label_target(label.span)),
@@ -776,6 +724,9 @@ pub fn check_continue_statement(stmt: &ContinueStatement, ctx: &SemanticBuilder<
     for node_kind in ctx.nodes.ancestor_kinds(ctx.current_node_id) {
         match node_kind {
             AstKind::Program(_) => {
+                if ctx.allow_continue_outside_loop {
+                    return;
+                }
                 return stmt.label.as_ref().map_or_else(
                     || ctx.error(invalid_continue(stmt.span)),
                     |label| ctx.error(invalid_label_target(label.span)),
@@ -1095,6 +1046,11 @@ pub fn check_super(sup: &Super, ctx: &SemanticBuilder<'_>) {
             unreachable!();
         }
 
+        // Reached the top level, see `SemanticBuilder::with_allow_super_outside_method`.
+        if ctx.allow_super_outside_method && scope_id == ctx.scoping.root_scope_id() {
+            return;
+        }
+
         let scope_flags = ctx.scoping.scope_flags(scope_id);
 
         // `super.foo` is legal in static blocks, `super()` is not.
@@ -1230,30 +1186,6 @@ pub fn check_assignment_expression(assign_expr: &AssignmentExpression, ctx: &Sem
     }
 }
 
-pub fn check_object_expression(obj_expr: &ObjectExpression, ctx: &SemanticBuilder<'_>) {
-    // ObjectLiteral : { PropertyDefinitionList }
-    // It is a Syntax Error if PropertyNameList of PropertyDefinitionList contains any duplicate entries for "__proto__"
-    // and at least two of those entries were obtained from productions of the form PropertyDefinition : PropertyName : AssignmentExpression
-    let mut prev_proto: Option<Span> = None;
-    for prop in &obj_expr.properties {
-        if let ObjectPropertyKind::ObjectProperty(obj_prop) = prop {
-            // Skip if not a property definition production:
-            // PropertyDefinition : PropertyName : AssignmentExpression
-            if obj_prop.kind != PropertyKind::Init || obj_prop.method {
-                continue;
-            }
-            if let Some((prop_name, span)) = prop.prop_name()
-                && prop_name == "__proto__"
-            {
-                if let Some(prev_span) = prev_proto {
-                    ctx.error(redeclaration("__proto__", prev_span, span));
-                }
-                prev_proto = Some(span);
-            }
-        }
-    }
-}
-
 fn super_private(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Private fields cannot be accessed on super").with_label(span)
 }
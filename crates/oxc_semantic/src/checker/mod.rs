@@ -25,7 +25,6 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
             js::check_identifier_reference(ident, ctx);
         }
         AstKind::LabelIdentifier(ident) => js::check_identifier(&ident.name, ident.span, None, ctx),
-        AstKind::PrivateIdentifier(ident) => js::check_private_identifier_outside_class(ident, ctx),
         AstKind::NumericLiteral(lit) => js::check_number_literal(lit, ctx),
         AstKind::StringLiteral(lit) => js::check_string_literal(lit, ctx),
 
@@ -38,9 +37,7 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
 
         AstKind::WithStatement(stmt) => {
             js::check_function_declaration(&stmt.body, false, ctx);
-            js::check_with_statement(stmt, ctx);
         }
-        AstKind::SwitchStatement(stmt) => js::check_switch_statement(stmt, ctx),
         AstKind::BreakStatement(stmt) => js::check_break_statement(stmt, ctx),
         AstKind::ContinueStatement(stmt) => js::check_continue_statement(stmt, ctx),
         AstKind::LabeledStatement(stmt) => {
@@ -93,7 +90,6 @@ pub fn check<'a>(kind: AstKind<'a>, ctx: &SemanticBuilder<'a>) {
         AstKind::AssignmentExpression(expr) => js::check_assignment_expression(expr, ctx),
         AstKind::AwaitExpression(expr) => js::check_await_expression(expr, ctx),
         AstKind::PrivateFieldExpression(expr) => js::check_private_field_expression(expr, ctx),
-        AstKind::ObjectExpression(expr) => js::check_object_expression(expr, ctx),
         AstKind::UnaryExpression(expr) => js::check_unary_expression(expr, ctx),
         AstKind::YieldExpression(expr) => js::check_yield_expression(expr, ctx),
         AstKind::VariableDeclarator(decl) => {
@@ -483,4 +483,75 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_fragment_context_checks() {
+        fn check<'a>(
+            allocator: &'a Allocator,
+            source: &'a str,
+            builder: SemanticBuilder<'a>,
+        ) -> usize {
+            let parse = oxc_parser::Parser::new(allocator, source, SourceType::default()).parse();
+            assert!(parse.errors.is_empty(), "{source}");
+            let program = allocator.alloc(parse.program);
+            builder.with_check_syntax_error(true).build(program).errors.len()
+        }
+        let allocator = Allocator::default();
+        let allocator = &allocator;
+
+        let break_fragment = "if (done) break; if (skip) break outer;";
+        let continue_fragment = "if (skip) continue; if (next) continue outer;";
+        let super_fragment = "super.foo(); super(); const f = () => super.bar;";
+
+        assert_eq!(check(allocator, break_fragment, SemanticBuilder::new()), 2);
+        assert_eq!(check(allocator, continue_fragment, SemanticBuilder::new()), 2);
+        assert_eq!(check(allocator, super_fragment, SemanticBuilder::new()), 3);
+
+        let builder = || SemanticBuilder::new().with_allow_break_outside_loop(true);
+        assert_eq!(check(allocator, break_fragment, builder()), 0);
+        assert_eq!(check(allocator, continue_fragment, builder()), 2);
+        assert_eq!(check(allocator, super_fragment, builder()), 3);
+
+        let builder = || SemanticBuilder::new().with_allow_continue_outside_loop(true);
+        assert_eq!(check(allocator, break_fragment, builder()), 2);
+        assert_eq!(check(allocator, continue_fragment, builder()), 0);
+
+        let builder = || SemanticBuilder::new().with_allow_super_outside_method(true);
+        assert_eq!(check(allocator, super_fragment, builder()), 0);
+        assert_eq!(check(allocator, break_fragment, builder()), 2);
+
+        // Functions inside the fragment are still checked.
+        let builder = || {
+            SemanticBuilder::new()
+                .with_allow_break_outside_loop(true)
+                .with_allow_continue_outside_loop(true)
+                .with_allow_super_outside_method(true)
+        };
+        let source = "function f() { break; } function g() { continue; } function h() { super.x; }";
+        assert_eq!(check(allocator, source, builder()), 3);
+    }
+
+    #[test]
+    fn strict_mode_reserved_binding_names_reported_once() {
+        let allocator = Allocator::default();
+        let sources = [
+            ("let package = 1;", SourceType::mjs()),
+            ("class C { m() { let public = 1; } }", SourceType::cjs()),
+            ("'use strict'; function f(static) {}", SourceType::cjs()),
+            ("var interface; export {};", SourceType::unambiguous()),
+        ];
+        for (source, source_type) in sources {
+            let parse = oxc_parser::Parser::new(&allocator, source, source_type).parse();
+            let program = allocator.alloc(parse.program);
+            let semantic = SemanticBuilder::new().with_check_syntax_error(true).build(program);
+            assert_eq!(parse.errors.len() + semantic.errors.len(), 1, "{source}");
+        }
+
+        // References are only checked here.
+        let parse = oxc_parser::Parser::new(&allocator, "package;", SourceType::mjs()).parse();
+        assert!(parse.errors.is_empty());
+        let program = allocator.alloc(parse.program);
+        let semantic = SemanticBuilder::new().with_check_syntax_error(true).build(program);
+        assert_eq!(semantic.errors.len(), 1);
+    }
 }
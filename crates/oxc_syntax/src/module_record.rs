@@ -62,6 +62,12 @@ pub struct ModuleRecord<'a> {
 
     /// Span position of `import.meta`.
     pub import_metas: Vec<'a, Span>,
+
+    /// Export specifiers (`export { foo as bar }`), in source order.
+    ///
+    /// Kept apart from the [`ExportEntry`] records, which are shared by other kinds of exports,
+    /// see [`ModuleRecord::export_specifier`].
+    pub export_specifiers: Vec<'a, ExportSpecifierSpans>,
 }
 
 impl<'a> ModuleRecord<'a> {
@@ -77,8 +83,21 @@ impl<'a> ModuleRecord<'a> {
             exported_bindings: HashMap::new_in(allocator),
             dynamic_imports: Vec::new_in(allocator),
             import_metas: Vec::new_in(allocator),
+            export_specifiers: Vec::new_in(allocator),
         }
     }
+
+    /// The export specifier which `export_entry` was created for, if any.
+    ///
+    /// This tells `export { foo as default }` apart from `export default foo`.
+    pub fn export_specifier(&self, export_entry: &ExportEntry) -> Option<&ExportSpecifierSpans> {
+        let index = self
+            .export_specifiers
+            .binary_search_by_key(&export_entry.span.start, |specifier| specifier.span.start)
+            .ok()?;
+        let specifier = &self.export_specifiers[index];
+        (specifier.span == export_entry.span).then_some(specifier)
+    }
 }
 
 /// Name and Span
@@ -416,6 +435,24 @@ pub struct RequestedModule {
     pub is_import: bool,
 }
 
+/// Spans of an export specifier, e.g. `foo as bar` in `export { foo as bar }`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportSpecifierSpans {
+    /// Span of the whole specifier, which is the [`ExportEntry::span`] of its export entry.
+    pub span: Span,
+
+    /// Span of the local name, e.g. `foo`.
+    ///
+    /// Unlike [`ExportEntry::local_name`], this is also set for re-exports
+    /// (`export { foo as bar } from "mod"`).
+    pub local_name_span: Span,
+
+    /// Span of the exported name, e.g. `bar`.
+    ///
+    /// Includes the quotes of a string name (`export { foo as "some name" }`).
+    pub export_name_span: Span,
+}
+
 /// Dynamic import expression.
 #[ast]
 #[derive(Debug, Clone, Copy)]
@@ -441,6 +478,7 @@ pub trait VisitMutModuleRecord {
         module_record.star_export_entries.iter_mut().for_each(|e| self.visit_export_entry(e));
         module_record.dynamic_imports.iter_mut().for_each(|e| self.visit_dynamic_import(e));
         module_record.import_metas.iter_mut().for_each(|e| self.visit_span(e));
+        module_record.export_specifiers.iter_mut().for_each(|e| self.visit_export_specifier(e));
     }
 
     fn visit_requested_module(&mut self, requested_module: &mut RequestedModule) {
@@ -474,6 +512,12 @@ pub trait VisitMutModuleRecord {
         self.visit_export_local_name(&mut export_entry.local_name);
     }
 
+    fn visit_export_specifier(&mut self, export_specifier: &mut ExportSpecifierSpans) {
+        self.visit_span(&mut export_specifier.span);
+        self.visit_span(&mut export_specifier.local_name_span);
+        self.visit_span(&mut export_specifier.export_name_span);
+    }
+
     fn visit_export_import_name(&mut self, export_import_name: &mut ExportImportName) {
         match export_import_name {
             ExportImportName::Name(name_span) => self.visit_name_span(name_span),
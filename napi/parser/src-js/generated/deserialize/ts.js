@@ -4606,7 +4606,7 @@ function deserializeExportEntry(pos) {
     importName: deserializeExportImportName(pos + 40),
     exportName: deserializeExportExportName(pos + 72),
     localName: deserializeExportLocalName(pos + 104),
-    isType: deserializeBool(pos + 136),
+    isType: deserializeBool(pos + 152),
     start,
     end,
   };
@@ -6271,10 +6271,10 @@ function deserializeVecExportEntry(pos) {
   let arr = [],
     pos32 = pos >> 2;
   pos = uint32[pos32];
-  let endPos = pos + uint32[pos32 + 2] * 144;
+  let endPos = pos + uint32[pos32 + 2] * 160;
   for (; pos !== endPos; ) {
     arr.push(deserializeExportEntry(pos));
-    pos += 144;
+    pos += 160;
   }
   return arr;
 }
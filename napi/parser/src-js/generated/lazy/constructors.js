@@ -11917,7 +11917,7 @@ export class ExportEntry {
 
   get isType() {
     const internal = this.#internal;
-    return constructBool(internal.pos + 136, internal.ast);
+    return constructBool(internal.pos + 152, internal.ast);
   }
 
   toJSON() {
@@ -13826,7 +13826,7 @@ function constructImportEntry(pos, ast) {
 function constructVecExportEntry(pos, ast) {
   const { uint32 } = ast.buffer,
     pos32 = pos >> 2;
-  return new NodeArray(uint32[pos32], uint32[pos32 + 2], 144, constructExportEntry, ast);
+  return new NodeArray(uint32[pos32], uint32[pos32 + 2], 160, constructExportEntry, ast);
 }
 
 function constructExportEntry(pos, ast) {
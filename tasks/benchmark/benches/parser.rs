@@ -2,6 +2,7 @@ use oxc_allocator::Allocator;
 use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
 use oxc_benchmark::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
 use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
 use oxc_tasks_common::TestFiles;
 
 fn bench_parser(criterion: &mut Criterion) {
@@ -29,6 +30,45 @@ fn bench_parser(criterion: &mut Criterion) {
     group.finish();
 }
 
+fn bench_directives(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("parser_directives");
+    // Functions with directive prologues, followed by string statements which are not directives.
+    let source_text = (0..1000)
+        .map(|i| {
+            format!(r#"function f{i}() {{ 'use strict'; "use \"asm\""; foo(); 'a'; "b"; ('c'); }}"#)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let source_type = SourceType::cjs();
+    group.bench_function("prologues", |b| {
+        let mut allocator = Allocator::default();
+        b.iter(|| {
+            Parser::new(&allocator, &source_text, source_type).parse();
+            allocator.reset();
+        });
+    });
+    group.finish();
+}
+
+fn bench_small_files(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("parser_small_files");
+    // Many small files without errors, where per-parse overhead is significant.
+    let source_texts = (0..1000)
+        .map(|i| format!("import {{ a{i} }} from './a{i}';\nexport const b{i} = a{i} + {i};\n"))
+        .collect::<Vec<_>>();
+    let source_type = SourceType::mjs();
+    group.bench_function("valid", |b| {
+        let mut allocator = Allocator::default();
+        b.iter(|| {
+            for source_text in &source_texts {
+                black_box(Parser::new(&allocator, source_text, source_type).parse().errors);
+                allocator.reset();
+            }
+        });
+    });
+    group.finish();
+}
+
 fn bench_estree(criterion: &mut Criterion) {
     let mut group = criterion.benchmark_group("estree");
     for file in TestFiles::complicated().files().iter().take(1) {
@@ -60,5 +100,5 @@ fn bench_estree(criterion: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(parser, bench_parser, bench_estree);
+criterion_group!(parser, bench_parser, bench_directives, bench_small_files, bench_estree);
 criterion_main!(parser);
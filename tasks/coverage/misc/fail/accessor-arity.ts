@@ -0,0 +1,12 @@
+class C { get x(v) {} }
+class D { set x() {} }
+class E { set x(a, b) {} }
+class F { set x(...a) {} }
+({ get x(v) {} });
+({ set x() {} });
+({ set x(a, b) {} });
+({ set x(...a) {} });
+interface I { get x(v) }
+interface J { set x() }
+interface K { set x(a, b) }
+interface L { set x(...a) }
@@ -0,0 +1 @@
+do {} while
\ No newline at end of file
@@ -0,0 +1 @@
+do {} while x
\ No newline at end of file
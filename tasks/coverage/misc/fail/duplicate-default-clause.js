@@ -0,0 +1,2 @@
+switch (x) { default: a(); default: b(); case 1: c(); }
+switch (x) { default: default: case 1: default: }
@@ -0,0 +1,4 @@
+({ __proto__: 1, __proto__: 2 });
+({ __proto__: 1, '__proto__': 2 });
+({ __proto__: 1, __proto__: 2, __proto__: 3 });
+x = { a: { __proto__: 1, __proto__: 2 } };
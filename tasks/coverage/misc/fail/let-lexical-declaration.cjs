@@ -0,0 +1,5 @@
+{ let let = 1; }
+{ const let = 2; }
+{ let [a, let] = x; }
+{ const { b: { let } } = x; }
+for (const let of xs);
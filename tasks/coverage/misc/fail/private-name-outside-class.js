@@ -0,0 +1,2 @@
+this.#secret;
+#x in obj;
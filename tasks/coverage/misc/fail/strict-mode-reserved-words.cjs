@@ -0,0 +1,3 @@
+'use strict';
+let public = 1;
+class package { m(protected) {} }
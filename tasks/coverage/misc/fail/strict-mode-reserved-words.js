@@ -0,0 +1,4 @@
+let public = 1;
+const package = require('x');
+var static, interface;
+function f(implements, { a: private }) {}
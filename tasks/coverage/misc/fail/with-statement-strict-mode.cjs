@@ -0,0 +1,4 @@
+'use strict'; with (a) {}
+function f() { 'use strict'; with (b) {} }
+class C { m() { with (c) {} } }
+class D extends (function () { with (d) {} }) {}
@@ -0,0 +1 @@
+with (a) {}
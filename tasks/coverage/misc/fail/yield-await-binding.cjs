@@ -0,0 +1,6 @@
+function* g1() { const yield = 1; }
+function* g2() { let { yield } = x; }
+function* g3() { function yield() {} }
+async function f1() { let await = 2; }
+async function f2() { const { await } = x; }
+async function f3() { function await() {} }
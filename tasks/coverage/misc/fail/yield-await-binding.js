@@ -0,0 +1,2 @@
+function* g() { var [yield] = x; }
+const await = 1;